@@ -0,0 +1,224 @@
+use crate::utils::cratename;
+use proc_macro::{self, TokenStream};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse_macro_input, Attribute, DeriveInput, FnArg, GenericArgument, Ident, ImplItem,
+    ImplItemMethod, Item, PathArguments, ReturnType, Type, Visibility,
+};
+
+/// Plugins which describe their methods via `PluginAbi`, in the order they
+/// appear in `plugins_abi`.
+const PLUGINS: [&str; 5] = [
+    "Ownable",
+    "Pausable",
+    "Upgradable",
+    "FullAccessKeyFallback",
+    "AccessControllable",
+];
+
+pub fn derive_plugins_abi(input: TokenStream) -> TokenStream {
+    let cratename = cratename();
+
+    let input = parse_macro_input!(input);
+    let DeriveInput { ident, .. } = input;
+
+    let fragments = PLUGINS.iter().map(|plugin| {
+        let tag = Ident::new(plugin, Span::call_site());
+        quote! {
+            (&#cratename::abi::probe::<Self, #cratename::abi::tags::#tag>()).abi()
+        }
+    });
+
+    let output = quote! {
+        #[near_bindgen]
+        impl #ident {
+            pub fn plugins_abi(&self) -> String {
+                use #cratename::abi::{ProbeAbi as _, ProbeNone as _};
+                #cratename::abi::assemble(&[#(#fragments),*])
+            }
+        }
+    };
+
+    output.into()
+}
+
+/// Generates an implementation of `PluginAbi` which describes the methods
+/// exported by the `#[near_bindgen]` impls in `output`, i.e. the code
+/// generated for `plugin`.
+pub(crate) fn plugin_abi(plugin: &str, ident: &Ident, output: &TokenStream2) -> TokenStream2 {
+    let cratename = cratename();
+    let file: syn::File = syn::parse2(output.clone()).expect("Failed to parse generated code");
+
+    let mut methods = vec![];
+    for item in file.items.iter() {
+        let item_impl = match item {
+            Item::Impl(item_impl) if has_attr(&item_impl.attrs, "near_bindgen") => item_impl,
+            _ => continue,
+        };
+        // Methods of trait impls are exported, whereas inherent impls export
+        // only public methods.
+        let is_trait_impl = item_impl.trait_.is_some();
+        for impl_item in item_impl.items.iter() {
+            match impl_item {
+                ImplItem::Method(method)
+                    if is_trait_impl || matches!(method.vis, Visibility::Public(_)) =>
+                {
+                    methods.push(method_abi(method))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let abi = format!(
+        r#"{{"name":{},"methods":[{}]}}"#,
+        json_string(plugin),
+        methods.join(",")
+    );
+    let tag = Ident::new(plugin, Span::call_site());
+
+    quote! {
+        impl #cratename::abi::PluginAbi<#cratename::abi::tags::#tag> for #ident {
+            const ABI: &'static str = #abi;
+        }
+    }
+}
+
+/// Describes `method` as JSON object.
+fn method_abi(method: &ImplItemMethod) -> String {
+    let sig = &method.sig;
+    let mut kind = "view";
+    let mut args = vec![];
+    for input in sig.inputs.iter() {
+        match input {
+            FnArg::Receiver(receiver) => {
+                if receiver.reference.is_none() || receiver.mutability.is_some() {
+                    kind = "call";
+                }
+            }
+            FnArg::Typed(pat_type) => {
+                let pat = &pat_type.pat;
+                args.push(format!(
+                    r#"{{"name":{},"type":{},"serializer":{}}}"#,
+                    json_string(&normalize(quote!(#pat).to_string())),
+                    json_string(&render_type(&pat_type.ty)),
+                    json_string(serializer(&pat_type.attrs, "serializer")),
+                ));
+            }
+        }
+    }
+    let returns = match &sig.output {
+        ReturnType::Default => "null".to_string(),
+        ReturnType::Type(_, ty) => json_string(&render_type(ty)),
+    };
+
+    format!(
+        r#"{{"name":{},"kind":"{}","private":{},"payable":{},"args":[{}],"returns":{},"result_serializer":{}}}"#,
+        json_string(&sig.ident.to_string()),
+        kind,
+        has_attr(&method.attrs, "private"),
+        has_attr(&method.attrs, "payable"),
+        args.join(","),
+        returns,
+        json_string(serializer(&method.attrs, "result_serializer")),
+    )
+}
+
+/// Returns whether `attrs` contains an attribute whose path ends with `name`.
+fn has_attr(attrs: &[Attribute], name: &str) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident == name)
+    })
+}
+
+/// Returns the serializer selected by attribute `name`, which defaults to
+/// JSON.
+fn serializer(attrs: &[Attribute], name: &str) -> &'static str {
+    let borsh = attrs
+        .iter()
+        .any(|attr| attr.path.is_ident(name) && attr.tokens.to_string().contains("borsh"));
+    if borsh {
+        "borsh"
+    } else {
+        "json"
+    }
+}
+
+/// Renders `ty` without the paths leading to types, e.g.
+/// `Option<::near_sdk::AccountId>` as `Option<AccountId>`.
+fn render_type(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let segment = match type_path.path.segments.last() {
+                Some(segment) => segment,
+                None => return normalize(quote!(#ty).to_string()),
+            };
+            let mut rendered = segment.ident.to_string();
+            if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                let arguments: Vec<_> = arguments
+                    .args
+                    .iter()
+                    .map(|argument| match argument {
+                        GenericArgument::Type(ty) => render_type(ty),
+                        other => normalize(quote!(#other).to_string()),
+                    })
+                    .collect();
+                rendered.push_str(&format!("<{}>", arguments.join(", ")));
+            }
+            rendered
+        }
+        Type::Reference(reference) => {
+            let mutability = if reference.mutability.is_some() {
+                "mut "
+            } else {
+                ""
+            };
+            format!("&{}{}", mutability, render_type(&reference.elem))
+        }
+        Type::Tuple(tuple) => {
+            let elems: Vec<_> = tuple.elems.iter().map(render_type).collect();
+            format!("({})", elems.join(", "))
+        }
+        Type::Paren(paren) => render_type(&paren.elem),
+        Type::Group(group) => render_type(&group.elem),
+        _ => normalize(quote!(#ty).to_string()),
+    }
+}
+
+/// Removes whitespace from stringified tokens, except between words.
+fn normalize(tokens: String) -> String {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let chars: Vec<char> = tokens.chars().collect();
+    let mut normalized = String::with_capacity(tokens.len());
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_whitespace() {
+            let before = i.checked_sub(1).map(|j| chars[j]);
+            let after = chars.get(i + 1).copied();
+            if !(before.map_or(false, is_word) && after.map_or(false, is_word)) {
+                continue;
+            }
+        }
+        normalized.push(*c);
+    }
+    normalized
+}
+
+/// Encodes `value` as JSON string.
+fn json_string(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len() + 2);
+    encoded.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => encoded.push_str("\\\""),
+            '\\' => encoded.push_str("\\\\"),
+            c if c.is_control() => encoded.push_str(&format!("\\u{:04x}", c as u32)),
+            c => encoded.push(c),
+        }
+    }
+    encoded.push('"');
+    encoded
+}
@@ -38,6 +38,14 @@
 //!
 //! The last property aims to facilitate migrations which add or remove enum
 //! variants.
+//!
+//! Since each variant occupies two bits in addition to the super admin bit,
+//! an enum may have at most 63 variants. Deriving the trait on
+//! an enum with more variants fails to compile.
+//!
+//! Besides the trait, an associated function `bitflag` is generated which maps
+//! role names to the union of their permissions. It enables checking a set of
+//! roles with a single bitmask comparison.
 
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
@@ -49,17 +57,40 @@ const DEFAULT_SUPER_ADMIN_NAME: &str = "__SUPER_ADMIN";
 const DEFAULT_BITFLAGS_TYPE_NAME: &str = "RoleFlags";
 const DEFAULT_BOUNDCHECKER_TYPE_NAME: &str = "__AclBoundchecker";
 
+/// The maximum number of variants whose permissions fit into `u128`.
+const MAX_VARIANTS: usize = 63;
+
 pub fn derive_access_control_role(input: TokenStream) -> TokenStream {
-    // This derive doesn't take attributes, so no need to use `darling`.
+    // The only attribute is `#[grant_threshold(n)]` on variants, so no need
+    // to use `darling`.
     let input: ItemEnum = parse_macro_input!(input);
     let ItemEnum {
         ident, variants, ..
     } = input;
 
     let variants = variants.into_iter().collect::<Vec<_>>();
+    if variants.len() > MAX_VARIANTS {
+        let msg = format!(
+            "AccessControlRole supports at most {} variants, found {}",
+            MAX_VARIANTS,
+            variants.len(),
+        );
+        return TokenStream::from(syn::Error::new(ident.span(), msg).to_compile_error());
+    }
     let variant_idxs: Vec<_> =
         (0..u8::try_from(variants.len()).expect("Too many enum variants")).collect();
     let variant_names: Vec<_> = variants.iter().map(|v| format!("{}", v.ident)).collect();
+    // Interpolating the variants' identifiers, since variants may carry
+    // attributes.
+    let variant_idents: Vec<_> = variants.iter().map(|v| &v.ident).collect();
+    let grant_thresholds = match variants
+        .iter()
+        .map(grant_threshold)
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(thresholds) => thresholds,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
 
     let boundchecker_type = Ident::new(DEFAULT_BOUNDCHECKER_TYPE_NAME, ident.span());
     let bitflags_type_ident = new_bitflags_type_ident(Span::call_site());
@@ -87,13 +118,30 @@ pub fn derive_access_control_role(input: TokenStream) -> TokenStream {
                 // Compilation will fail if #ident doesn't satisfy above bounds.
                 let _x = #boundchecker_type::<#ident>::new();
             }
+
+            /// Returns the union of the permissions of `roles`, i.e. a
+            /// bitmask with the bit of each role set.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a role name does not correspond to a variant.
+            pub fn bitflag(roles: &[String]) -> u128 {
+                roles
+                    .iter()
+                    .map(|role| {
+                        let role = <#ident as ::std::convert::TryFrom<&str>>::try_from(role.as_str())
+                            .expect("Value does not correspond to a variant");
+                        role.acl_permission()
+                    })
+                    .fold(0, |acc, permission| acc | permission)
+            }
         }
 
         impl From<#ident> for u8 {
             fn from(value: #ident) -> Self {
                 match value {
                     #(
-                        #ident::#variants => #variant_idxs,
+                        #ident::#variant_idents => #variant_idxs,
                     )*
                 }
             }
@@ -105,7 +153,7 @@ pub fn derive_access_control_role(input: TokenStream) -> TokenStream {
             fn try_from(value: u8) -> Result<Self, Self::Error> {
                 match value {
                     #(
-                        #variant_idxs => Ok(#ident::#variants),
+                        #variant_idxs => Ok(#ident::#variant_idents),
                     )*
                     _ => Err("Value does not correspond to a variant"),
                 }
@@ -116,7 +164,7 @@ pub fn derive_access_control_role(input: TokenStream) -> TokenStream {
             fn from(value: #ident) -> Self {
                 match value {
                     #(
-                        #ident::#variants => #variant_names,
+                        #ident::#variant_idents => #variant_names,
                     )*
                 }
             }
@@ -126,7 +174,7 @@ pub fn derive_access_control_role(input: TokenStream) -> TokenStream {
             fn from(value: #ident) -> Self {
                 match value {
                     #(
-                        #ident::#variants => #variant_names.to_string(),
+                        #ident::#variant_idents => #variant_names.to_string(),
                     )*
                 }
             }
@@ -138,7 +186,7 @@ pub fn derive_access_control_role(input: TokenStream) -> TokenStream {
             fn try_from(value: &str) -> Result<#ident, Self::Error> {
                 match value {
                     #(
-                        #variant_names => Ok(#ident::#variants),
+                        #variant_names => Ok(#ident::#variant_idents),
                     )*
                     _ => Err("Value does not correspond to a variant"),
                 }
@@ -173,6 +221,14 @@ pub fn derive_access_control_role(input: TokenStream) -> TokenStream {
                     .expect("Too many enum variants");
                 safe_leftshift(1, n)
             }
+
+            fn acl_grant_threshold(self) -> u8 {
+                match self {
+                    #(
+                        #ident::#variant_idents => #grant_thresholds,
+                    )*
+                }
+            }
         }
 
         ::bitflags::bitflags! {
@@ -189,6 +245,27 @@ pub fn derive_access_control_role(input: TokenStream) -> TokenStream {
     output.into()
 }
 
+/// Parses the optional `#[grant_threshold(n)]` attribute of `variant`, which
+/// defaults to 1.
+fn grant_threshold(variant: &syn::Variant) -> Result<u8, syn::Error> {
+    let attr = match variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("grant_threshold"))
+    {
+        Some(attr) => attr,
+        None => return Ok(1),
+    };
+    let threshold = attr.parse_args::<syn::LitInt>()?.base10_parse::<u8>()?;
+    if threshold == 0 {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "grant_threshold must be at least 1",
+        ));
+    }
+    Ok(threshold)
+}
+
 pub fn new_bitflags_type_ident(span: Span) -> Ident {
     Ident::new(DEFAULT_BITFLAGS_TYPE_NAME, span)
 }
@@ -1,3 +1,4 @@
+use crate::abi::plugin_abi;
 use crate::access_control_role::new_bitflags_type_ident;
 use crate::utils::{cratename, is_near_bindgen_wrapped_or_marshall};
 use darling::FromMeta;
@@ -9,9 +10,71 @@ use syn::{parse_macro_input, AttributeArgs, ItemFn, ItemStruct};
 
 #[derive(Debug, FromMeta)]
 pub struct MacroArgs {
+    /// Prefix of the storage keys of collections related to access control,
+    /// returned by `acl_storage_prefix`. Defaults to `__acl`.
     #[darling(default)]
     storage_prefix: Option<String>,
     role_type: syn::Path,
+    /// If set, mutating methods of `AccessControllable` panic while the
+    /// contract is paused via the `Pausable` key "ALL".
+    #[darling(default)]
+    block_when_paused: bool,
+    /// Account which is treated as super-admin in addition to the stored
+    /// super-admins, e.g. a DAO contract.
+    #[darling(default)]
+    governance: Option<String>,
+    /// Which categories of events are emitted, either "minimal" or "full".
+    #[darling(default)]
+    event_level: Option<String>,
+    /// If set, role names passed to methods of `AccessControllable` are
+    /// matched case-insensitively.
+    #[darling(default)]
+    case_insensitive_roles: bool,
+    /// Roles which only super-admins may manage, i.e. admins of these roles
+    /// have no permissions.
+    #[darling(default)]
+    super_admin_only_roles: darling::util::PathList,
+    /// If set, the contract method `acl_init_super_admin` is generated.
+    #[darling(default)]
+    bootstrap: bool,
+    /// Named groups of roles, which `#[access_control_any(groups(...))]` may
+    /// refer to.
+    #[darling(default, multiple, rename = "group")]
+    groups: Vec<RoleGroup>,
+}
+
+/// A named group of roles declared via
+/// `#[access_control(group(name = "...", roles(...)))]`.
+#[derive(Debug, FromMeta)]
+pub struct RoleGroup {
+    name: String,
+    roles: darling::util::PathList,
+}
+
+/// Names of role groups passed as string literals, e.g.
+/// `groups("Managers")`.
+#[derive(Debug, Default)]
+pub struct GroupNames(Vec<String>);
+
+impl FromMeta for GroupNames {
+    fn from_list(items: &[syn::NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                syn::NestedMeta::Lit(syn::Lit::Str(name)) => Ok(name.value()),
+                _ => Err(darling::Error::unexpected_type("non-string literal").with_span(item)),
+            })
+            .collect::<darling::Result<Vec<_>>>()
+            .map(Self)
+    }
+}
+
+/// Returns the identifier of the associated constant which holds the roles
+/// of the group `name`. Both `#[access_control]` and `#[access_control_any]`
+/// use it, hence a group which is not declared fails to compile.
+fn role_group_ident(name: &str, span: Span) -> syn::Result<syn::Ident> {
+    syn::parse_str::<syn::Ident>(&format!("__ACL_GROUP_{}", name))
+        .map_err(|_| syn::Error::new(span, format!("Invalid role group name: {}", name)))
 }
 
 const DEFAULT_STORAGE_PREFIX: &str = "__acl";
@@ -20,6 +83,7 @@ const DEFAULT_ACL_TYPE_NAME: &str = "__Acl";
 
 const ERR_PARSE_BITFLAG: &str = "Value does not correspond to a permission";
 const ERR_PARSE_ROLE: &str = "Value does not correspond to a role";
+const ERR_PAUSED: &str = "AccessControllable: Method is blocked while the contract is paused";
 
 pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream {
     let cratename = cratename();
@@ -43,8 +107,104 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
         .storage_prefix
         .unwrap_or_else(|| DEFAULT_STORAGE_PREFIX.to_string());
     let role_type = macro_args.role_type;
+    let pause_check = if macro_args.block_when_paused {
+        quote! {
+            assert!(
+                !<Self as ::#cratename::Pausable>::pa_is_paused(self, "ALL".to_string()),
+                #ERR_PAUSED,
+            );
+        }
+    } else {
+        quote! {}
+    };
+    let version_check = quote! {
+        self.#acl_field.assert_storage_version();
+    };
+    let read_only_check = quote! {
+        ::#cratename::read_only::assert_not_read_only();
+    };
+    let event_level = match macro_args.event_level.as_deref() {
+        None | Some("full") => quote! { Full },
+        Some("minimal") => quote! { Minimal },
+        Some(_) => {
+            return TokenStream::from(
+                syn::Error::new(
+                    Span::call_site(),
+                    "event_level must be either \"minimal\" or \"full\"",
+                )
+                .to_compile_error(),
+            );
+        }
+    };
+    let try_parse_role = if macro_args.case_insensitive_roles {
+        quote! {
+            #acl_type::all_roles()
+                .find(|&variant| <&str>::from(variant).eq_ignore_ascii_case(role))
+        }
+    } else {
+        quote! {
+            <#role_type>::try_from(role).ok()
+        }
+    };
+    let super_admin_only_roles = macro_args.super_admin_only_roles.iter();
+    let is_super_admin_only = if macro_args.super_admin_only_roles.is_empty() {
+        quote! {
+            let _ = role;
+            false
+        }
+    } else {
+        quote! { matches!(role, #(#super_admin_only_roles)|*) }
+    };
+    let mut group_idents = vec![];
+    let mut group_roles = vec![];
+    for group in macro_args.groups.iter() {
+        match role_group_ident(&group.name, Span::call_site()) {
+            Ok(ident) => group_idents.push(ident),
+            Err(e) => return TokenStream::from(e.to_compile_error()),
+        }
+        let roles = group.roles.iter();
+        group_roles.push(quote! { #(#roles),* });
+    }
+    let role_groups = if group_idents.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            impl #ident {
+                #(
+                    #[doc(hidden)]
+                    #[allow(dead_code, non_upper_case_globals)]
+                    pub(crate) const #group_idents: &'static [#role_type] = &[#group_roles];
+                )*
+            }
+        }
+    };
+    let bootstrap = if macro_args.bootstrap {
+        quote! {
+            #[near_bindgen]
+            impl #ident {
+                /// Makes `account_id` super-admin if there are no
+                /// super-admins, see `__acl.init_super_admin`. Only the
+                /// contract itself may call it, e.g. in a transaction batching
+                /// deployment and initialization.
+                #[private]
+                pub fn acl_init_super_admin(&mut self, account_id: ::near_sdk::AccountId) -> bool {
+                    #read_only_check
+                    self.#acl_field.init_super_admin(&account_id)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let is_governance = match macro_args.governance {
+        Some(governance) => quote! { account_id.as_str() == #governance },
+        None => quote! {
+            let _ = account_id;
+            false
+        },
+    };
 
-    let output = quote! {
+    let mut output = quote! {
         #input
 
         #[derive(::near_sdk::borsh::BorshDeserialize, ::near_sdk::borsh::BorshSerialize)]
@@ -59,6 +219,24 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 #bitflags_type,
                 ::near_sdk::collections::UnorderedSet<::near_sdk::AccountId>,
             >,
+            /// Caches permissions read by admin checks, if enabled via
+            /// `enable_admin_cache`. It is not persisted, hence it is valid
+            /// only for the current receipt.
+            #[borsh_skip]
+            admin_cache: ::std::cell::RefCell<
+                Option<::std::collections::HashMap<::near_sdk::AccountId, #bitflags_type>>,
+            >,
+            /// Roles assumed via `assume_role`. It is not persisted, hence
+            /// assumed roles are active only for the current receipt.
+            #[borsh_skip]
+            assumed_roles: ::std::collections::HashSet<(::near_sdk::AccountId, u8)>,
+            /// Correlation id of the batch currently being processed, which is
+            /// included in emitted `RoleGranted` and `RoleRevoked` events.
+            #[borsh_skip]
+            correlation_id: Option<String>,
+            /// Whether the storage version was checked in the current receipt.
+            #[borsh_skip]
+            storage_version_checked: ::std::cell::Cell<bool>,
         }
 
         impl Default for #acl_type {
@@ -71,6 +249,10 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                     bearers: ::near_sdk::collections::UnorderedMap::new(
                         __acl_storage_prefix(base_prefix, __AclStorageKey::Bearers),
                     ),
+                    admin_cache: Default::default(),
+                    assumed_roles: Default::default(),
+                    correlation_id: Default::default(),
+                    storage_version_checked: Default::default(),
                 }
             }
         }
@@ -83,6 +265,35 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
             Permissions,
             Bearers,
             BearersSet { permission: #bitflags_type },
+            ConsumableUses,
+            AssumableRoles,
+            ScopedRoles,
+            StorageVersion,
+            GrantApprovals,
+            RoleMetadata,
+            RoleMetadataMap { role: u8 },
+            RoleExpiries,
+            PendingSuperAdminTransfer,
+            HandleRegistry,
+        }
+
+        /// Parses a role name passed to a method of `AccessControllable`.
+        fn __acl_parse_role(role: &str) -> #role_type {
+            __acl_try_parse_role(role).expect(#ERR_PARSE_ROLE)
+        }
+
+        /// Like `__acl_parse_role`, but returns `None` if `role` does not
+        /// correspond to a role.
+        fn __acl_try_parse_role(role: &str) -> Option<#role_type> {
+            #try_parse_role
+        }
+
+        /// Emits `event` according to the configured event level.
+        fn __acl_emit(event: ::#cratename::access_controllable::events::AclEvent) {
+            ::#cratename::access_controllable::events::emit_at_level(
+                ::#cratename::access_controllable::events::EventLevel::#event_level,
+                event,
+            );
         }
 
         /// Generates a prefix by concatenating the input parameters.
@@ -93,6 +304,26 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
             [base, specifier.as_slice()].concat()
         }
 
+        /// Estimates the storage in bytes added by inserting a new element into
+        /// an `UnorderedSet` or, if `value_len` is given, a new entry into an
+        /// `UnorderedMap` with the given `prefix_len`.
+        ///
+        /// Both collections store an index per element and append the element
+        /// to a `Vector`. Maps additionally append the value to a `Vector`.
+        fn __acl_unordered_insert_storage(prefix_len: u64, element_len: u64, value_len: Option<u64>) -> u64 {
+            // Prefixes of the collection's internal storage keys are extended
+            // by one byte and vector elements are keyed by a `u64` index.
+            let index_record = (prefix_len + 1 + element_len) + 8;
+            let element_record = (prefix_len + 1 + 8) + element_len;
+            let value_record = value_len.map(|value_len| (prefix_len + 1 + 8) + value_len);
+            let records = [Some(index_record), Some(element_record), value_record];
+            records
+                .iter()
+                .flatten()
+                .map(|record| record + ::#cratename::access_controllable::ACL_STORAGE_RECORD_OVERHEAD)
+                .sum()
+        }
+
         impl #acl_type {
             fn new_bearers_set(permission: #bitflags_type) -> ::near_sdk::collections::UnorderedSet<::near_sdk::AccountId> {
                 let base_prefix = <#ident as AccessControllable>::acl_storage_prefix();
@@ -100,6 +331,157 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 ::near_sdk::collections::UnorderedSet::new(__acl_storage_prefix(base_prefix, specifier))
             }
 
+            fn new_role_metadata_map(role: u8) -> ::near_sdk::collections::UnorderedMap<String, String> {
+                let base_prefix = <#ident as AccessControllable>::acl_storage_prefix();
+                let specifier = __AclStorageKey::RoleMetadataMap { role };
+                ::near_sdk::collections::UnorderedMap::new(__acl_storage_prefix(base_prefix, specifier))
+            }
+
+            // Persisted fields of this struct are those of version 1 of the
+            // storage layout. Adding fields would change its borsh layout and
+            // break deserialization of state written by version 1. Instead,
+            // each accessor below returns a handle to state stored under its
+            // own prefix.
+
+            /// Stores admins which approved pending grants of roles with a
+            /// grant threshold, keyed by grantee and role.
+            fn grant_approvals() -> ::near_sdk::collections::LookupMap<
+                (::near_sdk::AccountId, u8),
+                Vec<::near_sdk::AccountId>,
+            > {
+                let base_prefix = <#ident as AccessControllable>::acl_storage_prefix();
+                ::near_sdk::collections::LookupMap::new(
+                    __acl_storage_prefix(base_prefix, __AclStorageKey::GrantApprovals),
+                )
+            }
+
+            /// Stores metadata attached to roles at runtime, keyed by role.
+            fn role_metadata() -> ::near_sdk::collections::LookupMap<
+                u8,
+                ::near_sdk::collections::UnorderedMap<String, String>,
+            > {
+                let base_prefix = <#ident as AccessControllable>::acl_storage_prefix();
+                ::near_sdk::collections::LookupMap::new(
+                    __acl_storage_prefix(base_prefix, __AclStorageKey::RoleMetadata),
+                )
+            }
+
+            /// Stores the remaining uses of consumable roles, keyed by grantee
+            /// and role.
+            fn consumable_uses() -> ::near_sdk::collections::LookupMap<(::near_sdk::AccountId, u8), u32> {
+                let base_prefix = <#ident as AccessControllable>::acl_storage_prefix();
+                ::near_sdk::collections::LookupMap::new(
+                    __acl_storage_prefix(base_prefix, __AclStorageKey::ConsumableUses),
+                )
+            }
+
+            /// Stores which accounts may assume which roles via `assume_role`.
+            fn assumable_roles() -> ::near_sdk::collections::LookupSet<(::near_sdk::AccountId, u8)> {
+                let base_prefix = <#ident as AccessControllable>::acl_storage_prefix();
+                ::near_sdk::collections::LookupSet::new(
+                    __acl_storage_prefix(base_prefix, __AclStorageKey::AssumableRoles),
+                )
+            }
+
+            /// Stores grants of roles scoped to a resource, keyed by scope,
+            /// grantee and role.
+            fn scoped_roles() -> ::near_sdk::collections::LookupSet<(String, ::near_sdk::AccountId, u8)> {
+                let base_prefix = <#ident as AccessControllable>::acl_storage_prefix();
+                ::near_sdk::collections::LookupSet::new(
+                    __acl_storage_prefix(base_prefix, __AclStorageKey::ScopedRoles),
+                )
+            }
+
+            /// Stores the expiry of grants made via `grant_role_with_expiry`,
+            /// keyed by grantee and role.
+            fn role_expiries() -> ::near_sdk::collections::LookupMap<(::near_sdk::AccountId, u8), u64> {
+                let base_prefix = <#ident as AccessControllable>::acl_storage_prefix();
+                ::near_sdk::collections::LookupMap::new(
+                    __acl_storage_prefix(base_prefix, __AclStorageKey::RoleExpiries),
+                )
+            }
+
+            /// Stores the pending super-admin transfer, if any.
+            fn pending_super_admin_transfer(
+            ) -> ::near_sdk::collections::LazyOption<::#cratename::access_controllable::SuperAdminTransfer> {
+                let base_prefix = <#ident as AccessControllable>::acl_storage_prefix();
+                ::near_sdk::collections::LazyOption::new(
+                    __acl_storage_prefix(base_prefix, __AclStorageKey::PendingSuperAdminTransfer),
+                    None,
+                )
+            }
+
+            /// Stores the registry contract used to resolve handles to account
+            /// ids, if any.
+            fn handle_registry() -> ::near_sdk::collections::LazyOption<::near_sdk::AccountId> {
+                let base_prefix = <#ident as AccessControllable>::acl_storage_prefix();
+                ::near_sdk::collections::LazyOption::new(
+                    __acl_storage_prefix(base_prefix, __AclStorageKey::HandleRegistry),
+                    None,
+                )
+            }
+
+            /// Returns all variants of the role type, ordered by their `u8`
+            /// representation.
+            fn all_roles() -> impl Iterator<Item = #role_type> {
+                (0..=u8::MAX).map_while(|i| {
+                    <#role_type as ::std::convert::TryFrom<u8>>::try_from(i).ok()
+                })
+            }
+
+            fn storage_version_key() -> Vec<u8> {
+                let base_prefix = <#ident as AccessControllable>::acl_storage_prefix();
+                __acl_storage_prefix(base_prefix, __AclStorageKey::StorageVersion)
+            }
+
+            /// Returns the version of the storage layout recorded in state, if
+            /// any.
+            fn get_storage_version(&self) -> Option<u32> {
+                ::near_sdk::env::storage_read(&Self::storage_version_key()).map(|value| {
+                    <u32 as ::near_sdk::borsh::BorshDeserialize>::try_from_slice(&value)
+                        .expect("AccessControllable: Invalid storage version")
+                })
+            }
+
+            /// Panics if the storage version recorded in state differs from
+            /// the version expected by this code. The check is performed
+            /// once per receipt. State without a recorded version passes.
+            fn assert_storage_version(&self) {
+                if self.storage_version_checked.get() {
+                    return;
+                }
+                let expected = ::#cratename::access_controllable::ACL_STORAGE_VERSION;
+                if let Some(version) = self.get_storage_version() {
+                    if version != expected {
+                        ::near_sdk::env::panic_str(&format!(
+                            "AccessControllable: Migration required, storage version is {} but {} is expected",
+                            version,
+                            expected,
+                        ));
+                    }
+                }
+                self.storage_version_checked.set(true);
+            }
+
+            fn set_storage_version(&mut self, version: u32) -> bool {
+                if !self.is_super_admin(&::near_sdk::env::predecessor_account_id()) {
+                    return false;
+                }
+                self.set_storage_version_unchecked(version);
+                true
+            }
+
+            /// Records `version` as version of the storage layout __without__
+            /// checking any permissions.
+            fn set_storage_version_unchecked(&mut self, version: u32) {
+                ::near_sdk::env::storage_write(
+                    &Self::storage_version_key(),
+                    &::near_sdk::borsh::BorshSerialize::try_to_vec(&version)
+                        .expect("Storage version should be serializable"),
+                );
+                self.storage_version_checked.set(false);
+            }
+
             fn get_or_init_permissions(&self, account_id: &::near_sdk::AccountId) -> #bitflags_type {
                 match self.permissions.get(account_id) {
                     Some(permissions) => permissions,
@@ -107,6 +489,42 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 }
             }
 
+            /// Writes the `permissions` of `account_id`, keeping the admin
+            /// cache up to date.
+            fn set_permissions(&mut self, account_id: &::near_sdk::AccountId, permissions: &#bitflags_type) {
+                self.permissions.insert(account_id, permissions);
+                if let Some(cache) = self.admin_cache.get_mut() {
+                    cache.insert(account_id.clone(), *permissions);
+                }
+            }
+
+            /// Enables caching of the permissions read by admin checks for
+            /// the remainder of the current receipt. It makes repeated
+            /// `is_admin` and `is_super_admin` checks for the same account
+            /// cheaper, e.g. in governance methods that check permissions in
+            /// a loop.
+            ///
+            /// The cache is not persisted to storage, hence it never outlives
+            /// the receipt.
+            fn enable_admin_cache(&self) {
+                let mut cache = self.admin_cache.borrow_mut();
+                if cache.is_none() {
+                    *cache = Some(::std::collections::HashMap::new());
+                }
+            }
+
+            /// Returns the permissions of `account_id` for admin checks,
+            /// served from the admin cache if it's enabled.
+            fn get_admin_check_permissions(&self, account_id: &::near_sdk::AccountId) -> #bitflags_type {
+                let mut cache = self.admin_cache.borrow_mut();
+                match cache.as_mut() {
+                    Some(cache) => *cache
+                        .entry(account_id.clone())
+                        .or_insert_with(|| self.get_or_init_permissions(account_id)),
+                    None => self.get_or_init_permissions(account_id),
+                }
+            }
+
             fn init_super_admin(&mut self, account_id: &::near_sdk::AccountId) -> bool {
                 let flag = <#bitflags_type>::from_bits(<#role_type>::acl_super_admin_permission())
                     .expect(#ERR_PARSE_BITFLAG);
@@ -121,9 +539,51 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 }
                 let res = self.add_super_admin_unchecked(account_id);
                 assert!(res, "Failed to init super-admin.");
+                if self.get_storage_version().is_none() {
+                    self.set_storage_version_unchecked(::#cratename::access_controllable::ACL_STORAGE_VERSION);
+                }
                 res
             }
 
+            /// Applies `manifest` if there are no super-admins yet. Panics if
+            /// `manifest` is invalid, before modifying any state.
+            fn init_from_manifest(
+                &mut self,
+                manifest: ::#cratename::access_controllable::AclManifest,
+            ) -> bool {
+                if let Err(err) = manifest.validate() {
+                    ::near_sdk::env::panic_str(&err);
+                }
+                let admins: Vec<_> = manifest
+                    .admins
+                    .iter()
+                    .map(|(role, account_id)| (__acl_parse_role(role.as_str()), account_id))
+                    .collect();
+                let grantees: Vec<_> = manifest
+                    .grantees
+                    .iter()
+                    .map(|(role, account_id)| (__acl_parse_role(role.as_str()), account_id))
+                    .collect();
+
+                let (first, others) = manifest
+                    .super_admins
+                    .split_first()
+                    .expect("Manifest should contain a super-admin");
+                if !self.init_super_admin(first) {
+                    return false;
+                }
+                for account_id in others {
+                    self.add_super_admin_unchecked(account_id);
+                }
+                for (role, account_id) in admins {
+                    self.add_admin_unchecked(role, account_id);
+                }
+                for (role, account_id) in grantees {
+                    self.grant_role_unchecked(role, account_id);
+                }
+                true
+            }
+
             /// Makes `account_id` a super-admin __without__ checking any permissions.
             /// It returns whether `account_id` is a new super-admin.
             ///
@@ -136,34 +596,169 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 let is_new_super_admin = !permissions.contains(flag);
                 if is_new_super_admin {
                     permissions.insert(flag);
-                    self.permissions.insert(account_id, &permissions);
+                    self.set_permissions(account_id, &permissions);
                     self.add_bearer(flag, account_id);
 
                     let event = ::#cratename::access_controllable::events::SuperAdminAdded {
                         account: account_id.clone(),
                         by: ::near_sdk::env::predecessor_account_id(),
                     };
-                    event.emit();
+                    __acl_emit(
+                        ::#cratename::access_controllable::events::AclEvent::SuperAdminAdded(event),
+                    );
                 }
 
                 is_new_super_admin
             }
 
+            /// Returns whether `account_id` is the governance account
+            /// configured via `#[access_control(governance = "...")]`.
+            fn is_governance(account_id: &::near_sdk::AccountId) -> bool {
+                #is_governance
+            }
+
+            /// Returns whether `role` may be managed only by super-admins, as
+            /// configured via `#[access_control(super_admin_only_roles(...))]`.
+            fn is_super_admin_only(role: #role_type) -> bool {
+                #is_super_admin_only
+            }
+
             fn is_super_admin(&self, account_id: &::near_sdk::AccountId) -> bool {
-                let permissions = {
-                    match self.permissions.get(account_id) {
-                        Some(permissions) => permissions,
-                        None => return false,
-                    }
-                };
+                if Self::is_governance(account_id) {
+                    return true;
+                }
+                let permissions = self.get_admin_check_permissions(account_id);
                 let super_admin = <#bitflags_type>::from_bits(<#role_type>::acl_super_admin_permission())
                     .expect(#ERR_PARSE_BITFLAG);
                 permissions.contains(super_admin)
             }
 
+            fn revoke_super_admin(
+                &mut self,
+                account_id: &::near_sdk::AccountId,
+                reason: Option<String>,
+            ) -> Option<bool> {
+                if !self.is_super_admin(&::near_sdk::env::predecessor_account_id()) {
+                    return None;
+                }
+                if self.is_last_super_admin(account_id) {
+                    return None;
+                }
+                Some(self.revoke_super_admin_unchecked_with_reason(account_id, reason))
+            }
+
+            fn renounce_super_admin(&mut self) -> bool {
+                let predecessor = ::near_sdk::env::predecessor_account_id();
+                if self.is_last_super_admin(&predecessor) {
+                    return false;
+                }
+                self.revoke_super_admin_unchecked(&predecessor)
+            }
+
+            fn num_super_admins(&self) -> u64 {
+                let flag = <#bitflags_type>::from_bits(<#role_type>::acl_super_admin_permission())
+                    .expect(#ERR_PARSE_BITFLAG);
+                self.num_bearers(flag)
+            }
+
+            /// Returns whether `account_id` is the only account with stored
+            /// super-admin permissions.
+            fn is_last_super_admin(&self, account_id: &::near_sdk::AccountId) -> bool {
+                self.num_super_admins() == 1
+                    && ::#cratename::acl_store::AclStore::has_role(
+                        self,
+                        <#role_type>::acl_super_admin_permission(),
+                        account_id,
+                    )
+            }
+
+            fn propose_super_admin_transfer(&mut self, account_id: &::near_sdk::AccountId) -> Option<bool> {
+                let predecessor = ::near_sdk::env::predecessor_account_id();
+                if !self.is_super_admin(&predecessor) {
+                    return None;
+                }
+                let transfer = ::#cratename::access_controllable::SuperAdminTransfer {
+                    from: predecessor.clone(),
+                    to: account_id.clone(),
+                };
+                if let Some(overwritten) = Self::pending_super_admin_transfer().replace(&transfer) {
+                    let event = ::#cratename::access_controllable::events::SuperAdminTransferCancelled {
+                        from: overwritten.from,
+                        to: overwritten.to,
+                        by: predecessor.clone(),
+                    };
+                    __acl_emit(
+                        ::#cratename::access_controllable::events::AclEvent::SuperAdminTransferCancelled(event),
+                    );
+                }
+                let event = ::#cratename::access_controllable::events::SuperAdminTransferProposed {
+                    from: transfer.from,
+                    to: transfer.to,
+                    by: predecessor,
+                };
+                __acl_emit(
+                    ::#cratename::access_controllable::events::AclEvent::SuperAdminTransferProposed(event),
+                );
+                Some(true)
+            }
+
+            fn accept_super_admin_transfer(&mut self) -> bool {
+                let predecessor = ::near_sdk::env::predecessor_account_id();
+                let transfer = match Self::pending_super_admin_transfer().get() {
+                    Some(transfer) if transfer.to == predecessor => transfer,
+                    _ => return false,
+                };
+                if !self.is_super_admin(&transfer.from) {
+                    return false;
+                }
+                Self::pending_super_admin_transfer().remove();
+                self.add_super_admin_unchecked(&transfer.to);
+                if transfer.from != transfer.to {
+                    self.revoke_super_admin_unchecked(&transfer.from);
+                }
+                let event = ::#cratename::access_controllable::events::SuperAdminTransferAccepted {
+                    from: transfer.from,
+                    to: transfer.to,
+                };
+                __acl_emit(
+                    ::#cratename::access_controllable::events::AclEvent::SuperAdminTransferAccepted(event),
+                );
+                true
+            }
+
+            fn cancel_super_admin_transfer(&mut self) -> Option<bool> {
+                let predecessor = ::near_sdk::env::predecessor_account_id();
+                if !self.is_super_admin(&predecessor) {
+                    return None;
+                }
+                let transfer = match Self::pending_super_admin_transfer().take() {
+                    Some(transfer) => transfer,
+                    None => return Some(false),
+                };
+                let event = ::#cratename::access_controllable::events::SuperAdminTransferCancelled {
+                    from: transfer.from,
+                    to: transfer.to,
+                    by: predecessor,
+                };
+                __acl_emit(
+                    ::#cratename::access_controllable::events::AclEvent::SuperAdminTransferCancelled(event),
+                );
+                Some(true)
+            }
+
             /// Revokes super-admin permissions from `account_id` without checking any
             /// permissions. It returns whether `account_id` was a super-admin.
             fn revoke_super_admin_unchecked(&mut self, account_id: &::near_sdk::AccountId) -> bool {
+                self.revoke_super_admin_unchecked_with_reason(account_id, None)
+            }
+
+            /// Like `revoke_super_admin_unchecked`, additionally including
+            /// `reason` in the emitted event.
+            fn revoke_super_admin_unchecked_with_reason(
+                &mut self,
+                account_id: &::near_sdk::AccountId,
+                reason: Option<String>,
+            ) -> bool {
                 let flag = <#bitflags_type>::from_bits(<#role_type>::acl_super_admin_permission())
                     .expect(#ERR_PARSE_BITFLAG);
                 let mut permissions = self.get_or_init_permissions(account_id);
@@ -171,14 +766,17 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 let was_super_admin = permissions.contains(flag);
                 if was_super_admin {
                     permissions.remove(flag);
-                    self.permissions.insert(account_id, &permissions);
+                    self.set_permissions(account_id, &permissions);
                     self.remove_bearer(flag, account_id);
 
                     let event = ::#cratename::access_controllable::events::SuperAdminRevoked {
                         account: account_id.clone(),
                         by: ::near_sdk::env::predecessor_account_id(),
+                        reason,
                     };
-                    event.emit();
+                    __acl_emit(
+                        ::#cratename::access_controllable::events::AclEvent::SuperAdminRevoked(event),
+                    );
                 }
 
                 was_super_admin
@@ -191,6 +789,22 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 Some(self.add_admin_unchecked(role, account_id))
             }
 
+            /// Makes `first_admin` the initial admin for `role`, provided that
+            /// the predecessor is a super-admin. Returns `Some(false)` without
+            /// modifying state if `role` already has admins.
+            fn bootstrap_role(&mut self, role: #role_type, first_admin: &::near_sdk::AccountId) -> Option<bool> {
+                if !self.is_super_admin(&::near_sdk::env::predecessor_account_id()) {
+                    return None;
+                }
+                let flag = <#bitflags_type>::from_bits(role.acl_admin_permission())
+                    .expect(#ERR_PARSE_BITFLAG);
+                let has_admins = self.bearers.get(&flag).map_or(false, |set| !set.is_empty());
+                if has_admins {
+                    return Some(false);
+                }
+                Some(self.add_admin_unchecked(role, first_admin))
+            }
+
             /// Makes `account_id` an admin for role, __without__ checking any
             /// permissions. Returns whether `account_id` is a new admin for `role`.
             ///
@@ -203,7 +817,7 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 let is_new_admin = !permissions.contains(flag);
                 if is_new_admin {
                     permissions.insert(flag);
-                    self.permissions.insert(account_id, &permissions);
+                    self.set_permissions(account_id, &permissions);
                     self.add_bearer(flag, account_id);
 
                     let event = ::#cratename::access_controllable::events::AdminAdded {
@@ -211,21 +825,24 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                         account: account_id.clone(),
                         by: ::near_sdk::env::predecessor_account_id(),
                     };
-                    event.emit();
+                    __acl_emit(
+                        ::#cratename::access_controllable::events::AclEvent::AdminAdded(event),
+                    );
                 }
 
                 is_new_admin
             }
 
             fn is_admin(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
-                let permissions = {
-                    match self.permissions.get(account_id) {
-                        Some(permissions) => permissions,
-                        None => return false,
-                    }
-                };
+                if Self::is_governance(account_id) {
+                    return true;
+                }
+                let permissions = self.get_admin_check_permissions(account_id);
                 let super_admin = <#bitflags_type>::from_bits(<#role_type>::acl_super_admin_permission())
                     .expect(#ERR_PARSE_BITFLAG);
+                if Self::is_super_admin_only(role) {
+                    return permissions.contains(super_admin);
+                }
                 let role_admin = <#bitflags_type>::from_bits(role.acl_admin_permission())
                     .expect(#ERR_PARSE_BITFLAG);
                 permissions.contains(super_admin) || permissions.contains(role_admin)
@@ -238,8 +855,26 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 Some(self.revoke_admin_unchecked(role, account_id))
             }
 
-            fn renounce_admin(&mut self, role: #role_type) -> bool {
-                self.revoke_admin_unchecked(role, &::near_sdk::env::predecessor_account_id())
+            /// Revokes admin permissions for `role` from the predecessor.
+            /// Unless `force` is true, it is a no-op returning `false` if the
+            /// predecessor is the last admin for `role` while `role` has
+            /// grantees. Super-admins are not subject to this check.
+            fn renounce_admin(&mut self, role: #role_type, force: bool) -> bool {
+                let predecessor = ::near_sdk::env::predecessor_account_id();
+                if !force && !self.is_super_admin(&predecessor) {
+                    let admin_flag = <#bitflags_type>::from_bits(role.acl_admin_permission())
+                        .expect(#ERR_PARSE_BITFLAG);
+                    let flag = <#bitflags_type>::from_bits(role.acl_permission())
+                        .expect(#ERR_PARSE_BITFLAG);
+                    let admins = self.bearers.get(&admin_flag).map_or(0, |set| set.len());
+                    let grantees = self.num_bearers(flag);
+                    let is_last_admin = admins == 1
+                        && ::#cratename::acl_store::AclStore::has_role(self, admin_flag.bits(), &predecessor);
+                    if is_last_admin && grantees > 0 {
+                        return false;
+                    }
+                }
+                self.revoke_admin_unchecked(role, &predecessor)
             }
 
             /// Revokes admin permissions from `account_id` __without__ checking any
@@ -252,93 +887,817 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 let was_admin = permissions.contains(flag);
                 if was_admin {
                     permissions.remove(flag);
-                    self.permissions.insert(account_id, &permissions);
+                    self.set_permissions(account_id, &permissions);
                     self.remove_bearer(flag, account_id);
 
                     let event = ::#cratename::access_controllable::events::AdminRevoked {
                         role: role.into(),
                         account: account_id.clone(),
                         by: ::near_sdk::env::predecessor_account_id(),
+                        correlation_id: self.correlation_id.clone(),
                     };
-                    event.emit();
+                    __acl_emit(
+                        ::#cratename::access_controllable::events::AclEvent::AdminRevoked(event),
+                    );
                 }
 
                 was_admin
             }
 
             fn grant_role(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> Option<bool> {
-                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                let predecessor = ::near_sdk::env::predecessor_account_id();
+                if !self.is_admin(role, &predecessor) {
                     return None;
                 }
+                let threshold = usize::from(role.acl_grant_threshold());
+                if threshold > 1 && !self.has_role(role, account_id) {
+                    let key = (account_id.clone(), u8::from(role));
+                    // Approvals of admins that were revoked meanwhile no
+                    // longer count.
+                    let mut approvals: Vec<_> = Self::grant_approvals()
+                        .get(&key)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter(|approver| self.is_admin(role, approver))
+                        .collect();
+                    if !approvals.contains(&predecessor) {
+                        approvals.push(predecessor);
+                    }
+                    if approvals.len() < threshold {
+                        Self::grant_approvals().insert(&key, &approvals);
+                        return Some(false);
+                    }
+                    Self::grant_approvals().remove(&key);
+                }
                 Some(self.grant_role_unchecked(role, account_id))
             }
 
-            /// Grants `role` to `account_id` __without__ checking any permissions.
-            /// Returns whether `role` was newly granted to `account_id`.
-            fn grant_role_unchecked(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
-                let flag = <#bitflags_type>::from_bits(role.acl_permission())
-                    .expect(#ERR_PARSE_BITFLAG);
-                let mut permissions = self.get_or_init_permissions(account_id);
-
-                let is_new_grantee = !permissions.contains(flag);
-                if is_new_grantee {
-                    permissions.insert(flag);
-                    self.permissions.insert(account_id, &permissions);
-                    self.add_bearer(flag, account_id);
-
-                    let event = ::#cratename::access_controllable::events::RoleGranted {
-                        role: role.into(),
-                        by: ::near_sdk::env::predecessor_account_id(),
-                        to: account_id.clone(),
-                    };
-                    event.emit();
+            fn grant_roles(
+                &mut self,
+                role: #role_type,
+                account_ids: &[::near_sdk::AccountId],
+                correlation_id: Option<String>,
+            ) -> Vec<Option<bool>> {
+                let max_len = ::#cratename::access_controllable::ACL_GRANT_ROLES_MAX_LEN;
+                assert!(
+                    account_ids.len() <= max_len,
+                    "Granting roles accepts at most {} accounts",
+                    max_len,
+                );
+                self.assert_no_grant_threshold(role);
+                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                    return vec![None; account_ids.len()];
                 }
-
-                is_new_grantee
+                // Later occurrences of an account yield `false`, since it is
+                // a grantee already.
+                self.correlation_id = correlation_id;
+                let results = account_ids
+                    .iter()
+                    .map(|account_id| Some(self.grant_role_unchecked(role, account_id)))
+                    .collect();
+                self.correlation_id = None;
+                results
             }
 
-            fn revoke_role(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> Option<bool> {
+            fn apply_membership_change(
+                &mut self,
+                role: #role_type,
+                add: &[::near_sdk::AccountId],
+                remove: &[::near_sdk::AccountId],
+                correlation_id: Option<String>,
+            ) -> Option<::#cratename::access_controllable::MembershipChangeResult> {
+                let max_len = ::#cratename::access_controllable::ACL_GRANT_ROLES_MAX_LEN;
+                assert!(
+                    add.len() + remove.len() <= max_len,
+                    "Membership changes accept at most {} accounts",
+                    max_len,
+                );
+                self.assert_no_grant_threshold(role);
+                let removed_set: ::std::collections::HashSet<_> = remove.iter().collect();
+                if let Some(account_id) = add.iter().find(|account_id| removed_set.contains(account_id)) {
+                    ::near_sdk::env::panic_str(&format!(
+                        "Account {} is both added and removed",
+                        account_id,
+                    ));
+                }
                 if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
                     return None;
                 }
-                Some(self.revoke_role_unchecked(role, account_id))
+                self.correlation_id = correlation_id;
+                let removed = remove
+                    .iter()
+                    .filter(|account_id| self.revoke_role_unchecked(role, account_id))
+                    .cloned()
+                    .collect();
+                let added = add
+                    .iter()
+                    .filter(|account_id| self.grant_role_unchecked(role, account_id))
+                    .cloned()
+                    .collect();
+                self.correlation_id = None;
+                Some(::#cratename::access_controllable::MembershipChangeResult { added, removed })
             }
 
-            fn renounce_role(&mut self, role: #role_type) -> bool {
-                self.revoke_role_unchecked(role, &::near_sdk::env::predecessor_account_id())
+            /// Panics if granting `role` requires approvals of multiple
+            /// admins. Approvals are collected only by `grant_role`, hence
+            /// all other methods granting roles reject such roles.
+            fn assert_no_grant_threshold(&self, role: #role_type) {
+                if role.acl_grant_threshold() > 1 {
+                    ::near_sdk::env::panic_str(&format!(
+                        "AccessControllable: Role {} requires approvals via acl_grant_role",
+                        <&'static str>::from(role),
+                    ));
+                }
             }
 
-            fn revoke_role_unchecked(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
-                let flag = <#bitflags_type>::from_bits(role.acl_permission())
-                    .expect(#ERR_PARSE_BITFLAG);
-                let mut permissions = self.get_or_init_permissions(account_id);
+            /// Returns the admins which approved granting `role` to
+            /// `account_id`, while the grant is pending.
+            fn get_grant_approvals(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> Vec<::near_sdk::AccountId> {
+                Self::grant_approvals()
+                    .get(&(account_id.clone(), u8::from(role)))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|approver| self.is_admin(role, approver))
+                    .collect()
+            }
 
-                let was_grantee = permissions.contains(flag);
-                if was_grantee {
-                    permissions.remove(flag);
-                    self.permissions.insert(account_id, &permissions);
-                    self.remove_bearer(flag, account_id);
+            /// Grants `role` to `account_id` __without__ checking any permissions.
+            /// Returns whether `role` was newly granted to `account_id`.
+            fn grant_role_unchecked(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
+                self.grant_role_unchecked_by(role, account_id, ::near_sdk::env::predecessor_account_id())
+            }
 
-                    let event = ::#cratename::access_controllable::events::RoleRevoked {
-                        role: role.into(),
-                        from: account_id.clone(),
-                        by: ::near_sdk::env::predecessor_account_id(),
-                    };
-                    event.emit();
+            /// Like `grant_role_unchecked`, but the emitted event specifies
+            /// that the role was granted `by` the given account.
+            fn grant_role_unchecked_by(
+                &mut self,
+                role: #role_type,
+                account_id: &::near_sdk::AccountId,
+                by: ::near_sdk::AccountId,
+            ) -> bool {
+                self.remove_expired_grant(role, account_id);
+                let is_new_grantee = ::#cratename::acl_store::AclStore::add_grantee(
+                    self,
+                    role.acl_permission(),
+                    account_id,
+                );
+                if is_new_grantee {
+                    self.emit_role_granted(role, account_id, by);
                 }
 
-                was_grantee
+                is_new_grantee
             }
 
-            fn has_role(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
-                match self.permissions.get(account_id) {
-                    Some(permissions) => {
-                        let flag = <#bitflags_type>::from_bits(role.acl_permission())
-                            .expect(#ERR_PARSE_BITFLAG);
-                        permissions.contains(flag)
-                    }
-                    None => false,
-                }
+            /// Emits `RoleGranted` including the current expiry of the grant.
+            fn emit_role_granted(
+                &self,
+                role: #role_type,
+                account_id: &::near_sdk::AccountId,
+                by: ::near_sdk::AccountId,
+            ) {
+                let event = ::#cratename::access_controllable::events::RoleGranted {
+                    role: role.into(),
+                    by,
+                    to: account_id.clone(),
+                    correlation_id: self.correlation_id.clone(),
+                    expires_at_ns: Self::role_expiries().get(&(account_id.clone(), u8::from(role))),
+                };
+                __acl_emit(
+                    ::#cratename::access_controllable::events::AclEvent::RoleGranted(event),
+                );
+            }
+
+            fn set_handle_registry(&mut self, registry: Option<::near_sdk::AccountId>) -> bool {
+                if !self.is_super_admin(&::near_sdk::env::predecessor_account_id()) {
+                    return false;
+                }
+                let mut handle_registry = Self::handle_registry();
+                match registry {
+                    Some(registry) => handle_registry.set(&registry),
+                    None => handle_registry.remove().is_some(),
+                };
+                true
+            }
+
+            /// Sets the metadata of `role` under `key` to `value`, provided
+            /// that the predecessor is an admin for `role`. Returns whether
+            /// the predecessor has sufficient permissions.
+            fn set_role_metadata(&mut self, role: #role_type, key: String, value: String) -> bool {
+                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                    return false;
+                }
+                let role = u8::from(role);
+                let mut map = Self::role_metadata()
+                    .get(&role)
+                    .unwrap_or_else(|| Self::new_role_metadata_map(role));
+                map.insert(&key, &value);
+                Self::role_metadata().insert(&role, &map);
+                true
+            }
+
+            fn get_role_metadata(&self, role: #role_type, key: &String) -> Option<String> {
+                Self::role_metadata().get(&u8::from(role))?.get(key)
+            }
+
+            /// Returns a page of up to `limit` metadata keys of `role`,
+            /// starting at the position encoded in `cursor`.
+            fn get_role_metadata_keys(
+                &self,
+                role: #role_type,
+                cursor: Option<String>,
+                limit: u64,
+            ) -> ::#cratename::pagination::Page<String> {
+                let position = ::#cratename::pagination::decode_cursor(cursor.as_deref());
+                let map = match Self::role_metadata().get(&u8::from(role)) {
+                    Some(map) => map,
+                    None => return ::#cratename::pagination::Page::new(vec![], position, 0),
+                };
+                let skip = usize::try_from(position).expect("skip should be in the range of usize");
+                let limit = usize::try_from(limit).expect("limit should be in the range of usize");
+                let items = map.keys().skip(skip).take(limit).collect();
+                ::#cratename::pagination::Page::new(items, position, map.len())
+            }
+
+            /// Resolves `handle` via the handle registry and grants `role` to
+            /// the resolved account in a callback, provided that the
+            /// predecessor is an admin for `role`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if no handle registry is configured.
+            fn grant_role_by_handle(
+                &mut self,
+                role: #role_type,
+                handle: String,
+            ) -> ::near_sdk::PromiseOrValue<Option<bool>> {
+                let caller = ::near_sdk::env::predecessor_account_id();
+                if !self.is_admin(role, &caller) {
+                    return ::near_sdk::PromiseOrValue::Value(None);
+                }
+                let registry = Self::handle_registry()
+                    .get()
+                    .expect("AccessControllable: Handle registry is not configured");
+
+                let resolve_args = ::near_sdk::serde_json::json!({ "handle": handle });
+                let callback_args = ::near_sdk::serde_json::json!({
+                    "role": String::from(role),
+                    "handle": handle,
+                    "caller": caller,
+                });
+                let promise = ::near_sdk::Promise::new(registry)
+                    .function_call(
+                        ::#cratename::access_controllable::ACL_HANDLE_REGISTRY_RESOLVE_METHOD.to_string(),
+                        resolve_args.to_string().into_bytes(),
+                        0,
+                        ::#cratename::access_controllable::ACL_RESOLVE_HANDLE_GAS,
+                    )
+                    .then(::near_sdk::Promise::new(::near_sdk::env::current_account_id()).function_call(
+                        "acl_on_handle_resolved".to_string(),
+                        callback_args.to_string().into_bytes(),
+                        0,
+                        ::#cratename::access_controllable::ACL_ON_HANDLE_RESOLVED_GAS,
+                    ));
+                ::near_sdk::PromiseOrValue::Promise(promise)
+            }
+
+            /// Callback of `grant_role_by_handle`. Admin permissions of
+            /// `caller` are checked again, since they might have been revoked
+            /// while the handle was resolved.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `handle` could not be resolved.
+            fn on_handle_resolved(
+                &mut self,
+                role: #role_type,
+                handle: &str,
+                caller: ::near_sdk::AccountId,
+            ) -> Option<bool> {
+                let account_id = match ::near_sdk::env::promise_result(0) {
+                    ::near_sdk::PromiseResult::Successful(value) => {
+                        ::near_sdk::serde_json::from_slice::<Option<::near_sdk::AccountId>>(&value)
+                            .ok()
+                            .flatten()
+                    }
+                    _ => None,
+                };
+                let account_id = account_id.unwrap_or_else(|| {
+                    ::near_sdk::env::panic_str(&format!(
+                        "AccessControllable: Failed to resolve handle {}",
+                        handle,
+                    ))
+                });
+                if !self.is_admin(role, &caller) {
+                    return None;
+                }
+                self.assert_no_grant_threshold(role);
+                Some(self.grant_role_unchecked_by(role, &account_id, caller))
+            }
+
+            fn grant_role_scoped(
+                &mut self,
+                role: #role_type,
+                scope: String,
+                account_id: &::near_sdk::AccountId,
+            ) -> Option<bool> {
+                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                    return None;
+                }
+                let is_new_grantee = Self::scoped_roles()
+                    .insert(&(scope.clone(), account_id.clone(), u8::from(role)));
+                if is_new_grantee {
+                    let event = ::#cratename::access_controllable::events::ScopedRoleGranted {
+                        role: role.into(),
+                        scope,
+                        to: account_id.clone(),
+                        by: ::near_sdk::env::predecessor_account_id(),
+                    };
+                    __acl_emit(
+                        ::#cratename::access_controllable::events::AclEvent::ScopedRoleGranted(event),
+                    );
+                }
+                Some(is_new_grantee)
+            }
+
+            fn revoke_role_scoped(
+                &mut self,
+                role: #role_type,
+                scope: String,
+                account_id: &::near_sdk::AccountId,
+            ) -> Option<bool> {
+                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                    return None;
+                }
+                let was_grantee = Self::scoped_roles()
+                    .remove(&(scope.clone(), account_id.clone(), u8::from(role)));
+                if was_grantee {
+                    let event = ::#cratename::access_controllable::events::ScopedRoleRevoked {
+                        role: role.into(),
+                        scope,
+                        from: account_id.clone(),
+                        by: ::near_sdk::env::predecessor_account_id(),
+                    };
+                    __acl_emit(
+                        ::#cratename::access_controllable::events::AclEvent::ScopedRoleRevoked(event),
+                    );
+                }
+                Some(was_grantee)
+            }
+
+            fn has_role_scoped(&self, role: #role_type, scope: String, account_id: &::near_sdk::AccountId) -> bool {
+                Self::scoped_roles()
+                    .contains(&(scope, account_id.clone(), u8::from(role)))
+            }
+
+            fn has_any_role_scoped(
+                &self,
+                roles: &[#role_type],
+                scope: &str,
+                account_id: &::near_sdk::AccountId,
+            ) -> bool {
+                roles
+                    .iter()
+                    .any(|&role| self.has_role_scoped(role, scope.to_string(), account_id))
+            }
+
+            /// Estimates the storage in bytes added by granting `role` to
+            /// `account_id`. Returns 0 if `account_id` is a grantee of `role`
+            /// already.
+            fn estimate_grant_storage(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> u64 {
+                if self.has_role(role, account_id) {
+                    return 0;
+                }
+                let flag = <#bitflags_type>::from_bits(role.acl_permission())
+                    .expect(#ERR_PARSE_BITFLAG);
+                let base_prefix = <#ident as AccessControllable>::acl_storage_prefix();
+                let len = |bytes: Vec<u8>| bytes.len() as u64;
+                let account_len = len(account_id.try_to_vec().expect("AccountId should be serializable"));
+                let flag_len = len(flag.try_to_vec().expect("Permission should be serializable"));
+
+                let mut storage = 0;
+                // A new `permissions` entry, unless existing permissions are updated.
+                if self.permissions.get(account_id).is_none() {
+                    let prefix_len = len(__acl_storage_prefix(base_prefix, __AclStorageKey::Permissions));
+                    storage += __acl_unordered_insert_storage(prefix_len, account_len, Some(flag_len));
+                }
+                // A new set of bearers, if `role` has no bearers yet.
+                if self.bearers.get(&flag).is_none() {
+                    let prefix_len = len(__acl_storage_prefix(base_prefix, __AclStorageKey::Bearers));
+                    let set_len = len(Self::new_bearers_set(flag).try_to_vec().expect("Set should be serializable"));
+                    storage += __acl_unordered_insert_storage(prefix_len, flag_len, Some(set_len));
+                }
+                // Adding `account_id` to the set of bearers.
+                let prefix_len = len(__acl_storage_prefix(base_prefix, __AclStorageKey::BearersSet { permission: flag }));
+                storage += __acl_unordered_insert_storage(prefix_len, account_len, None);
+                storage
+            }
+
+            fn revoke_role(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> Option<bool> {
+                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                    return None;
+                }
+                Some(self.revoke_role_unchecked(role, account_id))
+            }
+
+            /// Like `revoke_role`, additionally notifying `account_id` if the
+            /// role was revoked, see
+            /// `AccessControllable::acl_revoke_role_notify`.
+            fn revoke_role_notify(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> Option<bool> {
+                let was_grantee = self.revoke_role(role, account_id)?;
+                if was_grantee {
+                    let args = ::near_sdk::serde_json::json!({ "role": String::from(role) });
+                    // The promise is not awaited, hence its failure is ignored.
+                    ::near_sdk::Promise::new(account_id.clone()).function_call(
+                        ::#cratename::access_controllable::ACL_ON_ROLE_REVOKED_METHOD.to_string(),
+                        args.to_string().into_bytes(),
+                        0,
+                        ::#cratename::access_controllable::ACL_ON_ROLE_REVOKED_GAS,
+                    );
+                }
+                Some(was_grantee)
+            }
+
+            fn renounce_role(&mut self, role: #role_type) -> bool {
+                self.revoke_role_unchecked(role, &::near_sdk::env::predecessor_account_id())
+            }
+
+            fn revoke_role_unchecked(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
+                let was_grantee = self.remove_role_grant(role, account_id);
+                if was_grantee {
+                    let event = ::#cratename::access_controllable::events::RoleRevoked {
+                        role: role.into(),
+                        from: account_id.clone(),
+                        by: ::near_sdk::env::predecessor_account_id(),
+                        correlation_id: self.correlation_id.clone(),
+                    };
+                    __acl_emit(
+                        ::#cratename::access_controllable::events::AclEvent::RoleRevoked(event),
+                    );
+                }
+
+                was_grantee
+            }
+
+            /// Removes the grant of `role` to `account_id` along with its
+            /// uses, expiry and pending approvals __without__ checking any
+            /// permissions or emitting an event. Returns whether `account_id`
+            /// was a grantee.
+            fn remove_role_grant(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
+                // A revoke resets pending approvals to grant the role.
+                Self::grant_approvals().remove(&(account_id.clone(), u8::from(role)));
+                let was_grantee = ::#cratename::acl_store::AclStore::remove_grantee(
+                    self,
+                    role.acl_permission(),
+                    account_id,
+                );
+                if was_grantee {
+                    Self::consumable_uses().remove(&(account_id.clone(), u8::from(role)));
+                    Self::role_expiries().remove(&(account_id.clone(), u8::from(role)));
+                }
+
+                was_grantee
+            }
+
+            fn revoke_role_from_all(
+                &mut self,
+                role: #role_type,
+                limit: u64,
+                correlation_id: Option<String>,
+            ) -> Option<::#cratename::access_controllable::RevokeRoleFromAllProgress> {
+                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                    return None;
+                }
+                let flag = <#bitflags_type>::from_bits(role.acl_permission())
+                    .expect(#ERR_PARSE_BITFLAG);
+                // Expired grants are revoked as well, which removes them from
+                // storage.
+                let limit = usize::try_from(limit).expect("limit should be in the range of usize");
+                let grantees = ::#cratename::acl_store::get_grantees(self, flag.bits(), 0, limit);
+                let mut removed = 0;
+                for account_id in grantees.iter() {
+                    if self.remove_role_grant(role, account_id) {
+                        removed += 1;
+                    }
+                }
+                // A single event keeps gas bounded, see
+                // `AccessControllable::acl_revoke_role_from_all`.
+                if removed > 0 {
+                    let event = ::#cratename::access_controllable::events::RoleCleared {
+                        role: role.into(),
+                        count: removed,
+                        by: ::near_sdk::env::predecessor_account_id(),
+                        correlation_id,
+                    };
+                    __acl_emit(
+                        ::#cratename::access_controllable::events::AclEvent::RoleCleared(event),
+                    );
+                }
+                let remaining = match self.bearers.get(&flag) {
+                    Some(set) => set.len(),
+                    None => 0,
+                };
+                Some(::#cratename::access_controllable::RevokeRoleFromAllProgress { removed, remaining })
+            }
+
+            fn grant_consumable_role(
+                &mut self,
+                role: #role_type,
+                account_id: &::near_sdk::AccountId,
+                uses: u32,
+            ) -> Option<bool> {
+                assert!(uses > 0, "A consumable role requires at least one use");
+                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                    return None;
+                }
+                self.assert_no_grant_threshold(role);
+                let is_new_grantee = self.grant_role_unchecked(role, account_id);
+                Self::consumable_uses().insert(&(account_id.clone(), u8::from(role)), &uses);
+                Some(is_new_grantee)
+            }
+
+            fn grant_role_with_expiry(
+                &mut self,
+                role: #role_type,
+                account_id: &::near_sdk::AccountId,
+                expires_at_ns: u64,
+            ) -> Option<bool> {
+                assert!(
+                    expires_at_ns > ::near_sdk::env::block_timestamp(),
+                    "The expiry of a grant must be in the future",
+                );
+                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                    return None;
+                }
+                self.assert_no_grant_threshold(role);
+                // Removing an expired grant first makes it count as new grant.
+                self.remove_expired_grant(role, account_id);
+                Self::role_expiries().insert(&(account_id.clone(), u8::from(role)), &expires_at_ns);
+                let is_new_grantee = self.grant_role_unchecked(role, account_id);
+                if !is_new_grantee {
+                    // The expiry of an existing grant changed, which indexers
+                    // learn from the event.
+                    self.emit_role_granted(role, account_id, ::near_sdk::env::predecessor_account_id());
+                }
+                Some(is_new_grantee)
+            }
+
+            fn get_role_expiry(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> Option<u64> {
+                if !self.has_role(role, account_id) {
+                    return None;
+                }
+                Self::role_expiries().get(&(account_id.clone(), u8::from(role)))
+            }
+
+            /// Returns whether the grant of `role` to `account_id` has an
+            /// expiry which the current block timestamp has passed.
+            fn is_expired(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
+                Self::role_expiries()
+                    .get(&(account_id.clone(), u8::from(role)))
+                    .map_or(false, |expires_at_ns| ::near_sdk::env::block_timestamp() > expires_at_ns)
+            }
+
+            /// Removes the grant of `role` to `account_id` if it is expired.
+            /// No event is emitted, since indexers can derive the expiry from
+            /// the `RoleGranted` event.
+            fn remove_expired_grant(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) {
+                if self.is_expired(role, account_id) {
+                    ::#cratename::acl_store::AclStore::remove_grantee(
+                        self,
+                        role.acl_permission(),
+                        account_id,
+                    );
+                    Self::role_expiries().remove(&(account_id.clone(), u8::from(role)));
+                }
+            }
+
+            /// Like `get_bearers` for the grantees of `role`, but skips
+            /// expired grants.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `skip` or `limit` are outside the range of `usize`.
+            fn get_grantees(&self, role: #role_type, skip: u64, limit: u64) -> Vec<::near_sdk::AccountId> {
+                let skip = usize::try_from(skip).expect("skip should be in the range of usize");
+                let limit = usize::try_from(limit).expect("limit should be in the range of usize");
+                ::#cratename::acl_store::AclStore::iter_grantees(self, role.acl_permission())
+                    .filter(|account_id| !self.is_expired(role, account_id))
+                    .skip(skip)
+                    .take(limit)
+                    .collect()
+            }
+
+            fn get_remaining_uses(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> Option<u32> {
+                if !self.has_role(role, account_id) {
+                    return None;
+                }
+                Self::consumable_uses().get(&(account_id.clone(), u8::from(role)))
+            }
+
+            /// Consumes one use of a consumable role in `roles` held by
+            /// `account_id`, revoking the role once its last use is consumed.
+            ///
+            /// Nothing is consumed if `account_id` holds any of `roles`
+            /// without a limit on uses. Otherwise a use of the first
+            /// consumable role in `roles` held by `account_id` is consumed.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a use is to be consumed while the contract is in
+            /// read-only mode.
+            fn consume_role_use(&mut self, roles: &[#role_type], account_id: &::near_sdk::AccountId) {
+                let mut consumable = None;
+                for &role in roles {
+                    if !self.has_role(role, account_id) {
+                        continue;
+                    }
+                    match Self::consumable_uses().get(&(account_id.clone(), u8::from(role))) {
+                        None => return,
+                        Some(uses) => {
+                            if consumable.is_none() {
+                                consumable = Some((role, uses));
+                            }
+                        }
+                    }
+                }
+
+                if let Some((role, uses)) = consumable {
+                    ::#cratename::read_only::assert_not_read_only();
+                    if uses > 1 {
+                        Self::consumable_uses().insert(&(account_id.clone(), u8::from(role)), &(uses - 1));
+                    } else {
+                        self.revoke_role_unchecked(role, account_id);
+                    }
+                }
+            }
+
+            fn allow_assume_role(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> Option<bool> {
+                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                    return None;
+                }
+                let is_new = Self::assumable_roles().insert(&(account_id.clone(), u8::from(role)));
+                if is_new {
+                    let event = ::#cratename::access_controllable::events::AssumableRoleAllowed {
+                        role: role.into(),
+                        account: account_id.clone(),
+                        by: ::near_sdk::env::predecessor_account_id(),
+                    };
+                    __acl_emit(
+                        ::#cratename::access_controllable::events::AclEvent::AssumableRoleAllowed(event),
+                    );
+                }
+                Some(is_new)
+            }
+
+            fn disallow_assume_role(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> Option<bool> {
+                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                    return None;
+                }
+                let was_allowed = Self::assumable_roles().remove(&(account_id.clone(), u8::from(role)));
+                if was_allowed {
+                    let event = ::#cratename::access_controllable::events::AssumableRoleDisallowed {
+                        role: role.into(),
+                        account: account_id.clone(),
+                        by: ::near_sdk::env::predecessor_account_id(),
+                    };
+                    __acl_emit(
+                        ::#cratename::access_controllable::events::AclEvent::AssumableRoleDisallowed(event),
+                    );
+                }
+                Some(was_allowed)
+            }
+
+            /// Activates `role` for the predecessor until the end of the
+            /// current receipt, provided it is allowed to assume `role`.
+            /// Returns whether the role was assumed.
+            fn assume_role(&mut self, role: #role_type) -> bool {
+                let account_id = ::near_sdk::env::predecessor_account_id();
+                let key = (account_id.clone(), u8::from(role));
+                if !Self::assumable_roles().contains(&key) {
+                    return false;
+                }
+                if self.assumed_roles.insert(key) {
+                    let event = ::#cratename::access_controllable::events::RoleAssumed {
+                        role: role.into(),
+                        account: account_id,
+                    };
+                    __acl_emit(
+                        ::#cratename::access_controllable::events::AclEvent::RoleAssumed(event),
+                    );
+                }
+                true
+            }
+
+            fn is_assumed_role(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
+                !self.assumed_roles.is_empty()
+                    && self.assumed_roles.contains(&(account_id.clone(), u8::from(role)))
+            }
+
+            /// Revokes `role` or, if `admin` is true, admin permissions for
+            /// `role` from each of `account_ids`, provided that the
+            /// predecessor is an admin for `role`.
+            fn revoke_batch(
+                &mut self,
+                role: #role_type,
+                account_ids: &[::near_sdk::AccountId],
+                admin: bool,
+                correlation_id: Option<String>,
+            ) -> Option<Vec<bool>> {
+                let max_len = ::#cratename::access_controllable::ACL_REVOKE_BATCH_MAX_LEN;
+                assert!(
+                    account_ids.len() <= max_len,
+                    "Batch revoke accepts at most {} accounts",
+                    max_len,
+                );
+                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                    return None;
+                }
+                self.correlation_id = correlation_id;
+                let results = account_ids
+                    .iter()
+                    .map(|account_id| {
+                        if admin {
+                            self.revoke_admin_unchecked(role, account_id)
+                        } else {
+                            self.revoke_role_unchecked(role, account_id)
+                        }
+                    })
+                    .collect();
+                self.correlation_id = None;
+                Some(results)
+            }
+
+            fn transfer_role_bulk(
+                &mut self,
+                role: #role_type,
+                mappings: &[(::near_sdk::AccountId, ::near_sdk::AccountId)],
+                correlation_id: Option<String>,
+            ) -> Option<Vec<bool>> {
+                let max_len = ::#cratename::access_controllable::ACL_TRANSFER_ROLE_BULK_MAX_LEN;
+                assert!(
+                    mappings.len() <= max_len,
+                    "Bulk transfer accepts at most {} mappings",
+                    max_len,
+                );
+                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                    return None;
+                }
+                self.assert_no_grant_threshold(role);
+                self.correlation_id = correlation_id;
+                let results = mappings
+                    .iter()
+                    .map(|(old, new)| {
+                        if !self.is_grantee(role, old) || self.is_grantee(role, new) {
+                            return false;
+                        }
+                        self.transfer_role_unchecked(role, old, new);
+                        true
+                    })
+                    .collect();
+                self.correlation_id = None;
+                Some(results)
+            }
+
+            /// Moves the grant of `role` from `from` to `to` __without__
+            /// checking any permissions. `to` must not be a grantee of `role`.
+            ///
+            /// The expiry and remaining uses of the grant are moved along,
+            /// hence a time-limited or consumable grant stays limited.
+            fn transfer_role_unchecked(
+                &mut self,
+                role: #role_type,
+                from: &::near_sdk::AccountId,
+                to: &::near_sdk::AccountId,
+            ) {
+                let expires_at_ns = Self::role_expiries().get(&(from.clone(), u8::from(role)));
+                let uses = Self::consumable_uses().get(&(from.clone(), u8::from(role)));
+                self.revoke_role_unchecked(role, from);
+                // Removing an expired grant first makes it count as new grant.
+                self.remove_expired_grant(role, to);
+                if let Some(expires_at_ns) = expires_at_ns {
+                    // Inserted before granting to be included in `RoleGranted`.
+                    Self::role_expiries().insert(&(to.clone(), u8::from(role)), &expires_at_ns);
+                }
+                self.grant_role_unchecked(role, to);
+                if let Some(uses) = uses {
+                    Self::consumable_uses().insert(&(to.clone(), u8::from(role)), &uses);
+                }
+            }
+
+            /// Like `has_role`, but disregards roles assumed for the current
+            /// receipt, which are not stored.
+            fn is_grantee(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
+                ::#cratename::acl_store::AclStore::has_role(self, role.acl_permission(), account_id)
+                    && !self.is_expired(role, account_id)
+            }
+
+            fn has_role(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
+                let has_permission = ::#cratename::acl_store::AclStore::has_role(
+                    self,
+                    role.acl_permission(),
+                    account_id,
+                );
+                (has_permission && !self.is_expired(role, account_id))
+                    || self.is_assumed_role(role, account_id)
             }
 
             fn has_any_role(
@@ -356,12 +1715,20 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                         <#bitflags_type>::empty(),
                         |acc, x| acc | x,
                     );
-                self.has_any_permission(target, account_id)
+                // Permissions are read once and compared with `target`. Only
+                // the expiries of granted roles are read.
+                let permissions = self.get_or_init_permissions(account_id);
+                let has_unexpired = target.intersects(permissions)
+                    && roles.iter().any(|&role| {
+                        let flag = <#bitflags_type>::from_bits(role.acl_permission())
+                            .expect(#ERR_PARSE_BITFLAG);
+                        permissions.contains(flag) && !self.is_expired(role, account_id)
+                    });
+                has_unexpired || roles.iter().any(|&role| self.is_assumed_role(role, account_id))
             }
 
-            fn has_any_permission(&self, target: #bitflags_type, account_id: &::near_sdk::AccountId) -> bool {
-                let permissions = self.get_or_init_permissions(account_id);
-                target.intersects(permissions)
+            fn has_all_roles(&self, roles: &[#role_type], account_id: &::near_sdk::AccountId) -> bool {
+                roles.iter().all(|&role| self.has_role(role, account_id))
             }
 
             /// Adds `account_id` to the set of `permission` bearers.
@@ -387,8 +1754,26 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 }
             }
 
+            /// Returns the role whose grantees bear `permission`. Returns
+            /// `None` for other permissions, e.g. those of admins.
+            fn grantee_role(permission: #bitflags_type) -> Option<#role_type> {
+                Self::all_roles().find(|role| role.acl_permission() == permission.bits())
+            }
+
+            /// Iterates over the bearers of `permission`, skipping expired
+            /// grants.
+            fn iter_bearers(
+                &self,
+                permission: #bitflags_type,
+            ) -> impl Iterator<Item = ::near_sdk::AccountId> + '_ {
+                let role = Self::grantee_role(permission);
+                ::#cratename::acl_store::AclStore::iter_grantees(self, permission.bits())
+                    .filter(move |account_id| role.map_or(true, |role| !self.is_expired(role, account_id)))
+            }
+
             /// Enables paginated retrieval of bearers. Returns up to `limit`
             /// bearers of `permission`, skipping the first `skip` items.
+            /// Expired grants are skipped.
             ///
             /// # Panics
             ///
@@ -396,12 +1781,264 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
             fn get_bearers(&self, permission: #bitflags_type, skip: u64, limit: u64) -> Vec<::near_sdk::AccountId> {
                 let skip = usize::try_from(skip).expect("skip should be in the range of usize");
                 let limit = usize::try_from(limit).expect("limit should be in the range of usize");
+                self.iter_bearers(permission).skip(skip).take(limit).collect()
+            }
 
-                let set = match self.bearers.get(&permission) {
-                    Some(set) => set,
+            /// Returns the number of bearers of `permission`. It reads the
+            /// length of the set of bearers without loading its elements,
+            /// hence expired grants are counted until they are removed from
+            /// storage.
+            fn num_bearers(&self, permission: #bitflags_type) -> u64 {
+                self.bearers.get(&permission).map_or(0, |set| set.len())
+            }
+
+            /// Returns a page of up to `limit` bearers of `permission`,
+            /// starting at the position encoded in `cursor`.
+            fn get_bearers_page(
+                &self,
+                permission: #bitflags_type,
+                cursor: Option<String>,
+                limit: u64,
+            ) -> ::#cratename::pagination::Page<::near_sdk::AccountId> {
+                let position = ::#cratename::pagination::decode_cursor(cursor.as_deref());
+                let len = self.num_bearers(permission);
+                // The page covers `limit` stored bearers, hence skipping
+                // expired grants does not require reading further bearers.
+                let skip = usize::try_from(position).expect("skip should be in the range of usize");
+                let limit = usize::try_from(limit).expect("limit should be in the range of usize");
+                let bearers = ::#cratename::acl_store::get_grantees(self, permission.bits(), skip, limit);
+                let page = ::#cratename::pagination::Page::new(bearers, position, len);
+                let role = Self::grantee_role(permission);
+                ::#cratename::pagination::Page {
+                    items: page
+                        .items
+                        .into_iter()
+                        .filter(|account_id| role.map_or(true, |role| !self.is_expired(role, account_id)))
+                        .collect(),
+                    ..page
+                }
+            }
+
+            /// Returns each role with its number of grantees, ordered like
+            /// `all_roles`.
+            fn get_all_grantee_counts(&self) -> Vec<(String, u64)> {
+                Self::all_roles()
+                    .map(|role| {
+                        let permission = <#bitflags_type>::from_bits(role.acl_permission())
+                            .expect(#ERR_PARSE_BITFLAG);
+                        (String::from(role), self.num_bearers(permission))
+                    })
+                    .collect()
+            }
+
+            fn get_roles(&self, account_id: &::near_sdk::AccountId) -> Vec<String> {
+                let permissions = match self.permissions.get(account_id) {
+                    Some(permissions) => permissions,
                     None => return vec![],
                 };
-                set.iter().skip(skip).take(limit).collect()
+                Self::all_roles()
+                    .filter(|role| {
+                        let flag = <#bitflags_type>::from_bits(role.acl_permission())
+                            .expect(#ERR_PARSE_BITFLAG);
+                        permissions.contains(flag) && !self.is_expired(*role, account_id)
+                    })
+                    .map(String::from)
+                    .collect()
+            }
+
+            fn health(&self) -> ::#cratename::access_controllable::AclHealth {
+                use ::#cratename::access_controllable::HealthWarning;
+
+                let count = |permission: u128| {
+                    let permission = <#bitflags_type>::from_bits(permission)
+                        .expect(#ERR_PARSE_BITFLAG);
+                    self.num_bearers(permission)
+                };
+                let mut warnings = vec![];
+                match count(<#role_type>::acl_super_admin_permission()) {
+                    0 => warnings.push(HealthWarning::NoSuperAdmin),
+                    1 => warnings.push(HealthWarning::SingleSuperAdmin),
+                    _ => (),
+                }
+                for role in Self::all_roles() {
+                    let grantees = count(role.acl_permission());
+                    if grantees > 0 && count(role.acl_admin_permission()) == 0 {
+                        warnings.push(HealthWarning::RoleWithoutAdmins {
+                            role: String::from(role),
+                            grantees,
+                        });
+                    }
+                }
+                ::#cratename::access_controllable::AclHealth { warnings }
+            }
+
+            /// Enables paginated retrieval of `(role, admin)` pairs across
+            /// all roles, see `AccessControllable::acl_get_all_admins`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `limit` is outside the range of `usize`.
+            fn get_all_admins(&self, skip: u64, limit: u64) -> Vec<(String, ::near_sdk::AccountId)> {
+                let super_admin = <#bitflags_type>::from_bits(<#role_type>::acl_super_admin_permission())
+                    .expect(#ERR_PARSE_BITFLAG);
+                let mut permissions = vec![(
+                    ::#cratename::access_controllable::ACL_SUPER_ADMIN_ROLE_MARKER.to_string(),
+                    super_admin,
+                )];
+                permissions.extend(Self::all_roles().map(|role| {
+                    let permission = <#bitflags_type>::from_bits(role.acl_admin_permission())
+                        .expect(#ERR_PARSE_BITFLAG);
+                    (String::from(role), permission)
+                }));
+                self.get_bearers_across(permissions, skip, limit)
+            }
+
+            /// Enables paginated retrieval of bearers across multiple
+            /// permissions. Returns up to `limit` pairs of a permission's
+            /// `label` and a bearer, skipping the first `skip` items. Bearers
+            /// are ordered like `permissions` and expired grants are skipped.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `limit` is outside the range of `usize`.
+            fn get_bearers_across<T: Clone>(
+                &self,
+                permissions: Vec<(T, #bitflags_type)>,
+                skip: u64,
+                limit: u64,
+            ) -> Vec<(T, ::near_sdk::AccountId)> {
+                let limit = usize::try_from(limit).expect("limit should be in the range of usize");
+
+                let mut skip = skip;
+                let mut bearers = vec![];
+                for (label, permission) in permissions {
+                    if bearers.len() >= limit {
+                        break;
+                    }
+                    if Self::grantee_role(permission).is_some() {
+                        // Skipping expired grants requires reading every
+                        // element.
+                        let mut unexpired = self.iter_bearers(permission);
+                        while skip > 0 && unexpired.next().is_some() {
+                            skip -= 1;
+                        }
+                        let page = unexpired
+                            .take(limit - bearers.len())
+                            .map(|account_id| (label.clone(), account_id));
+                        bearers.extend(page);
+                        continue;
+                    }
+                    let set = match self.bearers.get(&permission) {
+                        Some(set) => set,
+                        None => continue,
+                    };
+                    // Skip entire sets without reading their elements.
+                    if skip >= set.len() {
+                        skip -= set.len();
+                        continue;
+                    }
+                    let page = set
+                        .iter()
+                        .skip(usize::try_from(skip).expect("skip should be in the range of usize"))
+                        .take(limit - bearers.len())
+                        .map(|account_id| (label.clone(), account_id));
+                    bearers.extend(page);
+                    skip = 0;
+                }
+                bearers
+            }
+
+            /// Emits a page of the current state as `AclSnapshot` event,
+            /// provided that the predecessor is a super-admin. Returns whether
+            /// there are more entries after this page.
+            fn emit_snapshot(&self, skip: u64, limit: u64) -> Option<bool> {
+                if !self.is_super_admin(&::near_sdk::env::predecessor_account_id()) {
+                    return None;
+                }
+
+                #[derive(Clone)]
+                enum Entry {
+                    SuperAdmin,
+                    Admin(String),
+                    Grantee(String),
+                }
+                let super_admin = <#bitflags_type>::from_bits(<#role_type>::acl_super_admin_permission())
+                    .expect(#ERR_PARSE_BITFLAG);
+                let mut permissions = vec![(Entry::SuperAdmin, super_admin)];
+                for role in Self::all_roles() {
+                    let permission = <#bitflags_type>::from_bits(role.acl_admin_permission())
+                        .expect(#ERR_PARSE_BITFLAG);
+                    permissions.push((Entry::Admin(String::from(role)), permission));
+                }
+                for role in Self::all_roles() {
+                    let permission = <#bitflags_type>::from_bits(role.acl_permission())
+                        .expect(#ERR_PARSE_BITFLAG);
+                    permissions.push((Entry::Grantee(String::from(role)), permission));
+                }
+
+                // Request one more entry to find out whether there are more.
+                let mut entries = self.get_bearers_across(permissions, skip, limit.saturating_add(1));
+                let has_more = u64::try_from(entries.len()).unwrap_or(u64::MAX) > limit;
+                if has_more {
+                    entries.pop();
+                }
+
+                let mut event = ::#cratename::access_controllable::events::AclSnapshot {
+                    skip,
+                    super_admins: vec![],
+                    admins: vec![],
+                    grantees: vec![],
+                    has_more,
+                };
+                for (entry, account_id) in entries {
+                    match entry {
+                        Entry::SuperAdmin => event.super_admins.push(account_id),
+                        Entry::Admin(role) => event.admins.push((role, account_id)),
+                        Entry::Grantee(role) => event.grantees.push((role, account_id)),
+                    }
+                }
+                __acl_emit(
+                    ::#cratename::access_controllable::events::AclEvent::AclSnapshot(event),
+                );
+                Some(has_more)
+            }
+
+            /// Returns the bearers of `permission` sorted by account id,
+            /// skipping expired grants.
+            fn get_sorted_bearers(&self, permission: #bitflags_type) -> Vec<::near_sdk::AccountId> {
+                let mut bearers: Vec<_> = self.iter_bearers(permission).collect();
+                bearers.sort();
+                bearers
+            }
+
+            /// Hashes the borsh serialization of super-admins followed by the
+            /// admins and grantees of every role, see
+            /// `AccessControllable::acl_state_checksum`.
+            fn state_checksum(&self) -> ::near_sdk::json_types::Base58CryptoHash {
+                let super_admin = <#bitflags_type>::from_bits(<#role_type>::acl_super_admin_permission())
+                    .expect(#ERR_PARSE_BITFLAG);
+                let roles: Vec<(String, Vec<::near_sdk::AccountId>, Vec<::near_sdk::AccountId>)> =
+                    Self::all_roles()
+                        .map(|role| {
+                            let admin = <#bitflags_type>::from_bits(role.acl_admin_permission())
+                                .expect(#ERR_PARSE_BITFLAG);
+                            let grantee = <#bitflags_type>::from_bits(role.acl_permission())
+                                .expect(#ERR_PARSE_BITFLAG);
+                            (
+                                String::from(role),
+                                self.get_sorted_bearers(admin),
+                                self.get_sorted_bearers(grantee),
+                            )
+                        })
+                        .collect();
+                let state = ::near_sdk::borsh::BorshSerialize::try_to_vec(&(
+                    self.get_sorted_bearers(super_admin),
+                    roles,
+                ))
+                .expect("ACL state should be serializable");
+                let hash: ::near_sdk::CryptoHash =
+                    ::std::convert::TryInto::try_into(::near_sdk::env::sha256(&state)).unwrap();
+                hash.into()
             }
 
             /// Removes `account_id` from the set of `permission` bearers.
@@ -418,86 +2055,735 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
             }
         }
 
+        /// Implements the storage backend on top of the collections of the
+        /// generated struct, keeping their layout.
+        impl ::#cratename::acl_store::AclStore for #acl_type {
+            fn has_role(&self, permission: u128, account_id: &::near_sdk::AccountId) -> bool {
+                let flag = <#bitflags_type>::from_bits(permission).expect(#ERR_PARSE_BITFLAG);
+                match self.permissions.get(account_id) {
+                    Some(permissions) => !flag.is_empty() && permissions.contains(flag),
+                    None => false,
+                }
+            }
+
+            fn add_grantee(&mut self, permission: u128, account_id: &::near_sdk::AccountId) -> bool {
+                let flag = <#bitflags_type>::from_bits(permission).expect(#ERR_PARSE_BITFLAG);
+                let mut permissions = self.get_or_init_permissions(account_id);
+                if permissions.contains(flag) {
+                    return false;
+                }
+                permissions.insert(flag);
+                self.set_permissions(account_id, &permissions);
+                self.add_bearer(flag, account_id);
+                true
+            }
+
+            fn remove_grantee(&mut self, permission: u128, account_id: &::near_sdk::AccountId) -> bool {
+                let flag = <#bitflags_type>::from_bits(permission).expect(#ERR_PARSE_BITFLAG);
+                let mut permissions = self.get_or_init_permissions(account_id);
+                if flag.is_empty() || !permissions.contains(flag) {
+                    return false;
+                }
+                permissions.remove(flag);
+                self.set_permissions(account_id, &permissions);
+                self.remove_bearer(flag, account_id);
+                true
+            }
+
+            fn iter_grantees(
+                &self,
+                permission: u128,
+            ) -> Box<dyn Iterator<Item = ::near_sdk::AccountId> + '_> {
+                let flag = <#bitflags_type>::from_bits(permission).expect(#ERR_PARSE_BITFLAG);
+                match self.bearers.get(&flag) {
+                    Some(set) => Box::new((0..set.len()).filter_map(move |i| set.as_vector().get(i))),
+                    None => Box::new(::std::iter::empty()),
+                }
+            }
+        }
+
         // Note that `#[near-bindgen]` exposes non-public functions in trait
         // implementations. This is [documented] behavior. Therefore some
         // functions are made `#[private]` despite _not_ being public.
         //
         // [documented]: https://docs.near.org/sdk/rust/contract-interface/public-methods#exposing-trait-implementations
+        #bootstrap
+
+        #role_groups
+
         #[near_bindgen]
         impl AccessControllable for #ident {
             fn acl_storage_prefix() -> &'static [u8] {
                 (#storage_prefix).as_bytes()
             }
 
+            fn acl_get_storage_version(&self) -> Option<u32> {
+                self.#acl_field.get_storage_version()
+            }
+
+            fn acl_set_storage_version(&mut self, version: u32) -> bool {
+                #read_only_check
+                self.#acl_field.set_storage_version(version)
+            }
+
+            #[private]
+            fn acl_init_from_manifest(
+                &mut self,
+                manifest: ::#cratename::access_controllable::AclManifest,
+            ) -> bool {
+                #version_check
+                #read_only_check
+                self.#acl_field.init_from_manifest(manifest)
+            }
+
+            fn acl_set_read_only(&mut self, read_only: bool) -> bool {
+                #version_check
+                if !self.#acl_field.is_super_admin(&::near_sdk::env::predecessor_account_id()) {
+                    return false;
+                }
+                ::#cratename::read_only::set_read_only(read_only);
+                true
+            }
+
+            fn acl_is_read_only(&self) -> bool {
+                ::#cratename::read_only::is_read_only()
+            }
+
             fn acl_is_super_admin(&self, account_id: ::near_sdk::AccountId) -> bool {
                 self.#acl_field.is_super_admin(&account_id)
             }
 
+            fn acl_am_i_super_admin(&mut self) -> bool {
+                #version_check
+                self.#acl_field.is_super_admin(&::near_sdk::env::predecessor_account_id())
+            }
+
+            fn acl_revoke_super_admin(
+                &mut self,
+                account_id: ::near_sdk::AccountId,
+                reason: Option<String>,
+            ) -> Option<bool> {
+                #version_check
+                #pause_check
+                #read_only_check
+                self.#acl_field.revoke_super_admin(&account_id, reason)
+            }
+
+            fn acl_renounce_super_admin(&mut self) -> bool {
+                #version_check
+                #pause_check
+                #read_only_check
+                self.#acl_field.renounce_super_admin()
+            }
+
+            fn acl_num_super_admins(&self) -> u64 {
+                self.#acl_field.num_super_admins()
+            }
+
+            fn acl_propose_super_admin_transfer(&mut self, account_id: ::near_sdk::AccountId) -> Option<bool> {
+                #version_check
+                #pause_check
+                #read_only_check
+                self.#acl_field.propose_super_admin_transfer(&account_id)
+            }
+
+            fn acl_accept_super_admin_transfer(&mut self) -> bool {
+                #version_check
+                #pause_check
+                #read_only_check
+                self.#acl_field.accept_super_admin_transfer()
+            }
+
+            fn acl_cancel_super_admin_transfer(&mut self) -> Option<bool> {
+                #version_check
+                #pause_check
+                #read_only_check
+                self.#acl_field.cancel_super_admin_transfer()
+            }
+
+            fn acl_get_pending_super_admin_transfer(
+                &self,
+            ) -> Option<::#cratename::access_controllable::SuperAdminTransfer> {
+                #acl_type::pending_super_admin_transfer().get()
+            }
+
             fn acl_add_admin(&mut self, role: String, account_id: ::near_sdk::AccountId) -> Option<bool> {
-                let role = <#role_type>::try_from(role.as_str()).expect(#ERR_PARSE_ROLE);
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
                 self.#acl_field.add_admin(role, &account_id)
             }
 
+            fn acl_bootstrap_role(&mut self, role: String, first_admin: ::near_sdk::AccountId) -> Option<bool> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.bootstrap_role(role, &first_admin)
+            }
+
             fn acl_is_admin(&self, role: String, account_id: ::near_sdk::AccountId) -> bool {
-                let role = <#role_type>::try_from(role.as_str()).expect(#ERR_PARSE_ROLE);
+                let role = __acl_parse_role(role.as_str());
                 self.#acl_field.is_admin(role, &account_id)
             }
 
+            fn acl_is_admin_of_any(&self, roles: Vec<String>, account_id: ::near_sdk::AccountId) -> bool {
+                roles
+                    .iter()
+                    .any(|role| self.#acl_field.is_admin(__acl_parse_role(role.as_str()), &account_id))
+            }
+
             fn acl_revoke_admin(&mut self, role: String, account_id: ::near_sdk::AccountId) -> Option<bool> {
-                let role = <#role_type>::try_from(role.as_str()).expect(#ERR_PARSE_ROLE);
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
                 self.#acl_field.revoke_admin(role, &account_id)
             }
 
-            fn acl_renounce_admin(&mut self, role: String) -> bool {
-                let role = <#role_type>::try_from(role.as_str()).expect(#ERR_PARSE_ROLE);
-                self.#acl_field.renounce_admin(role)
+            fn acl_revoke_admin_batch(
+                &mut self,
+                role: String,
+                account_ids: Vec<::near_sdk::AccountId>,
+                correlation_id: Option<String>,
+            ) -> Option<Vec<bool>> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.revoke_batch(role, &account_ids, true, correlation_id)
+            }
+
+            fn acl_renounce_admin(&mut self, role: String, force: Option<bool>) -> bool {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.renounce_admin(role, force.unwrap_or(false))
+            }
+
+            fn acl_set_handle_registry(&mut self, registry: Option<::near_sdk::AccountId>) -> bool {
+                #version_check
+                #pause_check
+                #read_only_check
+                self.#acl_field.set_handle_registry(registry)
+            }
+
+            fn acl_get_handle_registry(&self) -> Option<::near_sdk::AccountId> {
+                #acl_type::handle_registry().get()
+            }
+
+            fn acl_grant_role_by_handle(
+                &mut self,
+                role: String,
+                handle: String,
+            ) -> ::near_sdk::PromiseOrValue<Option<bool>> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.grant_role_by_handle(role, handle)
+            }
+
+            #[private]
+            fn acl_on_handle_resolved(
+                &mut self,
+                role: String,
+                handle: String,
+                caller: ::near_sdk::AccountId,
+            ) -> Option<bool> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.on_handle_resolved(role, &handle, caller)
+            }
+
+            fn acl_grant_roles(
+                &mut self,
+                role: String,
+                account_ids: Vec<::near_sdk::AccountId>,
+                correlation_id: Option<String>,
+            ) -> Vec<Option<bool>> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.grant_roles(role, &account_ids, correlation_id)
+            }
+
+            fn acl_apply_membership_change(
+                &mut self,
+                role: String,
+                add: Vec<::near_sdk::AccountId>,
+                remove: Vec<::near_sdk::AccountId>,
+                correlation_id: Option<String>,
+            ) -> Option<::#cratename::access_controllable::MembershipChangeResult> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.apply_membership_change(role, &add, &remove, correlation_id)
+            }
+
+            fn acl_revoke_role_batch(
+                &mut self,
+                role: String,
+                account_ids: Vec<::near_sdk::AccountId>,
+                correlation_id: Option<String>,
+            ) -> Option<Vec<bool>> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.revoke_batch(role, &account_ids, false, correlation_id)
+            }
+
+            fn acl_revoke_roles(
+                &mut self,
+                role: String,
+                account_ids: Vec<::near_sdk::AccountId>,
+                correlation_id: Option<String>,
+            ) -> Vec<Option<bool>> {
+                let len = account_ids.len();
+                match <Self as AccessControllable>::acl_revoke_role_batch(
+                    self,
+                    role,
+                    account_ids,
+                    correlation_id,
+                ) {
+                    Some(results) => results.into_iter().map(Some).collect(),
+                    None => vec![None; len],
+                }
             }
 
             fn acl_revoke_role(&mut self, role: String, account_id: ::near_sdk::AccountId) -> Option<bool> {
-                let role = <#role_type>::try_from(role.as_str()).expect(#ERR_PARSE_ROLE);
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
                 self.#acl_field.revoke_role(role, &account_id)
             }
 
+            fn acl_revoke_role_checked(
+                &mut self,
+                role: String,
+                account_id: ::near_sdk::AccountId,
+            ) -> ::#cratename::access_controllable::AclResult {
+                #version_check
+                #pause_check
+                #read_only_check
+                match __acl_try_parse_role(role.as_str()) {
+                    Some(role) => self.#acl_field.revoke_role(role, &account_id).into(),
+                    None => ::#cratename::access_controllable::AclResult::UnknownRole,
+                }
+            }
+
             fn acl_renounce_role(&mut self, role: String) -> bool {
-                let role = <#role_type>::try_from(role.as_str()).expect(#ERR_PARSE_ROLE);
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
                 self.#acl_field.renounce_role(role)
             }
 
+            fn acl_revoke_role_notify(
+                &mut self,
+                role: String,
+                account_id: ::near_sdk::AccountId,
+            ) -> Option<bool> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.revoke_role_notify(role, &account_id)
+            }
+
+            fn acl_ensure_role_absent(
+                &mut self,
+                role: String,
+                account_id: ::near_sdk::AccountId,
+            ) -> Option<bool> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.revoke_role(role, &account_id)
+            }
+
+            fn acl_revoke_role_from_all(
+                &mut self,
+                role: String,
+                limit: u64,
+                correlation_id: Option<String>,
+            ) -> Option<::#cratename::access_controllable::RevokeRoleFromAllProgress> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.revoke_role_from_all(role, limit, correlation_id)
+            }
+
             fn acl_grant_role(&mut self, role: String, account_id: ::near_sdk::AccountId) -> Option<bool> {
-                let role = <#role_type>::try_from(role.as_str()).expect(#ERR_PARSE_ROLE);
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
                 self.#acl_field.grant_role(role, &account_id)
             }
 
+            fn acl_grant_role_and_list(
+                &mut self,
+                role: String,
+                account_id: ::near_sdk::AccountId,
+                skip: u64,
+                limit: u64,
+            ) -> Option<Vec<::near_sdk::AccountId>> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let limit = limit.min(::#cratename::access_controllable::ACL_MAX_LIMIT);
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.grant_role(role, &account_id)?;
+                Some(self.#acl_field.get_grantees(role, skip, limit))
+            }
+
+            fn acl_grant_role_checked(
+                &mut self,
+                role: String,
+                account_id: ::near_sdk::AccountId,
+            ) -> ::#cratename::access_controllable::AclResult {
+                #version_check
+                #pause_check
+                #read_only_check
+                match __acl_try_parse_role(role.as_str()) {
+                    Some(role) => self.#acl_field.grant_role(role, &account_id).into(),
+                    None => ::#cratename::access_controllable::AclResult::UnknownRole,
+                }
+            }
+
+            fn acl_get_grant_approvals(
+                &self,
+                role: String,
+                account_id: ::near_sdk::AccountId,
+            ) -> Vec<::near_sdk::AccountId> {
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.get_grant_approvals(role, &account_id)
+            }
+
+            fn acl_grant_consumable_role(
+                &mut self,
+                role: String,
+                account_id: ::near_sdk::AccountId,
+                uses: u32,
+            ) -> Option<bool> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.grant_consumable_role(role, &account_id, uses)
+            }
+
+            fn acl_grant_role_with_expiry(
+                &mut self,
+                role: String,
+                account_id: ::near_sdk::AccountId,
+                expires_at_ns: u64,
+            ) -> Option<bool> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.grant_role_with_expiry(role, &account_id, expires_at_ns)
+            }
+
+            fn acl_get_role_expiry(&self, role: String, account_id: ::near_sdk::AccountId) -> Option<u64> {
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.get_role_expiry(role, &account_id)
+            }
+
+            fn acl_get_remaining_uses(&self, role: String, account_id: ::near_sdk::AccountId) -> Option<u32> {
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.get_remaining_uses(role, &account_id)
+            }
+
+            fn acl_allow_assume_role(&mut self, role: String, account_id: ::near_sdk::AccountId) -> Option<bool> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.allow_assume_role(role, &account_id)
+            }
+
+            fn acl_disallow_assume_role(&mut self, role: String, account_id: ::near_sdk::AccountId) -> Option<bool> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.disallow_assume_role(role, &account_id)
+            }
+
+            fn acl_estimate_grant_storage(&self, role: String, account_id: ::near_sdk::AccountId) -> u64 {
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.estimate_grant_storage(role, &account_id)
+            }
+
+            fn acl_grant_role_scoped(
+                &mut self,
+                role: String,
+                scope: String,
+                account_id: ::near_sdk::AccountId,
+            ) -> Option<bool> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.grant_role_scoped(role, scope, &account_id)
+            }
+
+            fn acl_revoke_role_scoped(
+                &mut self,
+                role: String,
+                scope: String,
+                account_id: ::near_sdk::AccountId,
+            ) -> Option<bool> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.revoke_role_scoped(role, scope, &account_id)
+            }
+
+            fn acl_has_role_scoped(&self, role: String, scope: String, account_id: ::near_sdk::AccountId) -> bool {
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.has_role_scoped(role, scope, &account_id)
+            }
 
             fn acl_has_role(&self, role: String, account_id: ::near_sdk::AccountId) -> bool {
-                let role = <#role_type>::try_from(role.as_str()).expect(#ERR_PARSE_ROLE);
+                let role = __acl_parse_role(role.as_str());
                 self.#acl_field.has_role(role, &account_id)
             }
 
+            fn acl_get_roles(&self, account_id: ::near_sdk::AccountId) -> Vec<String> {
+                self.#acl_field.get_roles(&account_id)
+            }
+
+            fn acl_my_roles(&mut self) -> Vec<String> {
+                #version_check
+                self.#acl_field.get_roles(&::near_sdk::env::predecessor_account_id())
+            }
+
+            fn acl_has_all_roles(&self, roles: Vec<String>, account_id: ::near_sdk::AccountId) -> bool {
+                let roles: Vec<#role_type> = roles
+                    .iter()
+                    .map(|role| __acl_parse_role(role.as_str()))
+                    .collect();
+                self.#acl_field.has_all_roles(&roles, &account_id)
+            }
+
             fn acl_has_any_role(&self, roles: Vec<String>, account_id: ::near_sdk::AccountId) -> bool {
                 let roles: Vec<#role_type> = roles
                     .iter()
-                    .map(|role| <#role_type>::try_from(role.as_str()).expect(#ERR_PARSE_ROLE))
+                    .map(|role| __acl_parse_role(role.as_str()))
                     .collect();
                 self.#acl_field.has_any_role(roles, &account_id)
             }
 
+            fn acl_which_role(&self, roles: Vec<String>, account_id: ::near_sdk::AccountId) -> Option<String> {
+                roles.into_iter().find(|role| {
+                    let role = __acl_parse_role(role.as_str());
+                    self.#acl_field.has_role(role, &account_id)
+                })
+            }
+
+            fn acl_get_super_admins(&self, skip: u64, limit: u64) -> Vec<::near_sdk::AccountId> {
+                let limit = limit.min(::#cratename::access_controllable::ACL_MAX_LIMIT);
+                let permission = <#bitflags_type>::from_bits(<#role_type>::acl_super_admin_permission())
+                    .expect(#ERR_PARSE_BITFLAG);
+                self.#acl_field.get_bearers(permission, skip, limit)
+            }
+
             fn acl_get_admins(&self, role: String, skip: u64, limit: u64) -> Vec<::near_sdk::AccountId> {
-                let role = <#role_type>::try_from(role.as_str()).expect(#ERR_PARSE_ROLE);
+                let limit = limit.min(::#cratename::access_controllable::ACL_MAX_LIMIT);
+                let role = __acl_parse_role(role.as_str());
                 let permission = <#bitflags_type>::from_bits(role.acl_admin_permission())
                     .expect(#ERR_PARSE_BITFLAG);
                 self.#acl_field.get_bearers(permission, skip, limit)
             }
 
             fn acl_get_grantees(&self, role: String, skip: u64, limit: u64) -> Vec<::near_sdk::AccountId> {
-                let role = <#role_type>::try_from(role.as_str()).expect(#ERR_PARSE_ROLE);
+                let limit = limit.min(::#cratename::access_controllable::ACL_MAX_LIMIT);
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.get_grantees(role, skip, limit)
+            }
+
+            fn acl_num_admins(&self, role: String) -> u64 {
+                let role = __acl_parse_role(role.as_str());
+                let permission = <#bitflags_type>::from_bits(role.acl_admin_permission())
+                    .expect(#ERR_PARSE_BITFLAG);
+                self.#acl_field.num_bearers(permission)
+            }
+
+            fn acl_num_grantees(&self, role: String) -> u64 {
+                let role = __acl_parse_role(role.as_str());
                 let permission = <#bitflags_type>::from_bits(role.acl_permission())
                     .expect(#ERR_PARSE_BITFLAG);
-                self.#acl_field.get_bearers(permission, skip, limit)
+                self.#acl_field.num_bearers(permission)
+            }
+
+            fn acl_get_admins_page(
+                &self,
+                role: String,
+                cursor: Option<String>,
+                limit: u64,
+            ) -> ::#cratename::pagination::Page<::near_sdk::AccountId> {
+                let limit = limit.min(::#cratename::access_controllable::ACL_MAX_LIMIT);
+                let role = __acl_parse_role(role.as_str());
+                let permission = <#bitflags_type>::from_bits(role.acl_admin_permission())
+                    .expect(#ERR_PARSE_BITFLAG);
+                self.#acl_field.get_bearers_page(permission, cursor, limit)
+            }
+
+            fn acl_get_grantees_page(
+                &self,
+                role: String,
+                cursor: Option<String>,
+                limit: u64,
+            ) -> ::#cratename::pagination::Page<::near_sdk::AccountId> {
+                let limit = limit.min(::#cratename::access_controllable::ACL_MAX_LIMIT);
+                let role = __acl_parse_role(role.as_str());
+                let permission = <#bitflags_type>::from_bits(role.acl_permission())
+                    .expect(#ERR_PARSE_BITFLAG);
+                self.#acl_field.get_bearers_page(permission, cursor, limit)
+            }
+
+            fn acl_get_all_roles(&self) -> Vec<String> {
+                #acl_type::all_roles().map(String::from).collect()
+            }
+
+            fn acl_role_exists(&self, role: String) -> bool {
+                __acl_try_parse_role(role.as_str()).is_some()
+            }
+
+            fn acl_get_all_grantee_counts(&self) -> Vec<(String, u64)> {
+                self.#acl_field.get_all_grantee_counts()
+            }
+
+            fn acl_health(&self) -> ::#cratename::access_controllable::AclHealth {
+                self.#acl_field.health()
+            }
+
+            fn acl_get_all_admins(&self, skip: u64, limit: u64) -> Vec<(String, ::near_sdk::AccountId)> {
+                let limit = limit.min(::#cratename::access_controllable::ACL_MAX_LIMIT);
+                self.#acl_field.get_all_admins(skip, limit)
+            }
+
+            fn acl_emit_snapshot(&mut self, skip: u64, limit: u64) -> Option<bool> {
+                #version_check
+                let limit = limit.min(::#cratename::access_controllable::ACL_MAX_LIMIT);
+                self.#acl_field.emit_snapshot(skip, limit)
+            }
+
+            fn acl_state_checksum(&self) -> ::near_sdk::json_types::Base58CryptoHash {
+                self.#acl_field.state_checksum()
+            }
+
+            fn acl_set_role_metadata(&mut self, role: String, key: String, value: String) -> bool {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.set_role_metadata(role, key, value)
+            }
+
+            fn acl_get_role_metadata(&self, role: String, key: String) -> Option<String> {
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.get_role_metadata(role, &key)
+            }
+
+            fn acl_get_role_metadata_keys(
+                &self,
+                role: String,
+                cursor: Option<String>,
+                limit: u64,
+            ) -> ::#cratename::pagination::Page<String> {
+                let limit = limit.min(::#cratename::access_controllable::ACL_MAX_LIMIT);
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.get_role_metadata_keys(role, cursor, limit)
+            }
+
+            fn acl_transfer_role_bulk(
+                &mut self,
+                role: String,
+                mappings: Vec<(::near_sdk::AccountId, ::near_sdk::AccountId)>,
+                correlation_id: Option<String>,
+            ) -> Option<Vec<bool>> {
+                #version_check
+                #pause_check
+                #read_only_check
+                let role = __acl_parse_role(role.as_str());
+                self.#acl_field.transfer_role_bulk(role, &mappings, correlation_id)
+            }
+        }
+
+        /// Typed counterparts of methods of `AccessControllable` for use within
+        /// the contract. They are not exposed as contract methods.
+        impl #ident {
+            pub fn acl_grant_role_typed(
+                &mut self,
+                role: #role_type,
+                account_id: ::near_sdk::AccountId,
+            ) -> Option<bool> {
+                <Self as AccessControllable>::acl_grant_role(self, String::from(role), account_id)
+            }
+
+            pub fn acl_revoke_role_typed(
+                &mut self,
+                role: #role_type,
+                account_id: ::near_sdk::AccountId,
+            ) -> Option<bool> {
+                <Self as AccessControllable>::acl_revoke_role(self, String::from(role), account_id)
+            }
+
+            pub fn acl_has_role_typed(&self, role: #role_type, account_id: ::near_sdk::AccountId) -> bool {
+                <Self as AccessControllable>::acl_has_role(self, String::from(role), account_id)
+            }
+
+            /// Activates `role` for the predecessor until the end of the
+            /// current execution, provided that it was allowed to assume
+            /// `role` via `acl_allow_assume_role`. Returns whether `role` was
+            /// assumed.
+            ///
+            /// Assumed roles are not persisted, hence this method is not
+            /// exposed as contract method. Contract code calls it before
+            /// the restricted code which requires `role`.
+            pub fn acl_assume_role(&mut self, role: #role_type) -> bool {
+                #version_check
+                #pause_check
+                self.#acl_field.assume_role(role)
+            }
+
+            /// Grants `role` to the predecessor, applying the checks of
+            /// methods which modify the ACL. Code generated by
+            /// `#[grants_role_on_success]` calls it. Returns whether `role`
+            /// was newly granted.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `role` has a grant threshold greater than one.
+            #[doc(hidden)]
+            pub fn __acl_grant_role_on_success(&mut self, role: #role_type) -> bool {
+                #version_check
+                #pause_check
+                #read_only_check
+                self.#acl_field.assert_no_grant_threshold(role);
+                self.#acl_field.grant_role_unchecked(role, &::near_sdk::env::predecessor_account_id())
             }
         }
     };
 
+    output.extend(plugin_abi("AccessControllable", &ident, &output));
     output.into()
 }
 
@@ -524,7 +2810,20 @@ fn inject_acl_field(
 
 #[derive(Debug, FromMeta)]
 pub struct MacroArgsAny {
+    #[darling(default)]
     roles: darling::util::PathList,
+    /// Names of role groups declared in `#[access_control]`, whose roles are
+    /// checked in addition to `roles`.
+    #[darling(default)]
+    groups: GroupNames,
+    /// Expression evaluating to the scope in which roles are checked, e.g.
+    /// an argument of the method.
+    #[darling(default)]
+    scope: Option<String>,
+    /// Message to panic with if the caller lacks the roles. By default the
+    /// message names the method and its roles.
+    #[darling(default)]
+    message: Option<String>,
 }
 
 pub fn access_control_any(attrs: TokenStream, item: TokenStream) -> TokenStream {
@@ -551,25 +2850,156 @@ pub fn access_control_any(attrs: TokenStream, item: TokenStream) -> TokenStream
         }
     };
     let roles = macro_args.roles;
-    assert!(roles.len() > 0, "Specify at least one role");
+    assert!(
+        roles.len() > 0 || !macro_args.groups.0.is_empty(),
+        "Specify at least one role or group"
+    );
+    let mut groups = vec![];
+    for name in macro_args.groups.0.iter() {
+        match role_group_ident(name, Span::call_site()) {
+            Ok(ident) => groups.push(ident),
+            Err(e) => return TokenStream::from(e.to_compile_error()),
+        }
+    }
+    let scope = match macro_args.scope.as_deref().map(syn::parse_str::<syn::Expr>) {
+        None => None,
+        Some(Ok(scope)) => Some(scope),
+        Some(Err(e)) => return TokenStream::from(e.to_compile_error()),
+    };
+
+    // Uses of consumable roles can be consumed only by methods which may
+    // modify state.
+    let is_mut_receiver = matches!(
+        sig.inputs.first(),
+        Some(syn::FnArg::Receiver(receiver)) if receiver.mutability.is_some()
+    );
+    let consume_role_use = if is_mut_receiver && scope.is_none() {
+        let acl_field = syn::Ident::new(DEFAULT_ACL_FIELD_NAME, Span::call_site());
+        quote! {
+            self.#acl_field.consume_role_use(&__acl_any_role_values, &__acl_any_account_id);
+        }
+    } else {
+        quote! {}
+    };
+
+    let has_any_role = match scope {
+        Some(scope) => {
+            let acl_field = syn::Ident::new(DEFAULT_ACL_FIELD_NAME, Span::call_site());
+            quote! {
+                self.#acl_field.has_any_role_scoped(
+                    &__acl_any_role_values,
+                    &::std::string::ToString::to_string(&(#scope)),
+                    &__acl_any_account_id,
+                )
+            }
+        }
+        None => quote! {
+            self.acl_has_any_role(__acl_any_roles_ser, __acl_any_account_id.clone())
+        },
+    };
+
+    let panic_insufficient_permissions = match macro_args.message {
+        Some(message) => quote! {
+            env::panic_str(#message);
+        },
+        None => quote! {
+            let message = format!(
+                "Insufficient permissions for method {} restricted by access control. Requires one of these roles: {:?}",
+                #function_name,
+                __acl_any_roles,
+            );
+            env::panic_str(&message);
+        },
+    };
 
     // TODO optimize case `roles.len() == 1` (speed up expected common case)
+    let role_values = if groups.is_empty() {
+        quote! {
+            let __acl_any_role_values = vec![#(#roles),*];
+        }
+    } else {
+        quote! {
+            let mut __acl_any_role_values = vec![#(#roles),*];
+            #(
+                __acl_any_role_values.extend_from_slice(Self::#groups);
+            )*
+        }
+    };
+
     let acl_check = quote! {
-        let __acl_any_roles: Vec<&str> = vec![#(#roles.into()),*];
+        #role_values
+        let __acl_any_roles: Vec<&str> = __acl_any_role_values
+            .iter()
+            .map(|&role| role.into())
+            .collect();
         let __acl_any_roles_ser: Vec<String> =
             __acl_any_roles.iter().map(|&role| role.into()).collect();
         let __acl_any_account_id = ::near_sdk::env::predecessor_account_id();
-        if !self.acl_has_any_role(__acl_any_roles_ser, __acl_any_account_id) {
+        if !#has_any_role {
+            #panic_insufficient_permissions
+        }
+        #consume_role_use
+    };
+
+    // https://stackoverflow.com/a/66851407
+    quote! {
+        #(#attrs)* #vis #sig {
+            #acl_check
+            #(#stmts)*
+        }
+    }
+    .into()
+}
+
+#[derive(Debug, FromMeta)]
+pub struct MacroArgsAll {
+    roles: darling::util::PathList,
+}
+
+pub fn access_control_all(attrs: TokenStream, item: TokenStream) -> TokenStream {
+    let attr_args = parse_macro_input!(attrs as AttributeArgs);
+    let cloned_item = item.clone();
+    let input: ItemFn = parse_macro_input!(cloned_item);
+    if is_near_bindgen_wrapped_or_marshall(&input) {
+        return item;
+    }
+
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = input;
+    let function_name = sig.ident.to_string();
+    let stmts = &block.stmts;
+
+    let macro_args = match MacroArgsAll::from_list(&attr_args) {
+        Ok(args) => args,
+        Err(e) => {
+            return TokenStream::from(e.write_errors());
+        }
+    };
+    let roles = macro_args.roles;
+    assert!(roles.len() > 0, "Specify at least one role");
+
+    // Super-admins bypass the check.
+    let acl_check = quote! {
+        let __acl_all_roles: Vec<&str> = vec![#(#roles.into()),*];
+        let __acl_all_roles_ser: Vec<String> =
+            __acl_all_roles.iter().map(|&role| role.into()).collect();
+        let __acl_all_account_id = ::near_sdk::env::predecessor_account_id();
+        if !self.acl_is_super_admin(__acl_all_account_id.clone())
+            && !self.acl_has_all_roles(__acl_all_roles_ser, __acl_all_account_id)
+        {
             let message = format!(
-                "Insufficient permissions for method {} restricted by access control. Requires one of these roles: {:?}",
+                "Insufficient permissions for method {} restricted by access control. Requires all of these roles: {:?}",
                 #function_name,
-                __acl_any_roles,
+                __acl_all_roles,
             );
             env::panic_str(&message);
         }
     };
 
-    // https://stackoverflow.com/a/66851407
     quote! {
         #(#attrs)* #vis #sig {
             #acl_check
@@ -578,3 +3008,32 @@ pub fn access_control_any(attrs: TokenStream, item: TokenStream) -> TokenStream
     }
     .into()
 }
+
+pub fn grants_role_on_success(attrs: TokenStream, item: TokenStream) -> TokenStream {
+    let role = parse_macro_input!(attrs as syn::Path);
+    let cloned_item = item.clone();
+    let input: ItemFn = parse_macro_input!(cloned_item);
+    if is_near_bindgen_wrapped_or_marshall(&input) {
+        return item;
+    }
+
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = input;
+    let output = &sig.output;
+
+    // The body is wrapped in a closure to grant the role after it ran,
+    // regardless of early returns. If the body panics, the receipt fails and
+    // the role is not granted.
+    quote! {
+        #(#attrs)* #vis #sig {
+            let __acl_result = (|| #output #block)();
+            self.__acl_grant_role_on_success(#role);
+            __acl_result
+        }
+    }
+    .into()
+}
@@ -1,31 +1,97 @@
+use crate::abi::plugin_abi;
 use crate::utils::cratename;
+use darling::FromDeriveInput;
 use proc_macro::{self, TokenStream};
 use quote::quote;
 use syn::{parse_macro_input, DeriveInput};
 
+#[derive(FromDeriveInput, Default)]
+#[darling(
+    default,
+    attributes(full_access_key_fallback),
+    forward_attrs(allow, doc, cfg)
+)]
+struct Opts {
+    keys_storage_key: Option<String>,
+}
+
 pub fn derive_fak_fallback(input: TokenStream) -> TokenStream {
     let cratename = cratename();
 
     let input = parse_macro_input!(input);
+    let opts = Opts::from_derive_input(&input).expect("Wrong options");
     let DeriveInput { ident, .. } = input;
 
-    let output = quote! {
+    let keys_storage_key = opts
+        .keys_storage_key
+        .unwrap_or_else(|| "__FAK_KEYS__".to_string());
+
+    let mut output = quote! {
         #[near_bindgen]
         impl FullAccessKeyFallback for #ident {
             #[#cratename::only(owner)]
             fn attach_full_access_key(&mut self, public_key: ::near_sdk::PublicKey) -> near_sdk::Promise {
-                let current_account_id = ::near_sdk::env::current_account_id();
-                // TODO: Use .emit
-                ::near_sdk::log!(#cratename::events::AsEvent::event(
+                #cratename::read_only::assert_not_read_only();
+                // The key is recorded and the event emitted in the callback,
+                // since adding the key fails e.g. if the key already exists.
+                let callback_args = ::near_sdk::serde_json::json!({
+                    "by": ::near_sdk::env::predecessor_account_id(),
+                    "public_key": public_key,
+                });
+                ::near_sdk::Promise::new(::near_sdk::env::current_account_id())
+                    .add_full_access_key(public_key)
+                    .then(::near_sdk::Promise::new(::near_sdk::env::current_account_id()).function_call(
+                        "fak_on_full_access_key_added".to_string(),
+                        callback_args.to_string().into_bytes(),
+                        0,
+                        #cratename::full_access_key_fallback::FAK_ON_FULL_ACCESS_KEY_ADDED_GAS,
+                    ))
+            }
+
+            #[private]
+            fn fak_on_full_access_key_added(
+                &mut self,
+                by: ::near_sdk::AccountId,
+                public_key: ::near_sdk::PublicKey,
+            ) -> bool {
+                if !matches!(
+                    ::near_sdk::env::promise_result(0),
+                    ::near_sdk::PromiseResult::Successful(_)
+                ) {
+                    return false;
+                }
+
+                #cratename::events::AsEvent::emit(
                     &#cratename::full_access_key_fallback::FullAccessKeyAdded {
-                        by: current_account_id.clone(),
-                        public_key: public_key.clone()
-                    }
-                ));
-                ::near_sdk::Promise::new(current_account_id).add_full_access_key(public_key)
+                        by,
+                        public_key: public_key.clone(),
+                    },
+                );
+
+                let mut keys = self.fak_get_keys();
+                if !keys.contains(&public_key) {
+                    keys.push(public_key.clone());
+                    ::near_sdk::env::storage_write(
+                        (#keys_storage_key).as_bytes(),
+                        ::near_sdk::borsh::BorshSerialize::try_to_vec(&keys)
+                            .expect("FullAccessKeyFallback: Unexpected error serializing keys")
+                            .as_ref(),
+                    );
+                }
+                true
+            }
+
+            fn fak_get_keys(&self) -> Vec<::near_sdk::PublicKey> {
+                ::near_sdk::env::storage_read((#keys_storage_key).as_bytes())
+                    .map(|value| {
+                        ::near_sdk::borsh::BorshDeserialize::try_from_slice(value.as_ref())
+                            .expect("FullAccessKeyFallback: Invalid format for keys")
+                    })
+                    .unwrap_or_default()
             }
         }
     };
 
+    output.extend(plugin_abi("FullAccessKeyFallback", &ident, &output));
     output.into()
 }
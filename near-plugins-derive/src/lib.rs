@@ -1,5 +1,6 @@
 use proc_macro::{self, TokenStream};
 
+mod abi;
 mod access_control_role;
 mod access_controllable;
 mod full_access_key_fallback;
@@ -23,7 +24,7 @@ pub fn derive_upgradable(input: TokenStream) -> TokenStream {
     upgradable::derive_upgradable(input)
 }
 
-#[proc_macro_derive(FullAccessKeyFallback)]
+#[proc_macro_derive(FullAccessKeyFallback, attributes(full_access_key_fallback))]
 pub fn derive_fak_fallback(input: TokenStream) -> TokenStream {
     full_access_key_fallback::derive_fak_fallback(input)
 }
@@ -43,7 +44,12 @@ pub fn if_paused(attrs: TokenStream, item: TokenStream) -> TokenStream {
     pausable::if_paused(attrs, item)
 }
 
-#[proc_macro_derive(AccessControlRole)]
+#[proc_macro_derive(PluginsAbi)]
+pub fn derive_plugins_abi(input: TokenStream) -> TokenStream {
+    abi::derive_plugins_abi(input)
+}
+
+#[proc_macro_derive(AccessControlRole, attributes(grant_threshold))]
 pub fn derive_access_control_role(input: TokenStream) -> TokenStream {
     access_control_role::derive_access_control_role(input)
 }
@@ -57,3 +63,13 @@ pub fn access_control(attrs: TokenStream, item: TokenStream) -> TokenStream {
 pub fn access_control_any(attrs: TokenStream, item: TokenStream) -> TokenStream {
     access_controllable::access_control_any(attrs, item)
 }
+
+#[proc_macro_attribute]
+pub fn access_control_all(attrs: TokenStream, item: TokenStream) -> TokenStream {
+    access_controllable::access_control_all(attrs, item)
+}
+
+#[proc_macro_attribute]
+pub fn grants_role_on_success(attrs: TokenStream, item: TokenStream) -> TokenStream {
+    access_controllable::grants_role_on_success(attrs, item)
+}
@@ -1,3 +1,4 @@
+use crate::abi::plugin_abi;
 use crate::utils::{cratename, is_near_bindgen_wrapped_or_marshall};
 use darling::FromDeriveInput;
 use proc_macro::{self, TokenStream};
@@ -8,6 +9,8 @@ use syn::{parse, parse_macro_input, DeriveInput, ItemFn};
 #[darling(default, attributes(ownable), forward_attrs(allow, doc, cfg))]
 struct Opts {
     owner_storage_key: Option<String>,
+    proposed_owner_storage_key: Option<String>,
+    owner_renounced_storage_key: Option<String>,
 }
 
 pub fn derive_ownable(input: TokenStream) -> TokenStream {
@@ -20,8 +23,14 @@ pub fn derive_ownable(input: TokenStream) -> TokenStream {
     let owner_storage_key = opts
         .owner_storage_key
         .unwrap_or_else(|| "__OWNER__".to_string());
-
-    let output = quote! {
+    let proposed_owner_storage_key = opts
+        .proposed_owner_storage_key
+        .unwrap_or_else(|| "__PROPOSED_OWNER__".to_string());
+    let owner_renounced_storage_key = opts
+        .owner_renounced_storage_key
+        .unwrap_or_else(|| "__OWNER_RENOUNCED__".to_string());
+
+    let mut output = quote! {
         #[near_bindgen]
         impl Ownable for #ident {
             fn owner_storage_key(&self) -> Vec<u8> {
@@ -37,6 +46,11 @@ pub fn derive_ownable(input: TokenStream) -> TokenStream {
             }
 
             fn owner_set(&mut self, owner: Option<::near_sdk::AccountId>) {
+                #cratename::read_only::assert_not_read_only();
+                assert!(
+                    !self.owner_is_renounced(),
+                    "Ownable: Ownership has been renounced"
+                );
                 let current_owner = self.owner_get();
 
                 if let Some(owner) = current_owner.as_ref() {
@@ -69,6 +83,93 @@ pub fn derive_ownable(input: TokenStream) -> TokenStream {
                     ),
                     None => ::near_sdk::env::storage_remove(&self.owner_storage_key()),
                 };
+                // A proposal made by the previous owner is void.
+                ::near_sdk::env::storage_remove((#proposed_owner_storage_key).as_bytes());
+            }
+
+            fn owner_renounce(&mut self) {
+                #cratename::read_only::assert_not_read_only();
+                assert!(self.owner_is(), "Ownable: Only owner can renounce ownership");
+
+                ::near_sdk::log!(#cratename::events::AsEvent::event(
+                    &#cratename::ownable::OwnershipRenounced {
+                        previous_owner: ::near_sdk::env::predecessor_account_id(),
+                    }
+                ));
+
+                ::near_sdk::env::storage_remove(&self.owner_storage_key());
+                ::near_sdk::env::storage_remove((#proposed_owner_storage_key).as_bytes());
+                ::near_sdk::env::storage_write((#owner_renounced_storage_key).as_bytes(), &[]);
+            }
+
+            fn owner_is_renounced(&self) -> bool {
+                ::near_sdk::env::storage_has_key((#owner_renounced_storage_key).as_bytes())
+            }
+
+            fn owner_get_proposed(&self) -> Option<::near_sdk::AccountId> {
+                ::near_sdk::env::storage_read((#proposed_owner_storage_key).as_bytes()).map(
+                    |owner_bytes| {
+                        let owner_raw = String::from_utf8(owner_bytes)
+                            .expect("Ownable: Invalid string format");
+                        std::convert::TryInto::try_into(owner_raw)
+                            .expect("Ownable: Invalid account id")
+                    },
+                )
+            }
+
+            fn owner_propose(&mut self, account_id: Option<::near_sdk::AccountId>) {
+                #cratename::read_only::assert_not_read_only();
+                assert!(self.owner_is(), "Ownable: Only owner can propose a new owner");
+                assert_ne!(
+                    account_id,
+                    Some(::near_sdk::env::predecessor_account_id()),
+                    "Ownable: The current owner can't be proposed as new owner"
+                );
+
+                ::near_sdk::log!(#cratename::events::AsEvent::event(
+                    &#cratename::ownable::OwnershipTransferProposed {
+                        owner: ::near_sdk::env::predecessor_account_id(),
+                        proposed_owner: account_id.clone(),
+                    }
+                ));
+
+                match account_id.as_ref() {
+                    Some(account_id) => ::near_sdk::env::storage_write(
+                        (#proposed_owner_storage_key).as_bytes(),
+                        account_id.as_ref().as_bytes(),
+                    ),
+                    None => ::near_sdk::env::storage_remove((#proposed_owner_storage_key).as_bytes()),
+                };
+            }
+
+            fn owner_accept(&mut self) {
+                #cratename::read_only::assert_not_read_only();
+                let new_owner = ::near_sdk::env::predecessor_account_id();
+                assert_eq!(
+                    self.owner_get_proposed().as_ref(),
+                    Some(&new_owner),
+                    "Ownable: Only the proposed owner can accept ownership"
+                );
+                let previous_owner = self.owner_get();
+
+                ::near_sdk::log!(#cratename::events::AsEvent::event(
+                    &#cratename::ownable::OwnershipTransferAccepted {
+                        previous_owner: previous_owner.clone(),
+                        new_owner: new_owner.clone(),
+                    }
+                ));
+                ::near_sdk::log!(#cratename::events::AsEvent::event(
+                    &#cratename::ownable::OwnershipTransferred {
+                        previous_owner,
+                        new_owner: Some(new_owner.clone()),
+                    }
+                ));
+
+                ::near_sdk::env::storage_write(
+                    &self.owner_storage_key(),
+                    new_owner.as_ref().as_bytes(),
+                );
+                ::near_sdk::env::storage_remove((#proposed_owner_storage_key).as_bytes());
             }
 
             fn owner_is(&self) -> bool {
@@ -79,6 +180,7 @@ pub fn derive_ownable(input: TokenStream) -> TokenStream {
         }
     };
 
+    output.extend(plugin_abi("Ownable", &ident, &output));
     output.into()
 }
 
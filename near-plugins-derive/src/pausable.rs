@@ -1,3 +1,4 @@
+use crate::abi::plugin_abi;
 use crate::utils::{cratename, is_near_bindgen_wrapped_or_marshall};
 use darling::{FromDeriveInput, FromMeta};
 use proc_macro::{self, TokenStream};
@@ -8,6 +9,10 @@ use syn::{parse, parse_macro_input, AttributeArgs, DeriveInput, ItemFn};
 #[darling(default, attributes(pausable), forward_attrs(allow, doc, cfg))]
 struct Opts {
     paused_storage_key: Option<String>,
+    maintenance_message_storage_key: Option<String>,
+    paused_since_storage_key: Option<String>,
+    pause_reasons_storage_key: Option<String>,
+    paused_until_storage_key: Option<String>,
 }
 
 pub fn derive_pausable(input: TokenStream) -> TokenStream {
@@ -20,8 +25,20 @@ pub fn derive_pausable(input: TokenStream) -> TokenStream {
     let paused_storage_key = opts
         .paused_storage_key
         .unwrap_or_else(|| "__PAUSE__".to_string());
-
-    let output = quote! {
+    let maintenance_message_storage_key = opts
+        .maintenance_message_storage_key
+        .unwrap_or_else(|| "__PAUSE_MESSAGE__".to_string());
+    let paused_since_storage_key = opts
+        .paused_since_storage_key
+        .unwrap_or_else(|| "__PAUSE_SINCE__".to_string());
+    let pause_reasons_storage_key = opts
+        .pause_reasons_storage_key
+        .unwrap_or_else(|| "__PAUSE_REASONS__".to_string());
+    let paused_until_storage_key = opts
+        .paused_until_storage_key
+        .unwrap_or_else(|| "__PAUSE_UNTIL__".to_string());
+
+    let mut output = quote! {
         #[near_bindgen]
         impl Pausable for #ident {
             fn pa_storage_key(&self) -> Vec<u8>{
@@ -35,37 +52,157 @@ pub fn derive_pausable(input: TokenStream) -> TokenStream {
             }
 
             fn pa_all_paused(&self) -> Option<std::collections::HashSet<String>> {
-                ::near_sdk::env::storage_read(self.pa_storage_key().as_ref()).map(|value| {
-                    std::collections::HashSet::try_from_slice(value.as_ref())
-                        .expect("Pausable: Invalid format for paused keys")
-                })
+                let mut paused_keys = self.pa_read_paused_keys()?;
+                let paused_until = self.pa_paused_until();
+                let now = ::near_sdk::env::block_timestamp();
+                paused_keys.retain(|key| paused_until.get(key).map_or(true, |&until| now <= until));
+                if paused_keys.is_empty() {
+                    None
+                } else {
+                    Some(paused_keys)
+                }
+            }
+
+            fn pa_paused_for(&self, key: String) -> Option<u64> {
+                let paused_keys = self.pa_all_paused()?;
+                let paused_since = self.pa_paused_since();
+                let since = [key.as_str(), "ALL"]
+                    .iter()
+                    .filter(|&&key| paused_keys.contains(key))
+                    .filter_map(|&key| paused_since.get(key).copied())
+                    .min()?;
+                Some(::near_sdk::env::block_timestamp().saturating_sub(since))
             }
 
             #[#cratename::only(owner)]
             fn pa_pause_feature(&mut self, key: String) {
-                let mut paused_keys = self.pa_all_paused().unwrap_or_default();
-                paused_keys.insert(key.clone());
+                self.pa_pause(key, None, None);
+            }
+
+            #[#cratename::only(owner)]
+            fn pa_pause_feature_with_reason(&mut self, key: String, reason: String) {
+                self.pa_pause(key, Some(reason), None);
+            }
+
+            #[#cratename::only(owner)]
+            fn pa_pause_feature_until(&mut self, key: String, unpause_at_ns: u64) {
+                self.pa_pause(key, None, Some(unpause_at_ns));
+            }
+
+            fn pa_unpause_at(&self, key: String) -> Option<u64> {
+                self.pa_all_paused().filter(|keys| keys.contains(&key))?;
+                self.pa_paused_until().remove(&key)
+            }
+
+            fn pa_pause_reason(&self, key: String) -> Option<String> {
+                self.pa_all_paused().filter(|keys| keys.contains(&key))?;
+                self.pa_pause_reasons().remove(&key)
+            }
+
+            #[#cratename::only(owner)]
+            fn pa_unpause_feature(&mut self, key: String) {
+                self.pa_unpause(key);
+            }
+
+            #[#cratename::only(owner)]
+            fn pa_pause_all(&mut self) {
+                self.pa_pause("ALL".to_string(), None, None);
+            }
+
+            #[#cratename::only(owner)]
+            fn pa_unpause_all(&mut self) {
+                #cratename::read_only::assert_not_read_only();
+                self.pa_remove_expired_pauses();
+                let mut paused_keys: Vec<String> =
+                    self.pa_all_paused().unwrap_or_default().into_iter().collect();
+                paused_keys.sort();
+                for key in paused_keys {
+                    self.pa_unpause(key);
+                }
+            }
+
+            #[#cratename::only(owner)]
+            fn pa_set_maintenance_message(&mut self, message: Option<String>) {
+                #cratename::read_only::assert_not_read_only();
+                match message {
+                    Some(message) => ::near_sdk::env::storage_write(
+                        (#maintenance_message_storage_key).as_bytes(),
+                        message.as_bytes(),
+                    ),
+                    None => ::near_sdk::env::storage_remove(
+                        (#maintenance_message_storage_key).as_bytes(),
+                    ),
+                };
+            }
+
+            fn pa_maintenance_message(&self) -> Option<String> {
+                ::near_sdk::env::storage_read((#maintenance_message_storage_key).as_bytes()).map(
+                    |message| {
+                        String::from_utf8(message).expect("Pausable: Invalid maintenance message")
+                    },
+                )
+            }
+        }
+
+        impl #ident {
+            /// Returns an error if `key` is paused, either by itself or via "ALL". Unlike
+            /// `#[pause]` it does not panic, so callers may degrade gracefully.
+            pub fn pa_check_not_paused(&self, key: &str) -> Result<(), #cratename::pausable::PausableError> {
+                if self.pa_is_paused(key.to_string()) {
+                    Err(#cratename::pausable::PausableError::Paused { key: key.to_string() })
+                } else {
+                    Ok(())
+                }
+            }
+
+            /// Pauses `key` with an optional `reason` and an optional time at
+            /// which the pause expires. Both replace the ones of a previous
+            /// pause. It does not check permissions.
+            fn pa_pause(&mut self, key: String, reason: Option<String>, unpause_at_ns: Option<u64>) {
+                #cratename::read_only::assert_not_read_only();
+                self.pa_remove_expired_pauses();
+                let mut paused_keys = self.pa_read_paused_keys().unwrap_or_default();
+                if paused_keys.insert(key.clone()) {
+                    let mut paused_since = self.pa_paused_since();
+                    paused_since.insert(key.clone(), ::near_sdk::env::block_timestamp());
+                    self.pa_write_paused_since(&paused_since);
+                }
+
+                let mut pause_reasons = self.pa_pause_reasons();
+                let reasons_changed = match reason.clone() {
+                    Some(reason) => pause_reasons.insert(key.clone(), reason.clone()) != Some(reason),
+                    None => pause_reasons.remove(&key).is_some(),
+                };
+                if reasons_changed {
+                    self.pa_write_pause_reasons(&pause_reasons);
+                }
+
+                let mut paused_until = self.pa_paused_until();
+                let until_changed = match unpause_at_ns {
+                    Some(until) => paused_until.insert(key.clone(), until) != Some(until),
+                    None => paused_until.remove(&key).is_some(),
+                };
+                if until_changed {
+                    self.pa_write_paused_until(&paused_until);
+                }
 
                 ::near_sdk::log!(#cratename::events::AsEvent::event(
                     &#cratename::pausable::Pause {
                         by: ::near_sdk::env::predecessor_account_id(),
                         key,
+                        reason,
+                        unpause_at_ns,
                     }
                 ));
 
-                ::near_sdk::env::storage_write(
-                    self.pa_storage_key().as_ref(),
-                    paused_keys
-                        .try_to_vec()
-                        .expect("Pausable: Unexpected error serializing keys")
-                        .as_ref(),
-                );
+                self.pa_write_paused_keys(&paused_keys);
             }
 
-            #[#cratename::only(owner)]
-            fn pa_unpause_feature(&mut self, key: String) {
-                let mut paused_keys = self.pa_all_paused().unwrap_or_default();
-                paused_keys.remove(&key);
+            /// Unpauses `key`. It does not check permissions.
+            fn pa_unpause(&mut self, key: String) {
+                #cratename::read_only::assert_not_read_only();
+                self.pa_remove_expired_pauses();
+                self.pa_remove_paused_key(&key);
 
                 ::near_sdk::log!(#cratename::events::AsEvent::event(
                     &#cratename::pausable::Unpause {
@@ -73,7 +210,51 @@ pub fn derive_pausable(input: TokenStream) -> TokenStream {
                         key,
                     }
                 ));
+            }
+
+            /// Removes keys whose pause expired from storage. No events are
+            /// emitted, since these keys are considered unpaused already.
+            fn pa_remove_expired_pauses(&mut self) {
+                let now = ::near_sdk::env::block_timestamp();
+                let expired_keys: Vec<String> = self
+                    .pa_paused_until()
+                    .into_iter()
+                    .filter(|&(_, until)| now > until)
+                    .map(|(key, _)| key)
+                    .collect();
+                for key in expired_keys {
+                    self.pa_remove_paused_key(&key);
+                }
+            }
+
+            /// Removes `key` and its metadata from storage.
+            fn pa_remove_paused_key(&mut self, key: &str) {
+                let mut paused_keys = self.pa_read_paused_keys().unwrap_or_default();
+                paused_keys.remove(key);
+                let mut paused_since = self.pa_paused_since();
+                if paused_since.remove(key).is_some() {
+                    self.pa_write_paused_since(&paused_since);
+                }
+                let mut pause_reasons = self.pa_pause_reasons();
+                if pause_reasons.remove(key).is_some() {
+                    self.pa_write_pause_reasons(&pause_reasons);
+                }
+                let mut paused_until = self.pa_paused_until();
+                if paused_until.remove(key).is_some() {
+                    self.pa_write_paused_until(&paused_until);
+                }
+                self.pa_write_paused_keys(&paused_keys);
+            }
+
+            /// Returns the paused keys including those whose pause expired.
+            fn pa_read_paused_keys(&self) -> Option<std::collections::HashSet<String>> {
+                ::near_sdk::env::storage_read(self.pa_storage_key().as_ref()).map(|value| {
+                    std::collections::HashSet::try_from_slice(value.as_ref())
+                        .expect("Pausable: Invalid format for paused keys")
+                })
+            }
 
+            fn pa_write_paused_keys(&self, paused_keys: &std::collections::HashSet<String>) {
                 if paused_keys.is_empty() {
                     ::near_sdk::env::storage_remove(self.pa_storage_key().as_ref());
                 } else {
@@ -86,9 +267,85 @@ pub fn derive_pausable(input: TokenStream) -> TokenStream {
                     );
                 }
             }
+
+            /// Returns the times at which pauses of keys paused via
+            /// `pa_pause_feature_until` expire.
+            fn pa_paused_until(&self) -> std::collections::HashMap<String, u64> {
+                ::near_sdk::env::storage_read((#paused_until_storage_key).as_bytes())
+                    .map(|value| {
+                        std::collections::HashMap::try_from_slice(value.as_ref())
+                            .expect("Pausable: Invalid format for pause expiries")
+                    })
+                    .unwrap_or_default()
+            }
+
+            fn pa_write_paused_until(&self, paused_until: &std::collections::HashMap<String, u64>) {
+                if paused_until.is_empty() {
+                    ::near_sdk::env::storage_remove((#paused_until_storage_key).as_bytes());
+                } else {
+                    ::near_sdk::env::storage_write(
+                        (#paused_until_storage_key).as_bytes(),
+                        paused_until
+                            .try_to_vec()
+                            .expect("Pausable: Unexpected error serializing pause expiries")
+                            .as_ref(),
+                    );
+                }
+            }
+
+            /// Returns the reasons of currently paused keys which were paused
+            /// with a reason.
+            fn pa_pause_reasons(&self) -> std::collections::HashMap<String, String> {
+                ::near_sdk::env::storage_read((#pause_reasons_storage_key).as_bytes())
+                    .map(|value| {
+                        std::collections::HashMap::try_from_slice(value.as_ref())
+                            .expect("Pausable: Invalid format for pause reasons")
+                    })
+                    .unwrap_or_default()
+            }
+
+            fn pa_write_pause_reasons(&self, pause_reasons: &std::collections::HashMap<String, String>) {
+                if pause_reasons.is_empty() {
+                    ::near_sdk::env::storage_remove((#pause_reasons_storage_key).as_bytes());
+                } else {
+                    ::near_sdk::env::storage_write(
+                        (#pause_reasons_storage_key).as_bytes(),
+                        pause_reasons
+                            .try_to_vec()
+                            .expect("Pausable: Unexpected error serializing pause reasons")
+                            .as_ref(),
+                    );
+                }
+            }
+
+            /// Returns the timestamps at which currently paused keys were
+            /// paused.
+            fn pa_paused_since(&self) -> std::collections::HashMap<String, u64> {
+                ::near_sdk::env::storage_read((#paused_since_storage_key).as_bytes())
+                    .map(|value| {
+                        std::collections::HashMap::try_from_slice(value.as_ref())
+                            .expect("Pausable: Invalid format for pause timestamps")
+                    })
+                    .unwrap_or_default()
+            }
+
+            fn pa_write_paused_since(&self, paused_since: &std::collections::HashMap<String, u64>) {
+                if paused_since.is_empty() {
+                    ::near_sdk::env::storage_remove((#paused_since_storage_key).as_bytes());
+                } else {
+                    ::near_sdk::env::storage_write(
+                        (#paused_since_storage_key).as_bytes(),
+                        paused_since
+                            .try_to_vec()
+                            .expect("Pausable: Unexpected error serializing pause timestamps")
+                            .as_ref(),
+                    );
+                }
+            }
         }
     };
 
+    output.extend(plugin_abi("Pausable", &ident, &output));
     output.into()
 }
 
@@ -108,6 +365,10 @@ pub struct PauseArgs {
     name: Option<String>,
     #[darling(default)]
     except: ExceptSubArgs,
+    /// Roles whose grantees may call the method while it is paused. Requires the contract to be
+    /// `AccessControllable`.
+    #[darling(default)]
+    except_roles: darling::util::PathList,
 }
 
 pub fn pause(attrs: TokenStream, item: TokenStream) -> TokenStream {
@@ -150,9 +411,24 @@ pub fn pause(attrs: TokenStream, item: TokenStream) -> TokenStream {
         quote!()
     };
 
+    let except_roles = args.except_roles;
+    let roles_condition = if except_roles.is_empty() {
+        quote!()
+    } else {
+        quote!(
+            if self.acl_has_any_role(
+                vec![#(String::from(#except_roles)),*],
+                ::near_sdk::env::predecessor_account_id(),
+            ) {
+                check_paused = false;
+            }
+        )
+    };
+
     let bypass_condition = quote!(
         #self_condition
         #owner_condition
+        #roles_condition
     );
 
     let check_pause = quote!(
@@ -1,6 +1,8 @@
+use crate::abi::plugin_abi;
 use crate::utils::cratename;
 use darling::FromDeriveInput;
 use proc_macro::{self, TokenStream};
+use proc_macro2::Ident;
 use quote::quote;
 use syn::{parse_macro_input, DeriveInput};
 
@@ -8,6 +10,38 @@ use syn::{parse_macro_input, DeriveInput};
 #[darling(default, attributes(upgradable), forward_attrs(allow, doc, cfg))]
 struct Opts {
     code_storage_key: Option<String>,
+    staging_duration_storage_key: Option<String>,
+    delay_status_storage_key: Option<String>,
+    staging_duration_update_storage_key: Option<String>,
+    /// Roles whose grantees may stage code instead of the owner.
+    stage_roles: darling::util::PathList,
+    /// Roles whose grantees may deploy code instead of the owner.
+    deploy_roles: darling::util::PathList,
+}
+
+/// Returns the attribute restricting a method to the owner and the check
+/// inside the method. If `roles` are given, only their grantees may call the
+/// method instead of the owner. Requires the contract to be
+/// `AccessControllable` in that case.
+fn permission_check(
+    cratename: &Ident,
+    roles: &darling::util::PathList,
+    action: &str,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    if roles.is_empty() {
+        return (quote! { #[#cratename::only(owner)] }, quote! {});
+    }
+    let roles = roles.iter();
+    let check = quote! {
+        let __up_roles: Vec<String> = vec![#(String::from(#roles)),*];
+        assert!(
+            self.acl_has_any_role(__up_roles.clone(), ::near_sdk::env::predecessor_account_id()),
+            "Upgradable: {} requires one of these roles: {:?}",
+            #action,
+            __up_roles,
+        );
+    };
+    (quote! {}, check)
 }
 
 pub fn derive_upgradable(input: TokenStream) -> TokenStream {
@@ -20,20 +54,46 @@ pub fn derive_upgradable(input: TokenStream) -> TokenStream {
     let code_storage_key = opts
         .code_storage_key
         .unwrap_or_else(|| "__CODE__".to_string());
+    let staging_duration_storage_key = opts
+        .staging_duration_storage_key
+        .unwrap_or_else(|| "__STAGING_DURATION__".to_string());
+    let delay_status_storage_key = opts
+        .delay_status_storage_key
+        .unwrap_or_else(|| "__DELAY_STATUS__".to_string());
+    let staging_duration_update_storage_key = opts
+        .staging_duration_update_storage_key
+        .unwrap_or_else(|| "__STAGING_DURATION_UPDATE__".to_string());
 
-    let output = quote! {
+    let (stage_attr, stage_check) = permission_check(&cratename, &opts.stage_roles, "Staging");
+    let (deploy_attr, deploy_check) = permission_check(&cratename, &opts.deploy_roles, "Deploying");
+
+    let mut output = quote! {
         #[near_bindgen]
         impl Upgradable for #ident {
             fn up_storage_key(&self) -> Vec<u8>{
                 (#code_storage_key).as_bytes().to_vec()
             }
 
-            #[#cratename::only(owner)]
+            #stage_attr
             fn up_stage_code(&mut self, #[serializer(borsh)] code: Vec<u8>) {
+                #cratename::read_only::assert_not_read_only();
+                #stage_check
                 if code.is_empty() {
                     near_sdk::env::storage_remove(self.up_storage_key().as_ref());
+                    near_sdk::env::storage_remove((#delay_status_storage_key).as_bytes());
                 } else {
                     near_sdk::env::storage_write(self.up_storage_key().as_ref(), code.as_ref());
+                    let staged_at = near_sdk::env::block_timestamp();
+                    let status = #cratename::upgradable::DelayStatus {
+                        staged_at,
+                        deploy_allowed_at: staged_at.saturating_add(self.up_get_staging_duration()),
+                    };
+                    near_sdk::env::storage_write(
+                        (#delay_status_storage_key).as_bytes(),
+                        near_sdk::borsh::BorshSerialize::try_to_vec(&status)
+                            .expect("Upgradable: Unexpected error serializing delay status")
+                            .as_ref(),
+                    );
                 }
             }
 
@@ -42,18 +102,121 @@ pub fn derive_upgradable(input: TokenStream) -> TokenStream {
                 near_sdk::env::storage_read(self.up_storage_key().as_ref())
             }
 
-            fn up_staged_code_hash(&self) -> Option<::near_sdk::CryptoHash> {
-                self.up_staged_code()
-                    .map(|code| std::convert::TryInto::try_into(near_sdk::env::sha256(code.as_ref())).unwrap())
+            fn up_staged_code_hash(&self) -> Option<::near_sdk::json_types::Base58CryptoHash> {
+                self.up_staged_code().map(|code| {
+                    let hash: ::near_sdk::CryptoHash =
+                        std::convert::TryInto::try_into(near_sdk::env::sha256(code.as_ref())).unwrap();
+                    hash.into()
+                })
             }
 
             #[#cratename::only(owner)]
+            fn up_set_staging_duration(&mut self, staging_duration: u64) {
+                #cratename::read_only::assert_not_read_only();
+                let now = near_sdk::env::block_timestamp();
+                let current = self.up_get_staging_duration();
+                if staging_duration < current {
+                    // A reduction takes effect only after the current
+                    // duration passed, so users keep the announced time to
+                    // exit.
+                    let update = #cratename::upgradable::StagingDurationUpdate {
+                        staging_duration,
+                        apply_at: now.saturating_add(current),
+                    };
+                    if let Some(applied) = self.up_staging_duration_update() {
+                        // Persist a reduction which already took effect, as
+                        // the new one replaces it.
+                        if now >= applied.apply_at {
+                            self.up_write_staging_duration(applied.staging_duration);
+                        }
+                    }
+                    near_sdk::env::storage_write(
+                        (#staging_duration_update_storage_key).as_bytes(),
+                        near_sdk::borsh::BorshSerialize::try_to_vec(&update)
+                            .expect("Upgradable: Unexpected error serializing staging duration update")
+                            .as_ref(),
+                    );
+                } else {
+                    self.up_write_staging_duration(staging_duration);
+                    near_sdk::env::storage_remove((#staging_duration_update_storage_key).as_bytes());
+                }
+            }
+
+            fn up_get_staging_duration(&self) -> u64 {
+                if let Some(update) = self.up_staging_duration_update() {
+                    if near_sdk::env::block_timestamp() >= update.apply_at {
+                        return update.staging_duration;
+                    }
+                }
+                near_sdk::env::storage_read((#staging_duration_storage_key).as_bytes())
+                    .map_or(0, |value| {
+                        u64::from_le_bytes(
+                            std::convert::TryInto::try_into(value.as_slice())
+                                .expect("Upgradable: Invalid format for staging duration"),
+                        )
+                    })
+            }
+
+            fn up_get_pending_staging_duration(&self) -> Option<#cratename::upgradable::StagingDurationUpdate> {
+                self.up_staging_duration_update()
+                    .filter(|update| near_sdk::env::block_timestamp() < update.apply_at)
+            }
+
+            fn up_get_delay_status(&self) -> Option<#cratename::upgradable::DelayStatus> {
+                near_sdk::env::storage_read((#delay_status_storage_key).as_bytes()).map(|value| {
+                    near_sdk::borsh::BorshDeserialize::try_from_slice(value.as_ref())
+                        .expect("Upgradable: Invalid format for delay status")
+                })
+            }
+
+            #deploy_attr
             fn up_deploy_code(&mut self) -> near_sdk::Promise {
+                #cratename::read_only::assert_not_read_only();
+                #deploy_check
+                // Code staged without a delay status, e.g. by an earlier
+                // version of this plugin, is treated as staged right now.
+                let deploy_allowed_at = match self.up_get_delay_status() {
+                    Some(status) => status.deploy_allowed_at,
+                    None => near_sdk::env::block_timestamp().saturating_add(self.up_get_staging_duration()),
+                };
+                assert!(
+                    near_sdk::env::block_timestamp() >= deploy_allowed_at,
+                    "Upgradable: Deploy code too early: staging duration not passed"
+                );
                 near_sdk::Promise::new(near_sdk::env::current_account_id())
                     .deploy_contract(self.up_staged_code().expect("Upgradable: No staged code"))
             }
+
+            #deploy_attr
+            fn up_deploy_code_and_call(
+                &mut self,
+                function_name: String,
+                args: Vec<u8>,
+                gas: near_sdk::Gas,
+            ) -> near_sdk::Promise {
+                self.up_deploy_code().function_call(function_name, args, 0, gas)
+            }
+        }
+
+        impl #ident {
+            /// Returns the reduction of the staging duration set last, whether
+            /// or not it took effect.
+            fn up_staging_duration_update(&self) -> Option<#cratename::upgradable::StagingDurationUpdate> {
+                near_sdk::env::storage_read((#staging_duration_update_storage_key).as_bytes()).map(|value| {
+                    near_sdk::borsh::BorshDeserialize::try_from_slice(value.as_ref())
+                        .expect("Upgradable: Invalid format for staging duration update")
+                })
+            }
+
+            fn up_write_staging_duration(&mut self, staging_duration: u64) {
+                near_sdk::env::storage_write(
+                    (#staging_duration_storage_key).as_bytes(),
+                    &staging_duration.to_le_bytes(),
+                );
+            }
         }
     };
 
+    output.extend(plugin_abi("Upgradable", &ident, &output));
     output.into()
 }
@@ -0,0 +1,169 @@
+//! # Plugins ABI
+//!
+//! Machine-readable description of the methods which plugins add to a
+//! contract. The macro of each plugin describes the methods it generates by
+//! implementing [`PluginAbi`]. Deriving `PluginsAbi` adds the view
+//! `plugins_abi`, which returns the descriptions of all plugins used by the
+//! contract as JSON:
+//!
+//! ```json
+//! {
+//!   "plugins": [
+//!     {
+//!       "name": "Ownable",
+//!       "methods": [
+//!         {
+//!           "name": "owner_set",
+//!           "kind": "call",
+//!           "private": false,
+//!           "payable": false,
+//!           "args": [{ "name": "owner", "type": "Option<AccountId>", "serializer": "json" }],
+//!           "returns": null,
+//!           "result_serializer": "json"
+//!         }
+//!       ]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Methods taking `&self` are of kind `"view"` and all other methods are of
+//! kind `"call"`. Types are rendered without their paths.
+//!
+//! ```ignore
+//! #[near_bindgen]
+//! #[derive(Ownable, Pausable, PluginsAbi)]
+//! struct Counter {
+//!     counter: u64,
+//! }
+//! ```
+use std::marker::PhantomData;
+
+/// Describes the methods generated by the plugin tagged by `P`.
+pub trait PluginAbi<P> {
+    /// JSON object with the name of the plugin and its methods.
+    const ABI: &'static str;
+}
+
+/// Types tagging the plugins which implement [`PluginAbi`].
+pub mod tags {
+    pub struct Ownable;
+    pub struct Pausable;
+    pub struct Upgradable;
+    pub struct FullAccessKeyFallback;
+    pub struct AccessControllable;
+}
+
+/// Looks up whether `T` implements `PluginAbi<P>`. Calling `abi` on a
+/// reference to it resolves to [`ProbeAbi`] if so, otherwise to
+/// [`ProbeNone`].
+#[doc(hidden)]
+pub struct Probe<T, P>(PhantomData<(T, P)>);
+
+#[doc(hidden)]
+pub fn probe<T, P>() -> Probe<T, P> {
+    Probe(PhantomData)
+}
+
+#[doc(hidden)]
+pub trait ProbeAbi {
+    fn abi(&self) -> Option<&'static str>;
+}
+
+impl<T: PluginAbi<P>, P> ProbeAbi for Probe<T, P> {
+    fn abi(&self) -> Option<&'static str> {
+        Some(T::ABI)
+    }
+}
+
+#[doc(hidden)]
+pub trait ProbeNone {
+    fn abi(&self) -> Option<&'static str>;
+}
+
+impl<T, P> ProbeNone for &Probe<T, P> {
+    fn abi(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+/// Combines the descriptions of plugins into the ABI returned by
+/// `plugins_abi`.
+pub fn assemble(plugins: &[Option<&str>]) -> String {
+    let plugins: Vec<&str> = plugins.iter().flatten().copied().collect();
+    format!(r#"{{"plugins":[{}]}}"#, plugins.join(","))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use crate as near_plugins;
+    use crate::{Ownable, Pausable, PluginsAbi};
+    use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+    use near_sdk::near_bindgen;
+    use near_sdk::serde_json::{json, Value};
+
+    #[near_bindgen]
+    #[derive(Ownable, Pausable, PluginsAbi)]
+    struct Counter {
+        counter: u64,
+    }
+
+    fn plugins_abi() -> Value {
+        let counter = Counter { counter: 0 };
+        near_sdk::serde_json::from_str(&counter.plugins_abi()).unwrap()
+    }
+
+    fn method(abi: &Value, plugin: &str, method: &str) -> Value {
+        let plugin = abi["plugins"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|p| p["name"] == plugin)
+            .unwrap_or_else(|| panic!("Plugin {} is missing", plugin));
+        plugin["methods"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|m| m["name"] == method)
+            .unwrap_or_else(|| panic!("Method {} is missing", method))
+            .clone()
+    }
+
+    #[test]
+    fn test_plugins_abi() {
+        let abi = plugins_abi();
+        let names: Vec<_> = abi["plugins"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["Ownable", "Pausable"]);
+
+        assert_eq!(
+            method(&abi, "Ownable", "owner_set"),
+            json!({
+                "name": "owner_set",
+                "kind": "call",
+                "private": false,
+                "payable": false,
+                "args": [{ "name": "owner", "type": "Option<AccountId>", "serializer": "json" }],
+                "returns": null,
+                "result_serializer": "json",
+            })
+        );
+        assert_eq!(
+            method(&abi, "Pausable", "pa_is_paused"),
+            json!({
+                "name": "pa_is_paused",
+                "kind": "view",
+                "private": false,
+                "payable": false,
+                "args": [{ "name": "key", "type": "String", "serializer": "json" }],
+                "returns": "bool",
+                "result_serializer": "json",
+            })
+        );
+    }
+}
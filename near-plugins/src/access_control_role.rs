@@ -7,4 +7,11 @@ pub trait AccessControlRole {
 
     /// Returns the bitflag corresponding to the role's permission.
     fn acl_permission(self) -> u128;
+
+    /// Returns the number of distinct admins that must call `acl_grant_role`
+    /// for the same account before the role is granted. By default, the
+    /// approval of a single admin suffices.
+    fn acl_grant_threshold(self) -> u8 {
+        1
+    }
 }
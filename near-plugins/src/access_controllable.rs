@@ -1,4 +1,8 @@
-use near_sdk::AccountId;
+use crate::pagination::Page;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::Base58CryptoHash;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{AccountId, Gas, PromiseOrValue};
 
 /// # Representation of roles
 ///
@@ -15,13 +19,379 @@ use near_sdk::AccountId;
 /// define their own enum whose variants are converted to `u8`.
 ///
 /// [does not support]: https://github.com/near/near-sdk-rs/blob/9d99077c6acfde68c06845f2a1eb2b5ed7983401/near-sdk/compilation_tests/impl_generic.stderr
+///
+/// # Storage prefix
+///
+/// The keys of all collections related to access control start with the
+/// prefix returned by [`acl_storage_prefix`], which defaults to `__acl`. It
+/// can be customized to avoid collisions with other storage keys of the
+/// contract, e.g. after migrating from another plugin:
+///
+/// ```
+/// use near_plugins::{access_control, AccessControlRole, AccessControllable};
+/// use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+/// use near_sdk::near_bindgen;
+/// use near_sdk::serde::{Deserialize, Serialize};
+///
+/// #[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+/// #[serde(crate = "near_sdk::serde")]
+/// pub enum Role {
+///     Manager,
+/// }
+///
+/// #[access_control(role_type = "Role", storage_prefix = "__acl_v2")]
+/// #[near_bindgen]
+/// #[derive(Default, BorshDeserialize, BorshSerialize)]
+/// pub struct Contract {}
+///
+/// assert_eq!(Contract::acl_storage_prefix(), b"__acl_v2");
+/// ```
+///
+/// The prefix must be given as string literal:
+///
+/// ```compile_fail
+/// use near_plugins::{access_control, AccessControlRole, AccessControllable};
+/// use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+/// use near_sdk::near_bindgen;
+/// use near_sdk::serde::{Deserialize, Serialize};
+///
+/// #[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+/// #[serde(crate = "near_sdk::serde")]
+/// pub enum Role {
+///     Manager,
+/// }
+///
+/// #[access_control(role_type = "Role", storage_prefix = 42)]
+/// #[near_bindgen]
+/// #[derive(Default, BorshDeserialize, BorshSerialize)]
+/// pub struct Contract {}
+/// ```
+///
+/// # Blocking mutations while paused
+///
+/// With `#[access_control(role_type = "Role", block_when_paused)]` all
+/// mutating methods of this trait panic while the contract is paused
+/// globally, i.e. while the `Pausable` key "ALL" is paused. This couples both
+/// plugins, hence the contract must derive `Pausable` too (which in turn
+/// requires `Ownable`). View methods and the internal `*_unchecked` methods
+/// are not affected.
+///
+/// # Caching admin checks
+///
+/// Methods that check admin permissions of the same account repeatedly, e.g.
+/// while iterating over governance proposals, may call
+/// `self.__acl.enable_admin_cache()` first. For the remainder of the receipt,
+/// [`acl_is_admin`] and [`acl_is_super_admin`] then read permissions from
+/// storage at most once per account. The cache is kept in memory only, so it
+/// never outlives the receipt.
+///
+/// # Governance
+///
+/// With `#[access_control(role_type = "Role", governance = "dao.near")]` the
+/// given account is a super-admin in addition to the super-admins stored in
+/// the contract, e.g. to let a DAO hold root authority without managing a
+/// key. The contract fully trusts this account: whoever controls it, e.g. via
+/// the DAO's proposals, may execute any action requiring super-admin
+/// permissions. It cannot be revoked without redeploying the contract and it
+/// is not listed by [`acl_get_all_admins`].
+///
+/// # Storage version
+///
+/// The version of the storage layout used by `AccessControllable` is recorded
+/// in state when the first super-admin is initialized via
+/// `self.__acl.init_super_admin(account_id)`. If the recorded version differs
+/// from [`ACL_STORAGE_VERSION`], mutating methods of this trait panic with a
+/// message stating that a migration is required. This prevents new code from
+/// modifying state with an incompatible layout. Views are not checked, which
+/// saves them a storage read. After migrating state, a super-admin
+/// records the new version via [`acl_set_storage_version`]. State without a
+/// recorded version is not checked.
+///
+/// The borsh serialization of the field `__acl` injected into the contract
+/// contains only the permissions and bearers of version 1. All state added
+/// later, like remaining uses of consumable roles, assumable roles, the
+/// handle registry, scoped grants, grant approvals, role metadata, expiries
+/// of grants and the pending super-admin transfer, is stored under storage
+/// keys of its own. This state is empty for contracts deployed with version
+/// 1, hence upgrading them requires no migration.
+///
+/// # Granting roles on success
+///
+/// A method with attribute `#[grants_role_on_success(Role::User)]` grants
+/// the role to the predecessor, unless it holds the role already. A
+/// `RoleGranted` event is emitted only for new grantees. This enables
+/// self-registration, where a first successful call unlocks methods gated
+/// via `#[access_control_any]`.
+///
+/// The attribute takes a variant of the role enum, hence a misspelled role
+/// fails to compile. The role is granted after the method body ran, also if
+/// it returned early, so the body does not observe the grant yet. If the
+/// body panics, the receipt fails and the role is not granted. Like
+/// [`acl_grant_role`], the method panics while state is read-only or, with
+/// `block_when_paused`, while the contract is paused. Roles with a grant
+/// threshold can't be granted this way, calling such a method panics as
+/// well.
+///
+/// [`acl_grant_role`]: AccessControllable::acl_grant_role
+///
+/// # Requiring all roles
+///
+/// A method with attribute `#[access_control_all(roles(Role::A, Role::B))]`
+/// may be called only by accounts which have been granted every listed role,
+/// as checked by [`acl_has_all_roles`], or by super-admins.
+///
+/// [`acl_has_all_roles`]: AccessControllable::acl_has_all_roles
+///
+/// # Role groups
+///
+/// Roles which gate methods collectively can be declared once as a named
+/// group via `#[access_control(group(name = "..", roles(..)))]`, which may be
+/// repeated. Methods refer to groups with
+/// `#[access_control_any(groups(".."))]`, optionally in addition to `roles`.
+/// Groups are expanded at compile time, hence referring to an undeclared
+/// group fails to compile:
+///
+/// ```compile_fail
+/// use near_plugins::{access_control, access_control_any, AccessControlRole, AccessControllable};
+/// use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+/// use near_sdk::serde::{Deserialize, Serialize};
+/// use near_sdk::{env, near_bindgen};
+///
+/// #[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+/// #[serde(crate = "near_sdk::serde")]
+/// pub enum Role {
+///     Manager,
+///     Auditor,
+/// }
+///
+/// #[access_control(
+///     role_type = "Role",
+///     group(name = "Managers", roles(Role::Manager, Role::Auditor))
+/// )]
+/// #[near_bindgen]
+/// #[derive(Default, BorshDeserialize, BorshSerialize)]
+/// pub struct Contract {}
+///
+/// #[near_bindgen]
+/// impl Contract {
+///     #[access_control_any(groups("Managerz"))]
+///     pub fn restricted(&self) {}
+/// }
+/// ```
+///
+/// # Event level
+///
+/// With `#[access_control(role_type = "Role", event_level = "minimal")]` only
+/// events of the categories [`events::EventCategory::Role`] and
+/// [`events::EventCategory::Snapshot`] are emitted, e.g. to save receipt
+/// space. Admin changes and role assumptions are then not visible to
+/// indexers. The default `event_level = "full"` emits all events.
+///
+/// # Case-insensitive role names
+///
+/// With `#[access_control(role_type = "Role", case_insensitive_roles)]` role
+/// names passed to methods of this trait are matched ignoring ASCII case, so
+/// a client sending `"levela"` refers to `Role::LevelA`. This tolerates client
+/// mistakes at the cost of strictness: role names differing only in case
+/// can't be told apart, hence the first such variant is matched. Parsing also
+/// iterates over all roles. By default, role names are case-sensitive and a
+/// name which does not match a variant exactly makes the call panic.
+///
+/// # Bootstrapping super-admins
+///
+/// With `#[access_control(role_type = "Role", bootstrap)]` the contract method
+/// `acl_init_super_admin(account_id)` is generated. It calls
+/// `self.__acl.init_super_admin(account_id)`, which makes `account_id` a
+/// super-admin provided that there are no super-admins yet, and returns
+/// whether it did. Only the contract itself may call it, e.g. in the
+/// transaction deploying the contract, which avoids adding a custom method to
+/// establish the first super-admin.
+///
+/// # Super-admin-only roles
+///
+/// With `#[access_control(role_type = "Role", super_admin_only_roles(Role::A))]`
+/// only super-admins may manage the listed roles, e.g. for extra-sensitive
+/// capabilities. Admins of these roles are not considered admins by
+/// [`acl_is_admin`], hence they can neither grant nor revoke the roles nor
+/// manage their admins. Other roles are not affected.
+///
+/// [`acl_is_admin`]: AccessControllable::acl_is_admin
+///
+/// # Typed roles
+///
+/// Methods of this trait take role names as `String` to keep the contract's
+/// ABI independent of the role type. Within the contract, the typed
+/// counterparts `acl_grant_role_typed`, `acl_revoke_role_typed` and
+/// `acl_has_role_typed` take the role type instead. They are generated by
+/// `#[access_control]` as inherent methods which are not exposed as contract
+/// methods, and they let the compiler check role names:
+///
+/// ```
+/// use near_plugins::{access_control, AccessControlRole, AccessControllable};
+/// use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+/// use near_sdk::serde::{Deserialize, Serialize};
+/// use near_sdk::{near_bindgen, AccountId};
+///
+/// #[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+/// #[serde(crate = "near_sdk::serde")]
+/// pub enum Role {
+///     Manager,
+/// }
+///
+/// #[access_control(role_type = "Role")]
+/// #[near_bindgen]
+/// #[derive(Default, BorshDeserialize, BorshSerialize)]
+/// pub struct Contract {}
+///
+/// fn is_manager(contract: &Contract, account_id: AccountId) -> bool {
+///     contract.acl_has_role_typed(Role::Manager, account_id)
+/// }
+/// ```
+///
+/// A typo in a role name then fails to compile:
+///
+/// ```compile_fail
+/// use near_plugins::{access_control, AccessControlRole, AccessControllable};
+/// use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+/// use near_sdk::serde::{Deserialize, Serialize};
+/// use near_sdk::{near_bindgen, AccountId};
+///
+/// #[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+/// #[serde(crate = "near_sdk::serde")]
+/// pub enum Role {
+///     Manager,
+/// }
+///
+/// #[access_control(role_type = "Role")]
+/// #[near_bindgen]
+/// #[derive(Default, BorshDeserialize, BorshSerialize)]
+/// pub struct Contract {}
+///
+/// fn is_manager(contract: &Contract, account_id: AccountId) -> bool {
+///     contract.acl_has_role_typed(Role::LevelZ, account_id)
+/// }
+/// ```
 pub trait AccessControllable {
     /// Returns the storage prefix for collections related to access control.
     fn acl_storage_prefix() -> &'static [u8];
 
+    /// Returns the version of the storage layout recorded in state, if any. See
+    /// [`ACL_STORAGE_VERSION`].
+    fn acl_get_storage_version(&self) -> Option<u32>;
+
+    /// Records `version` as version of the storage layout, provided that the
+    /// predecessor is a super-admin. Migrations call it after migrating state
+    /// to the layout expected by the deployed code.
+    ///
+    /// Returns whether the predecessor has sufficient permissions. Without
+    /// permissions, internal state is not modified.
+    fn acl_set_storage_version(&mut self, version: u32) -> bool;
+
+    /// Initializes super-admins, admins and grantees from `manifest` in a
+    /// single call, e.g. for reproducible deployments. Only the contract
+    /// itself may call this method. It emits the same events as adding each
+    /// entry individually.
+    ///
+    /// The manifest is validated before it is applied: it must pass
+    /// [`AclManifest::validate`] and refer only to roles of the contract,
+    /// otherwise the call panics. Like `self.__acl.init_super_admin`, it
+    /// returns `false` without modifying internal state if super-admins exist
+    /// already, hence a manifest can be applied only once.
+    fn acl_init_from_manifest(&mut self, manifest: AclManifest) -> bool;
+
+    /// Puts the contract in read-only mode or takes it out of it, provided
+    /// that the predecessor is a super-admin. While in read-only mode,
+    /// mutating methods of this trait, `Pausable`, `Upgradable`, `Ownable`
+    /// and `FullAccessKeyFallback` panic. This method remains callable. See
+    /// [`crate::read_only`].
+    ///
+    /// Returns whether the predecessor has sufficient permissions. Without
+    /// permissions, internal state is not modified.
+    fn acl_set_read_only(&mut self, read_only: bool) -> bool;
+
+    /// Returns whether the contract is in read-only mode.
+    fn acl_is_read_only(&self) -> bool;
+
     /// Returns whether `account_id` is a super-admin.
     fn acl_is_super_admin(&self, account_id: AccountId) -> bool;
 
+    /// Like [`acl_is_super_admin`] for the predecessor, e.g. for contracts
+    /// checking their own permissions via a cross contract call.
+    ///
+    /// View calls have no predecessor, hence it is a call method taking
+    /// `&mut self`, though it does not modify state. Frontends which avoid
+    /// transactions can call [`acl_is_super_admin`] with the user's account id
+    /// instead.
+    fn acl_am_i_super_admin(&mut self) -> bool;
+
+    /// Revokes super-admin permissions from `account_id` provided that the
+    /// predecessor is a super-admin. The optional `reason` is included in the
+    /// emitted `SuperAdminRevoked` event to provide context for audits.
+    ///
+    /// In case of sufficient permissions, the returned `Some(bool)` indicates
+    /// whether `account_id` was a super-admin. Without permissions, `None` is
+    /// returned and internal state is not modified.
+    ///
+    /// To prevent locking everyone out, it returns `None` without modifying
+    /// internal state if `account_id` is the last super-admin, see
+    /// [`acl_num_super_admins`].
+    fn acl_revoke_super_admin(
+        &mut self,
+        account_id: AccountId,
+        reason: Option<String>,
+    ) -> Option<bool>;
+
+    /// Revokes super-admin permissions from the predecessor. Returns whether
+    /// the predecessor was a super-admin, in which case a `SuperAdminRevoked`
+    /// event is emitted.
+    ///
+    /// Like [`acl_revoke_super_admin`], it returns `false` without modifying
+    /// internal state if the predecessor is the last super-admin.
+    fn acl_renounce_super_admin(&mut self) -> bool;
+
+    /// Returns the number of super-admins. The account configured via
+    /// `governance` is not counted, since its permissions are not stored.
+    fn acl_num_super_admins(&self) -> u64;
+
+    /// Proposes to transfer the predecessor's super-admin permissions to
+    /// `account_id`, provided that the predecessor is a super-admin. The
+    /// transfer takes effect only once `account_id` calls
+    /// [`acl_accept_super_admin_transfer`], which protects against handing
+    /// over permissions to a mistyped account. Until then, the proposer
+    /// retains its permissions.
+    ///
+    /// There is at most one pending transfer. A new proposal overwrites it,
+    /// emitting a `SuperAdminTransferCancelled` event for the overwritten
+    /// transfer. Hence the chain of super-admin transfers can be
+    /// reconstructed from events.
+    ///
+    /// In case of sufficient permissions, `Some(true)` is returned and a
+    /// `SuperAdminTransferProposed` event is emitted. Without permissions,
+    /// `None` is returned and internal state is not modified.
+    fn acl_propose_super_admin_transfer(&mut self, account_id: AccountId) -> Option<bool>;
+
+    /// Accepts the pending super-admin transfer, provided that the
+    /// predecessor is its target. The predecessor is made super-admin and
+    /// super-admin permissions are revoked from the proposer.
+    ///
+    /// Returns whether the transfer was applied, in which case a
+    /// `SuperAdminTransferAccepted` event is emitted. It returns `false`
+    /// without modifying state if the predecessor is not the target or if the
+    /// proposer is no longer a super-admin.
+    fn acl_accept_super_admin_transfer(&mut self) -> bool;
+
+    /// Clears the pending super-admin transfer, provided that the predecessor
+    /// is a super-admin.
+    ///
+    /// In case of sufficient permissions, the returned `Some(bool)` indicates
+    /// whether a transfer was pending, in which case a
+    /// `SuperAdminTransferCancelled` event is emitted. Without permissions,
+    /// `None` is returned and internal state is not modified.
+    fn acl_cancel_super_admin_transfer(&mut self) -> Option<bool>;
+
+    /// Returns the pending super-admin transfer, if any.
+    fn acl_get_pending_super_admin_transfer(&self) -> Option<SuperAdminTransfer>;
+
     /// Makes `account_id` an admin provided that the predecessor has sufficient
     /// permissions, i.e. is an admin as defined by [`acl_is_admin`].
     ///
@@ -32,10 +402,25 @@ pub trait AccessControllable {
     /// Note that any role may have multiple (or zero) admins.
     fn acl_add_admin(&mut self, role: String, account_id: AccountId) -> Option<bool>;
 
+    /// Seeds the administration of a role which has no admins yet, e.g. a
+    /// role introduced by an upgrade, by making `first_admin` its initial
+    /// admin. The predecessor must be a super-admin.
+    ///
+    /// In case of sufficient permissions, the returned `Some(bool)` indicates
+    /// whether `first_admin` was made admin, emitting an `AdminAdded` event.
+    /// If `role` already has admins, `Some(false)` is returned and internal
+    /// state is not modified. Without permissions, `None` is returned.
+    fn acl_bootstrap_role(&mut self, role: String, first_admin: AccountId) -> Option<bool>;
+
     /// Returns whether `account_id` is an admin for `role`. Super-admins are
     /// admins for _every_ role.
     fn acl_is_admin(&self, role: String, account_id: AccountId) -> bool;
 
+    /// Returns whether `account_id` is an admin for any of the `roles`, as
+    /// defined by [`acl_is_admin`]. If `roles` is empty, it returns `false`,
+    /// even for super-admins.
+    fn acl_is_admin_of_any(&self, roles: Vec<String>, account_id: AccountId) -> bool;
+
     /// Revokes admin permissions for `role` from `account_id` provided that the
     /// predecessor has sufficient permissions, i.e. is an admin as defined by
     /// [`acl_is_admin`].
@@ -47,7 +432,36 @@ pub trait AccessControllable {
 
     /// Revokes admin permissions for `role` from the predecessor. Returns
     /// whether the predecessor was an admin for `role`.
-    fn acl_renounce_admin(&mut self, role: String) -> bool;
+    ///
+    /// To prevent leaving `role` without admins to manage its grantees, it
+    /// returns `false` without modifying internal state if the predecessor is
+    /// the last admin for `role` and `role` has grantees, unless `force` is
+    /// `Some(true)`. Super-admins are not subject to this check. Grantees are
+    /// counted like [`acl_num_grantees`] counts them.
+    ///
+    /// If the predecessor was an admin for `role`, an `AdminRevoked` event is
+    /// emitted with `account` and `by` set to the predecessor.
+    fn acl_renounce_admin(&mut self, role: String, force: Option<bool>) -> bool;
+
+    /// Like [`acl_revoke_admin`], but revokes admin permissions for `role`
+    /// from each of `account_ids`. Permissions are checked once up front.
+    ///
+    /// In case of sufficient permissions, the returned `Some(Vec<bool>)`
+    /// indicates for each account whether it was an admin for `role`. An
+    /// `AdminRevoked` event is emitted for each of them, including the
+    /// optional `correlation_id`. Without permissions, `None` is returned and
+    /// internal state is not modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `account_ids` contains more than [`ACL_REVOKE_BATCH_MAX_LEN`]
+    /// accounts.
+    fn acl_revoke_admin_batch(
+        &mut self,
+        role: String,
+        account_ids: Vec<AccountId>,
+        correlation_id: Option<String>,
+    ) -> Option<Vec<bool>>;
 
     /// Grants `role` to `account_id` provided that the predecessor has
     /// sufficient permissions, i.e. is an admin as defined by [`acl_is_admin`].
@@ -55,11 +469,306 @@ pub trait AccessControllable {
     /// In case of sufficient permissions, the returned `Some(bool)` indicates
     /// whether `account_id` is a new grantee of `role`. Without permissions,
     /// `None` is returned and internal state is not modified.
+    ///
+    /// If `role` has a grant threshold `n > 1`, declared via
+    /// `#[grant_threshold(n)]` on its enum variant, the call records the
+    /// predecessor's approval and `role` is granted only once `n` distinct
+    /// admins approved. Until then, `Some(false)` is returned and no
+    /// `RoleGranted` event is emitted. Approvals of admins whose permissions
+    /// were revoked meanwhile no longer count. Revoking `role` from
+    /// `account_id` resets pending approvals. Other methods granting roles
+    /// panic for roles with a grant threshold, so approvals can't be
+    /// bypassed.
     fn acl_grant_role(&mut self, role: String, account_id: AccountId) -> Option<bool>;
 
+    /// Like [`acl_grant_role`], additionally returning the grantees of `role`
+    /// after the grant, like [`acl_get_grantees`] does. It saves frontends
+    /// displaying a role's membership a separate call, which might observe
+    /// later changes.
+    ///
+    /// Returns `None` in case of insufficient permissions, in which case
+    /// internal state is not modified.
+    fn acl_grant_role_and_list(
+        &mut self,
+        role: String,
+        account_id: AccountId,
+        skip: u64,
+        limit: u64,
+    ) -> Option<Vec<AccountId>>;
+
+    /// Like [`acl_grant_role`], but returns [`AclResult::UnknownRole`]
+    /// instead of panicking if `role` does not correspond to a role, enabling
+    /// frontends to tell this case apart from insufficient permissions.
+    fn acl_grant_role_checked(&mut self, role: String, account_id: AccountId) -> AclResult;
+
+    /// Like [`acl_grant_role`], but grants `role` to each of `account_ids`.
+    /// Permissions are checked once up front.
+    ///
+    /// The returned vector contains the result of [`acl_grant_role`] for each
+    /// account, in input order. Without permissions, all of its entries are
+    /// `None` and internal state is not modified. If an account occurs
+    /// multiple times, it is processed only once and its later occurrences
+    /// yield `Some(false)`. A `RoleGranted` event is emitted for each new
+    /// grantee, including the optional `correlation_id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `account_ids` contains more than [`ACL_GRANT_ROLES_MAX_LEN`]
+    /// accounts or if `role` has a grant threshold greater than one.
+    fn acl_grant_roles(
+        &mut self,
+        role: String,
+        account_ids: Vec<AccountId>,
+        correlation_id: Option<String>,
+    ) -> Vec<Option<bool>>;
+
+    /// Grants `role` to the accounts in `add` and revokes it from the
+    /// accounts in `remove`, provided that the predecessor is an admin for
+    /// `role`. It maps a governance decision like "remove A, add B" to a
+    /// single transaction, which either applies all changes or none.
+    ///
+    /// In case of sufficient permissions, the returned
+    /// [`MembershipChangeResult`] lists the accounts whose grants were
+    /// actually changed. A `RoleGranted` or `RoleRevoked` event is emitted
+    /// for each of them, including the optional `correlation_id`. Without
+    /// permissions, `None` is returned and internal state is not modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an account is contained in both `add` and `remove`, if
+    /// together they contain more than [`ACL_GRANT_ROLES_MAX_LEN`] accounts
+    /// or if `role` has a grant threshold greater than one, since approvals
+    /// cannot be collected for a combined change.
+    fn acl_apply_membership_change(
+        &mut self,
+        role: String,
+        add: Vec<AccountId>,
+        remove: Vec<AccountId>,
+        correlation_id: Option<String>,
+    ) -> Option<MembershipChangeResult>;
+
+    /// Returns the admins which approved granting `role` to `account_id` via
+    /// [`acl_grant_role`], while the grant is pending due to the role's grant
+    /// threshold. Admins whose permissions were revoked are omitted.
+    fn acl_get_grant_approvals(&self, role: String, account_id: AccountId) -> Vec<AccountId>;
+
+    /// Sets the registry contract used by [`acl_grant_role_by_handle`] to
+    /// resolve handles, provided that the predecessor is a super-admin. Use
+    /// `None` to remove the registry.
+    ///
+    /// Returns whether the predecessor has sufficient permissions. Without
+    /// permissions, internal state is not modified.
+    fn acl_set_handle_registry(&mut self, registry: Option<AccountId>) -> bool;
+
+    /// Returns the registry contract used to resolve handles, if any.
+    fn acl_get_handle_registry(&self) -> Option<AccountId>;
+
+    /// Grants `role` to the account that `handle` resolves to, provided that
+    /// the predecessor is an admin for `role`.
+    ///
+    /// The handle is resolved by calling the method
+    /// [`ACL_HANDLE_REGISTRY_RESOLVE_METHOD`] of the registry set via
+    /// [`acl_set_handle_registry`]. It receives `{"handle": String}` and is
+    /// expected to return `Option<AccountId>`. The role is granted in the
+    /// callback [`acl_on_handle_resolved`].
+    ///
+    /// Resolving the handle is asynchronous, hence:
+    ///
+    /// - Admin permissions of the predecessor are checked again in the
+    ///   callback, as they might have been revoked in the meantime.
+    /// - The handle might resolve to a different account than at the time
+    ///   the transaction was sent. The registry must be trusted.
+    ///
+    /// Without permissions, `None` is returned immediately and no cross
+    /// contract call is made. Otherwise the result of the callback is
+    /// returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no registry is set. The callback panics if the handle could
+    /// not be resolved.
+    fn acl_grant_role_by_handle(
+        &mut self,
+        role: String,
+        handle: String,
+    ) -> PromiseOrValue<Option<bool>>;
+
+    /// Callback of [`acl_grant_role_by_handle`] which grants `role` to the
+    /// account `handle` resolved to, provided that `caller` is still an admin
+    /// for `role`. Only the contract itself may call this method.
+    ///
+    /// In case of sufficient permissions, the returned `Some(bool)` indicates
+    /// whether the resolved account is a new grantee of `role`. Without
+    /// permissions, `None` is returned and internal state is not modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `role` has a grant threshold greater than one.
+    fn acl_on_handle_resolved(
+        &mut self,
+        role: String,
+        handle: String,
+        caller: AccountId,
+    ) -> Option<bool>;
+
+    /// Grants `role` to `account_id` for a limited number of `uses`, provided
+    /// that the predecessor has sufficient permissions, i.e. is an admin as
+    /// defined by [`acl_is_admin`]. If `account_id` already is a grantee of
+    /// `role`, its remaining uses are set to `uses`.
+    ///
+    /// Each successful call of a `&mut self` method restricted by
+    /// `#[access_control_any]` consumes one use of the first matching
+    /// consumable role, unless `account_id` holds another matching role
+    /// without a limit on uses. The role is revoked once its last use is
+    /// consumed. Methods taking `&self` cannot modify state and hence do not
+    /// consume uses.
+    ///
+    /// In case of sufficient permissions, the returned `Some(bool)` indicates
+    /// whether `account_id` is a new grantee of `role`. Without permissions,
+    /// `None` is returned and internal state is not modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `uses` is zero or if `role` has a grant threshold greater
+    /// than one.
+    fn acl_grant_consumable_role(
+        &mut self,
+        role: String,
+        account_id: AccountId,
+        uses: u32,
+    ) -> Option<bool>;
+
+    /// Returns the remaining uses of `role` granted to `account_id` via
+    /// [`acl_grant_consumable_role`]. Returns `None` if `account_id` is not a
+    /// grantee of `role` or if the grant is not limited in uses.
+    fn acl_get_remaining_uses(&self, role: String, account_id: AccountId) -> Option<u32>;
+
+    /// Grants `role` to `account_id` until the block timestamp passes
+    /// `expires_at_ns`, provided that the predecessor has sufficient
+    /// permissions, i.e. is an admin as defined by [`acl_is_admin`]. If
+    /// `account_id` already is a grantee of `role`, its expiry is set to
+    /// `expires_at_ns`, which turns a permanent grant into an expiring one.
+    /// The expiry is included in the emitted `RoleGranted` event, which is
+    /// emitted for existing grantees as well.
+    ///
+    /// Once expired, the grant is treated as absent by [`acl_has_role`],
+    /// [`acl_has_any_role`] and hence by `#[access_control_any]`. It is also
+    /// skipped by every method listing grantees, e.g. [`acl_get_grantees`].
+    /// Expired grants remain in storage until the role is revoked or granted
+    /// again. Until then they are counted by methods which read the number of
+    /// grantees without loading them, e.g. [`acl_num_grantees`].
+    ///
+    /// In case of sufficient permissions, the returned `Some(bool)` indicates
+    /// whether `account_id` is a new grantee of `role`. Without permissions,
+    /// `None` is returned and internal state is not modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `expires_at_ns` is not after the current block timestamp or
+    /// if `role` has a grant threshold greater than one.
+    fn acl_grant_role_with_expiry(
+        &mut self,
+        role: String,
+        account_id: AccountId,
+        expires_at_ns: u64,
+    ) -> Option<bool>;
+
+    /// Returns the expiry of `role` granted to `account_id` via
+    /// [`acl_grant_role_with_expiry`], as timestamp in nanoseconds. Returns
+    /// `None` if `account_id` is not a grantee of `role` or if the grant does
+    /// not expire.
+    fn acl_get_role_expiry(&self, role: String, account_id: AccountId) -> Option<u64>;
+
+    /// Allows `account_id` to assume `role`, provided that the predecessor is
+    /// an admin for `role`.
+    ///
+    /// Contract code activates an allowed role for the predecessor via the
+    /// method `acl_assume_role(role)`, which `#[access_control]` generates on
+    /// the contract itself. An assumed role is kept in memory only and is
+    /// active until the end of the current execution, e.g. for a method
+    /// restricted by `#[access_control_any]` which is called afterwards. It is
+    /// not persisted, hence `acl_assume_role` is not exposed as a contract
+    /// method.
+    ///
+    /// In case of sufficient permissions, the returned `Some(bool)` indicates
+    /// whether `account_id` was newly allowed to assume `role`. Without
+    /// permissions, `None` is returned and internal state is not modified.
+    fn acl_allow_assume_role(&mut self, role: String, account_id: AccountId) -> Option<bool>;
+
+    /// Disallows `account_id` to assume `role`, provided that the predecessor
+    /// is an admin for `role`. Assuming `role` in the current receipt is not
+    /// undone.
+    ///
+    /// In case of sufficient permissions, the returned `Some(bool)` indicates
+    /// whether `account_id` was allowed to assume `role`. Without permissions,
+    /// `None` is returned and internal state is not modified.
+    fn acl_disallow_assume_role(&mut self, role: String, account_id: AccountId) -> Option<bool>;
+
+    /// Grants `role` to `account_id` within `scope`, provided that the
+    /// predecessor is an admin for `role`. Scopes identify resources, e.g.
+    /// tenants of a multi-tenant contract.
+    ///
+    /// Grants in different scopes are independent of each other. Methods
+    /// without a scope, like [`acl_grant_role`], operate on the global scope
+    /// which is independent of all other scopes as well. Admins are global,
+    /// i.e. an admin for `role` may grant it in any scope.
+    ///
+    /// In case of sufficient permissions, the returned `Some(bool)` indicates
+    /// whether `account_id` is a new grantee of `role` in `scope`. Without
+    /// permissions, `None` is returned and internal state is not modified.
+    fn acl_grant_role_scoped(
+        &mut self,
+        role: String,
+        scope: String,
+        account_id: AccountId,
+    ) -> Option<bool>;
+
+    /// Revokes `role` within `scope` from `account_id`, provided that the
+    /// predecessor is an admin for `role`.
+    ///
+    /// In case of sufficient permissions, the returned `Some(bool)` indicates
+    /// whether `account_id` was a grantee of `role` in `scope`. Without
+    /// permissions, `None` is returned and internal state is not modified.
+    fn acl_revoke_role_scoped(
+        &mut self,
+        role: String,
+        scope: String,
+        account_id: AccountId,
+    ) -> Option<bool>;
+
+    /// Returns whether `account_id` has been granted `role` within `scope`.
+    fn acl_has_role_scoped(&self, role: String, scope: String, account_id: AccountId) -> bool;
+
+    /// Returns an estimate of the storage in bytes that granting `role` to
+    /// `account_id` would add, enabling frontends to show the storage staking
+    /// cost before a grant. Returns 0 if `account_id` is a grantee of `role`
+    /// already.
+    ///
+    /// The estimate is computed from the sizes of the collection entries
+    /// which a grant inserts. It doesn't account for data stored by the
+    /// contract in addition, e.g. in methods wrapping grants.
+    fn acl_estimate_grant_storage(&self, role: String, account_id: AccountId) -> u64;
+
     /// Returns whether `account_id` has been granted `role`.
     fn acl_has_role(&self, role: String, account_id: AccountId) -> bool;
 
+    /// Returns the roles granted to `account_id`, ordered by their `u8`
+    /// representation. Admin permissions are not included.
+    ///
+    /// It reads the permissions stored for `account_id` instead of checking
+    /// the grantees of every role. Besides that, it only reads the expiries of
+    /// granted roles. Expired grants are not included.
+    fn acl_get_roles(&self, account_id: AccountId) -> Vec<String>;
+
+    /// Like [`acl_get_roles`] for the predecessor, e.g. for contracts checking
+    /// their own roles via a cross contract call.
+    ///
+    /// View calls have no predecessor, hence it is a call method taking
+    /// `&mut self`, though it does not modify state. Frontends which avoid
+    /// transactions can call [`acl_get_roles`] with the user's account id
+    /// instead.
+    fn acl_my_roles(&mut self) -> Vec<String>;
+
     /// Revokes `role` from `account_id` provided that the predecessor has
     /// sufficient permissions, i.e. is an admin as defined by [`acl_is_admin`].
     ///
@@ -68,20 +777,497 @@ pub trait AccessControllable {
     /// `None` is returned and internal state is not modified.
     fn acl_revoke_role(&mut self, role: String, account_id: AccountId) -> Option<bool>;
 
+    /// Like [`acl_revoke_role`], but returns [`AclResult::UnknownRole`]
+    /// instead of panicking if `role` does not correspond to a role.
+    fn acl_revoke_role_checked(&mut self, role: String, account_id: AccountId) -> AclResult;
+
+    /// Like [`acl_revoke_role`], but revokes `role` from each of
+    /// `account_ids`. Permissions are checked once up front.
+    ///
+    /// In case of sufficient permissions, the returned `Some(Vec<bool>)`
+    /// indicates for each account whether it was a grantee of `role`. A
+    /// `RoleRevoked` event is emitted for each of them, including the
+    /// optional `correlation_id`. Without permissions, `None` is returned and
+    /// internal state is not modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `account_ids` contains more than [`ACL_REVOKE_BATCH_MAX_LEN`]
+    /// accounts.
+    fn acl_revoke_role_batch(
+        &mut self,
+        role: String,
+        account_ids: Vec<AccountId>,
+        correlation_id: Option<String>,
+    ) -> Option<Vec<bool>>;
+
+    /// Alias of [`acl_revoke_role_batch`] which returns its result in the
+    /// shape of the one returned by [`acl_grant_roles`], the counterpart of
+    /// this method.
+    ///
+    /// The returned vector indicates for each account, in input order,
+    /// whether it was a grantee of `role`. Without permissions, all of its
+    /// entries are `None` and internal state is not modified.
+    fn acl_revoke_roles(
+        &mut self,
+        role: String,
+        account_ids: Vec<AccountId>,
+        correlation_id: Option<String>,
+    ) -> Vec<Option<bool>>;
+
+    /// Like [`acl_revoke_role`], additionally notifying `account_id` if the
+    /// role was revoked. The notification is a call of the method
+    /// [`ACL_ON_ROLE_REVOKED_METHOD`] on `account_id` with arguments
+    /// `{"role": String}`, enabling contracts to react to revocations.
+    ///
+    /// Notifications are best-effort: the call is not awaited and its failure,
+    /// e.g. if `account_id` is not a contract, is ignored. It is bounded by
+    /// [`ACL_ON_ROLE_REVOKED_GAS`], which the predecessor must attach in
+    /// addition to the gas required for revoking.
+    fn acl_revoke_role_notify(&mut self, role: String, account_id: AccountId) -> Option<bool>;
+
+    /// Makes sure `account_id` is not a grantee of `role`, provided that the
+    /// predecessor has sufficient permissions, i.e. is an admin as defined by
+    /// [`acl_is_admin`]. It is a no-op if `account_id` is not a grantee,
+    /// which simplifies idempotent scripts.
+    ///
+    /// In case of sufficient permissions, `Some(was_grantee)` is returned,
+    /// like [`acl_revoke_role`] does. A script can hence treat any `Some` as
+    /// success. Without permissions, `None` is returned and internal state is
+    /// not modified. `Option<()>` is not used since `Some(())` and `None` are
+    /// both serialized to JSON `null`.
+    fn acl_ensure_role_absent(&mut self, role: String, account_id: AccountId) -> Option<bool>;
+
+    /// Revokes `role` from up to `limit` of its grantees provided that the
+    /// predecessor has sufficient permissions, i.e. is an admin as defined by
+    /// [`acl_is_admin`]. Limiting the number of revocations per call bounds
+    /// gas usage, so revoking from many grantees may require multiple calls.
+    ///
+    /// In case of sufficient permissions, the returned `Some` reports how many
+    /// grantees were removed and how many remain. The caller is done once
+    /// `remaining` is zero. Calling it for a role without grantees returns
+    /// zero for both and has no effect. Without permissions, `None` is
+    /// returned and internal state is not modified.
+    ///
+    /// Expired grants are revoked as well, which removes them from storage.
+    /// They count towards `removed` and `remaining`.
+    ///
+    /// To keep gas bounded, a call emits a single `RoleCleared` event with
+    /// `count` set to `removed` instead of a `RoleRevoked` event per grantee.
+    /// No event is emitted if no grantee was removed. The optional
+    /// `correlation_id` is included in the event, enabling indexers to group
+    /// the events of multiple calls.
+    fn acl_revoke_role_from_all(
+        &mut self,
+        role: String,
+        limit: u64,
+        correlation_id: Option<String>,
+    ) -> Option<RevokeRoleFromAllProgress>;
+
     /// Revokes `role` from the predecessor and returns whether it was a grantee
     /// of `role`.
+    ///
+    /// Renouncements are observable like revocations: if the predecessor was
+    /// a grantee, a `RoleRevoked` event is emitted with `from` and `by` set to
+    /// the predecessor.
     fn acl_renounce_role(&mut self, role: String) -> bool;
 
     /// Returns whether `account_id` has been granted any of the `roles`.
     fn acl_has_any_role(&self, roles: Vec<String>, account_id: AccountId) -> bool;
 
+    /// Returns whether `account_id` has been granted all of the `roles`. If
+    /// `roles` is empty, it returns `true`.
+    fn acl_has_all_roles(&self, roles: Vec<String>, account_id: AccountId) -> bool;
+
+    /// Returns the first role in `roles` that `account_id` has been granted,
+    /// e.g. to show which role grants access to a method restricted by
+    /// `#[access_control_any]`. Returns `None` if `account_id` has none of the
+    /// `roles`.
+    ///
+    /// Super-admin permissions are not taken into account, since they do not
+    /// satisfy `#[access_control_any]` either.
+    fn acl_which_role(&self, roles: Vec<String>, account_id: AccountId) -> Option<String>;
+
+    /// Enables paginated retrieval of super-admins. It returns up to `limit`
+    /// super-admins and skips the first `skip` super-admins.
+    ///
+    /// The account configured via `governance` is not listed, since its
+    /// permissions are not stored.
+    fn acl_get_super_admins(&self, skip: u64, limit: u64) -> Vec<AccountId>;
+
     /// Enables paginated retrieval of admins of `role`. It returns upt to
-    /// `limit` admins and skips the first `skip` admins.
+    /// `limit` admins and skips the first `skip` admins. `limit` is clamped
+    /// to [`ACL_MAX_LIMIT`].
     fn acl_get_admins(&self, role: String, skip: u64, limit: u64) -> Vec<AccountId>;
 
     /// Enables paginated retrieval of grantees of `role`. It returns up to
-    /// `limit` grantees and skips the first `skip` grantees.
+    /// `limit` grantees and skips the first `skip` grantees. `limit` is
+    /// clamped to [`ACL_MAX_LIMIT`].
     fn acl_get_grantees(&self, role: String, skip: u64, limit: u64) -> Vec<AccountId>;
+
+    /// Returns the number of admins of `role`, without counting super-admins.
+    /// Unlike paging through [`acl_get_admins`], it reads a single storage
+    /// record.
+    fn acl_num_admins(&self, role: String) -> u64;
+
+    /// Returns the number of grantees of `role`. Like [`acl_num_admins`], it
+    /// reads a single storage record. Hence, unlike [`acl_get_grantees`], it
+    /// counts expired grants which were not yet removed from storage, see
+    /// [`acl_grant_role_with_expiry`].
+    fn acl_num_grantees(&self, role: String) -> u64;
+
+    /// Returns the names of all roles defined by the contract's role type,
+    /// in the order of their declaration. It enables clients to discover
+    /// the roles of a deployment without hardcoding them.
+    fn acl_get_all_roles(&self) -> Vec<String>;
+
+    /// Returns whether `role` is the name of a role defined by the contract's
+    /// role type, taking `case_insensitive_roles` into account. Other methods
+    /// panic when receiving an unknown role, hence frontends may use this
+    /// method to validate input up front.
+    fn acl_role_exists(&self, role: String) -> bool;
+
+    /// Returns every role with its current number of grantees, e.g. for
+    /// summaries which would otherwise require a call per role.
+    ///
+    /// Grantees are counted like [`acl_num_grantees`] counts them. Since the
+    /// number of roles is bounded by the width of the bitflags representing
+    /// them, the result is not paginated.
+    fn acl_get_all_grantee_counts(&self) -> Vec<(String, u64)>;
+
+    /// Summarizes governance risks of the current state, e.g. for operators
+    /// monitoring the contract. The returned [`AclHealth`] contains a
+    /// [`HealthWarning`] for each of the following conditions:
+    ///
+    /// - There is no super-admin, or only one.
+    /// - A role has grantees but no admins, hence its grants can be changed
+    ///   only by super-admins.
+    ///
+    /// Grantees are counted like [`acl_num_grantees`] counts them, hence
+    /// expired grants which were not yet removed from storage are counted.
+    fn acl_health(&self) -> AclHealth;
+
+    /// Cursor based sibling of [`acl_get_admins`]. It returns up to `limit`
+    /// admins of `role`, starting at `cursor`. Pass `None` to retrieve the
+    /// first page and the returned `next` cursor to retrieve the following
+    /// one. `next` is `None` on the last page. Every page reports the `total`
+    /// number of admins, regardless of `cursor` and `limit`.
+    fn acl_get_admins_page(
+        &self,
+        role: String,
+        cursor: Option<String>,
+        limit: u64,
+    ) -> Page<AccountId>;
+
+    /// Cursor based sibling of [`acl_get_grantees`], returning grantees of
+    /// `role` like [`acl_get_admins_page`] returns admins. Every page covers
+    /// up to `limit` stored grants and skips expired ones, hence it may
+    /// contain less than `limit` items before the last page. `total` counts
+    /// grantees like [`acl_num_grantees`].
+    fn acl_get_grantees_page(
+        &self,
+        role: String,
+        cursor: Option<String>,
+        limit: u64,
+    ) -> Page<AccountId>;
+
+    /// Enables paginated retrieval of every account with admin permissions.
+    /// It returns up to `limit` `(role, admin)` pairs and skips the first
+    /// `skip` pairs.
+    ///
+    /// Super-admins are listed first, with role
+    /// [`ACL_SUPER_ADMIN_ROLE_MARKER`]. They are followed by the admins of
+    /// each role, ordered by the role's `u8` representation.
+    fn acl_get_all_admins(&self, skip: u64, limit: u64) -> Vec<(String, AccountId)>;
+
+    /// Transfers `role` from `old` to `new` for every `(old, new)` pair in
+    /// `mappings` provided that the predecessor has sufficient permissions,
+    /// i.e. is an admin as defined by [`acl_is_admin`]. This facilitates
+    /// migrating many accounts at once, e.g. during a key rotation.
+    ///
+    /// In case of sufficient permissions, the returned `Some(Vec<bool>)`
+    /// indicates for each pair whether `role` was transferred. Pairs are
+    /// skipped if `old` is not a grantee of `role` or `new` already is a
+    /// grantee, hence every `true` corresponds to exactly one new grantee.
+    /// Roles assumed via `acl_assume_role` can't be transferred. Without
+    /// permissions, `None` is returned and internal state is not modified.
+    ///
+    /// The expiry of a grant made via [`acl_grant_role_with_expiry`] and the
+    /// remaining uses of a grant made via [`acl_grant_consumable_role`] are
+    /// transferred along with the role.
+    ///
+    /// A transfer emits a `RoleRevoked` event for `old` followed by a
+    /// `RoleGranted` event for `new`. Like other batch methods, it accepts an
+    /// optional `correlation_id` which is included in all these events,
+    /// enabling indexers to group them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mappings` contains more than
+    /// [`ACL_TRANSFER_ROLE_BULK_MAX_LEN`] pairs or if `role` has a grant
+    /// threshold greater than one.
+    fn acl_transfer_role_bulk(
+        &mut self,
+        role: String,
+        mappings: Vec<(AccountId, AccountId)>,
+        correlation_id: Option<String>,
+    ) -> Option<Vec<bool>>;
+
+    /// Emits a page of the current state as [`events::AclSnapshot`] event,
+    /// provided that the predecessor is a super-admin. It is a recovery tool
+    /// for indexers which missed events, enabling them to rebuild state from
+    /// logs.
+    ///
+    /// Entries are ordered by super-admins, then admins and finally grantees,
+    /// with roles ordered by their `u8` representation. Up to `limit` entries
+    /// are included, skipping the first `skip` entries. Expired grants are
+    /// not included.
+    ///
+    /// In case of sufficient permissions, the returned `Some(bool)` indicates
+    /// whether there are more entries to emit. Without permissions, `None` is
+    /// returned and no event is emitted.
+    ///
+    /// It takes `&mut self` to be callable only via transactions, since the
+    /// logs of view calls are not recorded.
+    fn acl_emit_snapshot(&mut self, skip: u64, limit: u64) -> Option<bool>;
+
+    /// Returns a hash of the current state, enabling indexers to detect
+    /// whether the state they derived from events diverges from the state of
+    /// the contract. Divergence can then be resolved via
+    /// [`acl_emit_snapshot`].
+    ///
+    /// The hash is the SHA-256 of the borsh serialization of
+    /// `(super_admins, roles)`, where `roles` contains a tuple
+    /// `(role, admins, grantees)` for every role, ordered by their `u8`
+    /// representation. All accounts are sorted by account id, hence the hash
+    /// does not depend on the order of grants. Like [`acl_emit_snapshot`], it
+    /// skips expired grants.
+    ///
+    /// It reads every bearer, hence gas usage grows with their number.
+    fn acl_state_checksum(&self) -> Base58CryptoHash;
+
+    /// Attaches metadata to `role`, e.g. a link to the role's charter, by
+    /// setting the value under `key` to `value`, provided that the predecessor
+    /// is an admin for `role`. An existing value under `key` is updated.
+    ///
+    /// Returns whether the predecessor has sufficient permissions. Without
+    /// permissions, internal state is not modified.
+    fn acl_set_role_metadata(&mut self, role: String, key: String, value: String) -> bool;
+
+    /// Returns the metadata of `role` under `key`, if any.
+    fn acl_get_role_metadata(&self, role: String, key: String) -> Option<String>;
+
+    /// Enables paginated retrieval of the metadata keys of `role`, like
+    /// [`acl_get_grantees_page`] does for grantees.
+    fn acl_get_role_metadata_keys(
+        &self,
+        role: String,
+        cursor: Option<String>,
+        limit: u64,
+    ) -> Page<String>;
+}
+
+/// Role name used by [`AccessControllable::acl_get_all_admins`] to mark
+/// super-admins.
+pub const ACL_SUPER_ADMIN_ROLE_MARKER: &str = "__SUPER_ADMIN";
+
+/// The maximum number of entries returned by a single call of
+/// [`AccessControllable::acl_get_super_admins`],
+/// [`AccessControllable::acl_get_admins`],
+/// [`AccessControllable::acl_get_grantees`] and their `_page` siblings, as
+/// well as of [`AccessControllable::acl_get_all_admins`],
+/// [`AccessControllable::acl_get_role_metadata_keys`] and
+/// [`AccessControllable::acl_emit_snapshot`]. A larger `limit` is clamped to
+/// it rather than rejected, which keeps calls within their gas limit.
+pub const ACL_MAX_LIMIT: u64 = 1000;
+
+/// The maximum number of pairs accepted by
+/// [`AccessControllable::acl_transfer_role_bulk`]. It bounds the gas consumed
+/// by a single call.
+pub const ACL_TRANSFER_ROLE_BULK_MAX_LEN: usize = 100;
+
+/// The maximum number of accounts accepted by
+/// [`AccessControllable::acl_grant_roles`]. It bounds the gas consumed by a
+/// single call.
+pub const ACL_GRANT_ROLES_MAX_LEN: usize = 100;
+
+/// The maximum number of accounts accepted by
+/// [`AccessControllable::acl_revoke_role_batch`] and
+/// [`AccessControllable::acl_revoke_admin_batch`]. It bounds the gas consumed
+/// by a single call.
+pub const ACL_REVOKE_BATCH_MAX_LEN: usize = 100;
+
+/// The method called on the handle registry by
+/// [`AccessControllable::acl_grant_role_by_handle`] to resolve a handle.
+pub const ACL_HANDLE_REGISTRY_RESOLVE_METHOD: &str = "resolve";
+
+/// Gas attached to the call resolving a handle via the handle registry.
+pub const ACL_RESOLVE_HANDLE_GAS: Gas = Gas(10 * Gas::ONE_TERA.0);
+
+/// Gas attached to [`AccessControllable::acl_on_handle_resolved`].
+pub const ACL_ON_HANDLE_RESOLVED_GAS: Gas = Gas(20 * Gas::ONE_TERA.0);
+
+/// The method called on accounts notified by
+/// [`AccessControllable::acl_revoke_role_notify`].
+pub const ACL_ON_ROLE_REVOKED_METHOD: &str = "on_role_revoked";
+
+/// Gas attached to notifications of [`AccessControllable::acl_revoke_role_notify`].
+pub const ACL_ON_ROLE_REVOKED_GAS: Gas = Gas(5 * Gas::ONE_TERA.0);
+
+/// The version of the storage layout expected by `AccessControllable`. It is
+/// increased whenever the layout changes in a way that requires migrating
+/// state.
+pub const ACL_STORAGE_VERSION: u32 = 1;
+
+/// The number of bytes NEAR charges for each storage record in addition to
+/// the sizes of its key and value.
+pub const ACL_STORAGE_RECORD_OVERHEAD: u64 = 40;
+
+/// Progress of revoking a role from all its grantees, returned by
+/// [`AccessControllable::acl_revoke_role_from_all`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RevokeRoleFromAllProgress {
+    /// Number of grantees from whom the role was revoked in this call.
+    pub removed: u64,
+    /// Number of grantees still holding the role.
+    pub remaining: u64,
+}
+
+/// A super-admin transfer proposed via
+/// [`AccessControllable::acl_propose_super_admin_transfer`].
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SuperAdminTransfer {
+    /// The super-admin which proposed the transfer.
+    pub from: AccountId,
+    /// The account to receive super-admin permissions.
+    pub to: AccountId,
+}
+
+/// Result of the `_checked` variants of mutating methods, e.g.
+/// [`AccessControllable::acl_grant_role_checked`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AclResult {
+    /// The predecessor has sufficient permissions. The `bool` is the one that
+    /// the corresponding method without `_checked` suffix returns wrapped in
+    /// `Some`.
+    Ok(bool),
+    /// The predecessor has insufficient permissions, which corresponds to
+    /// `None` returned by the method without `_checked` suffix.
+    NoPermission,
+    /// The role name does not correspond to a role.
+    UnknownRole,
+}
+
+impl From<Option<bool>> for AclResult {
+    fn from(value: Option<bool>) -> Self {
+        match value {
+            Some(value) => Self::Ok(value),
+            None => Self::NoPermission,
+        }
+    }
+}
+
+/// Changes applied by [`AccessControllable::acl_apply_membership_change`].
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MembershipChangeResult {
+    /// Accounts which were newly granted the role.
+    pub added: Vec<AccountId>,
+    /// Accounts from which the role was revoked.
+    pub removed: Vec<AccountId>,
+}
+
+/// Governance risks reported by [`AccessControllable::acl_health`].
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AclHealth {
+    /// The detected risks. It is empty if none were detected.
+    pub warnings: Vec<HealthWarning>,
+}
+
+impl AclHealth {
+    /// Returns whether any of the warnings is critical.
+    pub fn is_critical(&self) -> bool {
+        self.warnings
+            .iter()
+            .any(|warning| warning.severity() == HealthSeverity::Critical)
+    }
+}
+
+/// A governance risk detected by [`AccessControllable::acl_health`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum HealthWarning {
+    /// There is no super-admin, so admins can no longer be added for roles
+    /// without admins.
+    NoSuperAdmin,
+    /// There is exactly one super-admin, which is a single point of failure.
+    SingleSuperAdmin,
+    /// `role` has `grantees` but no admins.
+    RoleWithoutAdmins { role: String, grantees: u64 },
+}
+
+/// Severity of a [`HealthWarning`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum HealthSeverity {
+    Warning,
+    Critical,
+}
+
+impl HealthWarning {
+    /// Returns how urgently the warning should be addressed.
+    pub fn severity(&self) -> HealthSeverity {
+        match self {
+            Self::NoSuperAdmin => HealthSeverity::Critical,
+            Self::SingleSuperAdmin | Self::RoleWithoutAdmins { .. } => HealthSeverity::Warning,
+        }
+    }
+}
+
+/// Declarative initial state of `AccessControllable`, applied at once via
+/// [`AccessControllable::acl_init_from_manifest`].
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AclManifest {
+    /// Accounts to make super-admins.
+    pub super_admins: Vec<AccountId>,
+    /// Pairs `(role, account_id)` making `account_id` an admin for `role`.
+    pub admins: Vec<(String, AccountId)>,
+    /// Pairs `(role, account_id)` granting `role` to `account_id`.
+    pub grantees: Vec<(String, AccountId)>,
+}
+
+impl AclManifest {
+    /// Checks that the manifest contains at least one super-admin and no
+    /// duplicate entries. Role names are checked when the manifest is
+    /// applied, since they depend on the contract's role type.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.super_admins.is_empty() {
+            return Err("AclManifest: at least one super-admin is required".to_string());
+        }
+        let mut super_admins = std::collections::HashSet::new();
+        for account_id in self.super_admins.iter() {
+            if !super_admins.insert(account_id) {
+                return Err(format!("AclManifest: duplicate super-admin {}", account_id));
+            }
+        }
+        for (kind, entries) in [("admin", &self.admins), ("grantee", &self.grantees)] {
+            let mut seen = std::collections::HashSet::new();
+            for (role, account_id) in entries.iter() {
+                if !seen.insert((role, account_id)) {
+                    return Err(format!(
+                        "AclManifest: duplicate {} {} of role {}",
+                        kind, account_id, role
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 pub mod events {
@@ -90,7 +1276,160 @@ pub mod events {
     use near_sdk::AccountId;
 
     const STANDARD: &str = "AccessControllable";
-    const VERSION: &str = "1.0.0";
+    const DEFAULT_VERSION: &str = "1.0.0";
+
+    /// Declares the schema version of an event, which is emitted in its
+    /// `version` field. Events start at `1.0.0` unless they override
+    /// [`EventVersion::VERSION`], enabling consumers to distinguish schema
+    /// versions per event.
+    pub trait EventVersion {
+        const VERSION: &'static str = DEFAULT_VERSION;
+    }
+
+    /// Wraps all events emitted by `AccessControllable`, enabling consumers to
+    /// handle them exhaustively.
+    #[derive(Clone)]
+    pub enum AclEvent {
+        SuperAdminAdded(SuperAdminAdded),
+        SuperAdminRevoked(SuperAdminRevoked),
+        AdminAdded(AdminAdded),
+        AdminRevoked(AdminRevoked),
+        RoleGranted(RoleGranted),
+        RoleRevoked(RoleRevoked),
+        RoleCleared(RoleCleared),
+        AclSnapshot(AclSnapshot),
+        AssumableRoleAllowed(AssumableRoleAllowed),
+        AssumableRoleDisallowed(AssumableRoleDisallowed),
+        RoleAssumed(RoleAssumed),
+        ScopedRoleGranted(ScopedRoleGranted),
+        ScopedRoleRevoked(ScopedRoleRevoked),
+        SuperAdminTransferProposed(SuperAdminTransferProposed),
+        SuperAdminTransferAccepted(SuperAdminTransferAccepted),
+        SuperAdminTransferCancelled(SuperAdminTransferCancelled),
+    }
+
+    impl AclEvent {
+        /// Returns the log of the wrapped event, see [`AsEvent::event`].
+        pub fn event(&self) -> String {
+            match self {
+                Self::SuperAdminAdded(event) => event.event(),
+                Self::SuperAdminRevoked(event) => event.event(),
+                Self::AdminAdded(event) => event.event(),
+                Self::AdminRevoked(event) => event.event(),
+                Self::RoleGranted(event) => event.event(),
+                Self::RoleRevoked(event) => event.event(),
+                Self::RoleCleared(event) => event.event(),
+                Self::AclSnapshot(event) => event.event(),
+                Self::AssumableRoleAllowed(event) => event.event(),
+                Self::AssumableRoleDisallowed(event) => event.event(),
+                Self::RoleAssumed(event) => event.event(),
+                Self::ScopedRoleGranted(event) => event.event(),
+                Self::ScopedRoleRevoked(event) => event.event(),
+                Self::SuperAdminTransferProposed(event) => event.event(),
+                Self::SuperAdminTransferAccepted(event) => event.event(),
+                Self::SuperAdminTransferCancelled(event) => event.event(),
+            }
+        }
+
+        /// Returns the schema version of the wrapped event, see
+        /// [`EventVersion`].
+        pub fn version(&self) -> &'static str {
+            match self {
+                Self::SuperAdminAdded(_) => SuperAdminAdded::VERSION,
+                Self::SuperAdminRevoked(_) => SuperAdminRevoked::VERSION,
+                Self::AdminAdded(_) => AdminAdded::VERSION,
+                Self::AdminRevoked(_) => AdminRevoked::VERSION,
+                Self::RoleGranted(_) => RoleGranted::VERSION,
+                Self::RoleRevoked(_) => RoleRevoked::VERSION,
+                Self::RoleCleared(_) => RoleCleared::VERSION,
+                Self::AclSnapshot(_) => AclSnapshot::VERSION,
+                Self::AssumableRoleAllowed(_) => AssumableRoleAllowed::VERSION,
+                Self::AssumableRoleDisallowed(_) => AssumableRoleDisallowed::VERSION,
+                Self::RoleAssumed(_) => RoleAssumed::VERSION,
+                Self::ScopedRoleGranted(_) => ScopedRoleGranted::VERSION,
+                Self::ScopedRoleRevoked(_) => ScopedRoleRevoked::VERSION,
+                Self::SuperAdminTransferProposed(_) => SuperAdminTransferProposed::VERSION,
+                Self::SuperAdminTransferAccepted(_) => SuperAdminTransferAccepted::VERSION,
+                Self::SuperAdminTransferCancelled(_) => SuperAdminTransferCancelled::VERSION,
+            }
+        }
+
+        /// Returns the category of the wrapped event.
+        pub fn category(&self) -> EventCategory {
+            match self {
+                Self::SuperAdminAdded(_)
+                | Self::SuperAdminRevoked(_)
+                | Self::AdminAdded(_)
+                | Self::AdminRevoked(_)
+                | Self::SuperAdminTransferProposed(_)
+                | Self::SuperAdminTransferAccepted(_)
+                | Self::SuperAdminTransferCancelled(_) => EventCategory::Admin,
+                Self::RoleGranted(_)
+                | Self::RoleRevoked(_)
+                | Self::RoleCleared(_)
+                | Self::ScopedRoleGranted(_)
+                | Self::ScopedRoleRevoked(_) => EventCategory::Role,
+                Self::AssumableRoleAllowed(_)
+                | Self::AssumableRoleDisallowed(_)
+                | Self::RoleAssumed(_) => EventCategory::Assumption,
+                Self::AclSnapshot(_) => EventCategory::Snapshot,
+            }
+        }
+    }
+
+    /// Categories of events, which determine whether an event is emitted at
+    /// a given [`EventLevel`].
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum EventCategory {
+        /// Changes of super-admins and admins: `SuperAdminAdded`,
+        /// `SuperAdminRevoked`, `AdminAdded`, `AdminRevoked` and the events
+        /// of super-admin transfers.
+        Admin,
+        /// Grants and revocations of roles: `RoleGranted`, `RoleRevoked`,
+        /// `RoleCleared`, `ScopedRoleGranted` and `ScopedRoleRevoked`.
+        Role,
+        /// Assuming roles: `AssumableRoleAllowed`, `AssumableRoleDisallowed`
+        /// and `RoleAssumed`.
+        Assumption,
+        /// `AclSnapshot`, which is emitted only on explicit request.
+        Snapshot,
+    }
+
+    /// Controls which categories of events are emitted, configured via
+    /// `#[access_control(event_level = "minimal" | "full")]`. The default is
+    /// `"full"`.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum EventLevel {
+        /// Emits only events of the categories [`EventCategory::Role`] and
+        /// [`EventCategory::Snapshot`], which saves receipt space. Indexers
+        /// can't track admins of such contracts via events.
+        Minimal,
+        /// Emits all events.
+        Full,
+    }
+
+    impl EventLevel {
+        /// Returns whether events of `category` are emitted at this level.
+        pub fn emits(self, category: EventCategory) -> bool {
+            match self {
+                Self::Minimal => matches!(category, EventCategory::Role | EventCategory::Snapshot),
+                Self::Full => true,
+            }
+        }
+    }
+
+    /// Emits `event`.
+    pub fn emit(event: AclEvent) {
+        near_sdk::log!(event.event());
+    }
+
+    /// Emits `event` if its category is emitted at `level`. Code generated by
+    /// `#[access_control]` emits all events via this function.
+    pub fn emit_at_level(level: EventLevel, event: AclEvent) {
+        if level.emits(event.category()) {
+            emit(event);
+        }
+    }
 
     /// Event emitted when an accout is made super-admin.
     #[derive(Serialize, Clone)]
@@ -102,11 +1441,13 @@ pub mod events {
         pub by: AccountId,
     }
 
+    impl EventVersion for SuperAdminAdded {}
+
     impl AsEvent<SuperAdminAdded> for SuperAdminAdded {
         fn metadata(&self) -> EventMetadata<SuperAdminAdded> {
             EventMetadata {
                 standard: STANDARD.to_string(),
-                version: VERSION.to_string(),
+                version: Self::VERSION.to_string(),
                 event: "super_admin_added".to_string(),
                 data: Some(self.clone()),
             }
@@ -121,19 +1462,98 @@ pub mod events {
         pub account: AccountId,
         /// Account that revoked the permissions.
         pub by: AccountId,
+        /// Optional reason for the revocation.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub reason: Option<String>,
     }
 
+    impl EventVersion for SuperAdminRevoked {}
+
     impl AsEvent<SuperAdminRevoked> for SuperAdminRevoked {
         fn metadata(&self) -> EventMetadata<SuperAdminRevoked> {
             EventMetadata {
                 standard: STANDARD.to_string(),
-                version: VERSION.to_string(),
+                version: Self::VERSION.to_string(),
                 event: "super_admin_revoked".to_string(),
                 data: Some(self.clone()),
             }
         }
     }
 
+    /// Event emitted when a super-admin proposes to transfer its permissions.
+    #[derive(Serialize, Clone)]
+    #[serde(crate = "near_sdk::serde")]
+    pub struct SuperAdminTransferProposed {
+        /// Super-admin whose permissions are to be transferred.
+        pub from: AccountId,
+        /// Account to receive super-admin permissions.
+        pub to: AccountId,
+        /// Account that proposed the transfer.
+        pub by: AccountId,
+    }
+
+    impl EventVersion for SuperAdminTransferProposed {}
+
+    impl AsEvent<SuperAdminTransferProposed> for SuperAdminTransferProposed {
+        fn metadata(&self) -> EventMetadata<SuperAdminTransferProposed> {
+            EventMetadata {
+                standard: STANDARD.to_string(),
+                version: Self::VERSION.to_string(),
+                event: "super_admin_transfer_proposed".to_string(),
+                data: Some(self.clone()),
+            }
+        }
+    }
+
+    /// Event emitted when the target of a super-admin transfer accepts it.
+    #[derive(Serialize, Clone)]
+    #[serde(crate = "near_sdk::serde")]
+    pub struct SuperAdminTransferAccepted {
+        /// Account from whom super-admin permissions were transferred.
+        pub from: AccountId,
+        /// Account that received super-admin permissions.
+        pub to: AccountId,
+    }
+
+    impl EventVersion for SuperAdminTransferAccepted {}
+
+    impl AsEvent<SuperAdminTransferAccepted> for SuperAdminTransferAccepted {
+        fn metadata(&self) -> EventMetadata<SuperAdminTransferAccepted> {
+            EventMetadata {
+                standard: STANDARD.to_string(),
+                version: Self::VERSION.to_string(),
+                event: "super_admin_transfer_accepted".to_string(),
+                data: Some(self.clone()),
+            }
+        }
+    }
+
+    /// Event emitted when a pending super-admin transfer is cancelled, either
+    /// explicitly or by proposing another transfer.
+    #[derive(Serialize, Clone)]
+    #[serde(crate = "near_sdk::serde")]
+    pub struct SuperAdminTransferCancelled {
+        /// Super-admin that proposed the transfer.
+        pub from: AccountId,
+        /// Account that would have received super-admin permissions.
+        pub to: AccountId,
+        /// Account that cancelled the transfer.
+        pub by: AccountId,
+    }
+
+    impl EventVersion for SuperAdminTransferCancelled {}
+
+    impl AsEvent<SuperAdminTransferCancelled> for SuperAdminTransferCancelled {
+        fn metadata(&self) -> EventMetadata<SuperAdminTransferCancelled> {
+            EventMetadata {
+                standard: STANDARD.to_string(),
+                version: Self::VERSION.to_string(),
+                event: "super_admin_transfer_cancelled".to_string(),
+                data: Some(self.clone()),
+            }
+        }
+    }
+
     /// Event emitted when an account is made admin.
     #[derive(Serialize, Clone)]
     #[serde(crate = "near_sdk::serde")]
@@ -146,11 +1566,13 @@ pub mod events {
         pub by: AccountId,
     }
 
+    impl EventVersion for AdminAdded {}
+
     impl AsEvent<AdminAdded> for AdminAdded {
         fn metadata(&self) -> EventMetadata<AdminAdded> {
             EventMetadata {
                 standard: STANDARD.to_string(),
-                version: VERSION.to_string(),
+                version: Self::VERSION.to_string(),
                 event: "admin_added".to_string(),
                 data: Some(self.clone()),
             }
@@ -167,13 +1589,19 @@ pub mod events {
         pub account: AccountId,
         /// Account that revoked the admin.
         pub by: AccountId,
+        /// Groups the events emitted by a batch method. It is `None` for
+        /// single operations.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub correlation_id: Option<String>,
     }
 
+    impl EventVersion for AdminRevoked {}
+
     impl AsEvent<AdminRevoked> for AdminRevoked {
         fn metadata(&self) -> EventMetadata<AdminRevoked> {
             EventMetadata {
                 standard: STANDARD.to_string(),
-                version: VERSION.to_string(),
+                version: Self::VERSION.to_string(),
                 event: "admin_revoked".to_string(),
                 data: Some(self.clone()),
             }
@@ -190,13 +1618,23 @@ pub mod events {
         pub to: AccountId,
         /// Account that granted the role.
         pub by: AccountId,
+        /// Groups the events emitted by a batch method. It is `None` for
+        /// single operations.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub correlation_id: Option<String>,
+        /// Block timestamp in nanoseconds after which the grant expires. It
+        /// is `None` for grants that do not expire.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub expires_at_ns: Option<u64>,
     }
 
+    impl EventVersion for RoleGranted {}
+
     impl AsEvent<RoleGranted> for RoleGranted {
         fn metadata(&self) -> EventMetadata<RoleGranted> {
             EventMetadata {
                 standard: STANDARD.to_string(),
-                version: VERSION.to_string(),
+                version: Self::VERSION.to_string(),
                 event: "role_granted".to_string(),
                 data: Some(self.clone()),
             }
@@ -213,16 +1651,370 @@ pub mod events {
         pub from: AccountId,
         /// Account that revoked the role.
         pub by: AccountId,
+        /// Groups the events emitted by a batch method. It is `None` for
+        /// single operations.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub correlation_id: Option<String>,
     }
 
+    impl EventVersion for RoleRevoked {}
+
     impl AsEvent<RoleRevoked> for RoleRevoked {
         fn metadata(&self) -> EventMetadata<RoleRevoked> {
             EventMetadata {
                 standard: STANDARD.to_string(),
-                version: VERSION.to_string(),
+                version: Self::VERSION.to_string(),
                 event: "role_revoked".to_string(),
                 data: Some(self.clone()),
             }
         }
     }
+
+    /// Event emitted when a role is revoked from several of its grantees at
+    /// once, replacing a `RoleRevoked` event per grantee.
+    #[derive(Serialize, Clone)]
+    #[serde(crate = "near_sdk::serde")]
+    pub struct RoleCleared {
+        /// Role that was revoked.
+        pub role: String,
+        /// Number of grantees from whom the role was revoked.
+        pub count: u64,
+        /// Account that revoked the role.
+        pub by: AccountId,
+        /// Groups the events emitted by a batch method. It is `None` if no
+        /// correlation id was provided.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub correlation_id: Option<String>,
+    }
+
+    impl EventVersion for RoleCleared {}
+
+    impl AsEvent<RoleCleared> for RoleCleared {
+        fn metadata(&self) -> EventMetadata<RoleCleared> {
+            EventMetadata {
+                standard: STANDARD.to_string(),
+                version: Self::VERSION.to_string(),
+                event: "role_cleared".to_string(),
+                data: Some(self.clone()),
+            }
+        }
+    }
+
+    /// Event emitted when a role is granted to an account within a scope.
+    #[derive(Serialize, Clone)]
+    #[serde(crate = "near_sdk::serde")]
+    pub struct ScopedRoleGranted {
+        /// Role that was granted.
+        pub role: String,
+        /// Scope within which the role was granted.
+        pub scope: String,
+        /// Account that was granted the role.
+        pub to: AccountId,
+        /// Account that granted the role.
+        pub by: AccountId,
+    }
+
+    impl EventVersion for ScopedRoleGranted {}
+
+    impl AsEvent<ScopedRoleGranted> for ScopedRoleGranted {
+        fn metadata(&self) -> EventMetadata<ScopedRoleGranted> {
+            EventMetadata {
+                standard: STANDARD.to_string(),
+                version: Self::VERSION.to_string(),
+                event: "scoped_role_granted".to_string(),
+                data: Some(self.clone()),
+            }
+        }
+    }
+
+    /// Event emitted when a role within a scope is revoked from an account.
+    #[derive(Serialize, Clone)]
+    #[serde(crate = "near_sdk::serde")]
+    pub struct ScopedRoleRevoked {
+        /// Role that was revoked.
+        pub role: String,
+        /// Scope within which the role was revoked.
+        pub scope: String,
+        /// Account from whom the role was revoked.
+        pub from: AccountId,
+        /// Account that revoked the role.
+        pub by: AccountId,
+    }
+
+    impl EventVersion for ScopedRoleRevoked {}
+
+    impl AsEvent<ScopedRoleRevoked> for ScopedRoleRevoked {
+        fn metadata(&self) -> EventMetadata<ScopedRoleRevoked> {
+            EventMetadata {
+                standard: STANDARD.to_string(),
+                version: Self::VERSION.to_string(),
+                event: "scoped_role_revoked".to_string(),
+                data: Some(self.clone()),
+            }
+        }
+    }
+
+    /// Event emitted to recover a page of the current state, see
+    /// [`super::AccessControllable::acl_emit_snapshot`].
+    #[derive(Serialize, Clone)]
+    #[serde(crate = "near_sdk::serde")]
+    pub struct AclSnapshot {
+        /// Number of entries skipped before this page.
+        pub skip: u64,
+        /// Super-admins in this page.
+        pub super_admins: Vec<AccountId>,
+        /// Pairs of role and admin in this page.
+        pub admins: Vec<(String, AccountId)>,
+        /// Pairs of role and grantee in this page.
+        pub grantees: Vec<(String, AccountId)>,
+        /// Whether there are more entries after this page.
+        pub has_more: bool,
+    }
+
+    impl EventVersion for AclSnapshot {}
+
+    impl AsEvent<AclSnapshot> for AclSnapshot {
+        fn metadata(&self) -> EventMetadata<AclSnapshot> {
+            EventMetadata {
+                standard: STANDARD.to_string(),
+                version: Self::VERSION.to_string(),
+                event: "acl_snapshot".to_string(),
+                data: Some(self.clone()),
+            }
+        }
+    }
+
+    /// Event emitted when an account is allowed to assume a role.
+    #[derive(Serialize, Clone)]
+    #[serde(crate = "near_sdk::serde")]
+    pub struct AssumableRoleAllowed {
+        /// Role that may be assumed.
+        pub role: String,
+        /// Account that may assume the role.
+        pub account: AccountId,
+        /// Account that allowed assuming the role.
+        pub by: AccountId,
+    }
+
+    impl EventVersion for AssumableRoleAllowed {}
+
+    impl AsEvent<AssumableRoleAllowed> for AssumableRoleAllowed {
+        fn metadata(&self) -> EventMetadata<AssumableRoleAllowed> {
+            EventMetadata {
+                standard: STANDARD.to_string(),
+                version: Self::VERSION.to_string(),
+                event: "assumable_role_allowed".to_string(),
+                data: Some(self.clone()),
+            }
+        }
+    }
+
+    /// Event emitted when an account is no longer allowed to assume a role.
+    #[derive(Serialize, Clone)]
+    #[serde(crate = "near_sdk::serde")]
+    pub struct AssumableRoleDisallowed {
+        /// Role that may no longer be assumed.
+        pub role: String,
+        /// Account that may no longer assume the role.
+        pub account: AccountId,
+        /// Account that disallowed assuming the role.
+        pub by: AccountId,
+    }
+
+    impl EventVersion for AssumableRoleDisallowed {}
+
+    impl AsEvent<AssumableRoleDisallowed> for AssumableRoleDisallowed {
+        fn metadata(&self) -> EventMetadata<AssumableRoleDisallowed> {
+            EventMetadata {
+                standard: STANDARD.to_string(),
+                version: Self::VERSION.to_string(),
+                event: "assumable_role_disallowed".to_string(),
+                data: Some(self.clone()),
+            }
+        }
+    }
+
+    /// Event emitted when an account assumes a role for the current receipt.
+    #[derive(Serialize, Clone)]
+    #[serde(crate = "near_sdk::serde")]
+    pub struct RoleAssumed {
+        /// Role that was assumed.
+        pub role: String,
+        /// Account that assumed the role.
+        pub account: AccountId,
+    }
+
+    impl EventVersion for RoleAssumed {}
+
+    impl AsEvent<RoleAssumed> for RoleAssumed {
+        fn metadata(&self) -> EventMetadata<RoleAssumed> {
+            EventMetadata {
+                standard: STANDARD.to_string(),
+                version: Self::VERSION.to_string(),
+                event: "role_assumed".to_string(),
+                data: Some(self.clone()),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use near_sdk::test_utils::get_logs;
+
+        fn account(id: &str) -> AccountId {
+            id.parse().unwrap()
+        }
+
+        #[test]
+        fn acl_event_matches_struct_event() {
+            let events = vec![
+                (
+                    AclEvent::SuperAdminAdded(SuperAdminAdded {
+                        account: account("alice.near"),
+                        by: account("bob.near"),
+                    }),
+                    SuperAdminAdded {
+                        account: account("alice.near"),
+                        by: account("bob.near"),
+                    }
+                    .event(),
+                ),
+                (
+                    AclEvent::SuperAdminRevoked(SuperAdminRevoked {
+                        account: account("alice.near"),
+                        by: account("bob.near"),
+                        reason: Some("rotation".to_string()),
+                    }),
+                    SuperAdminRevoked {
+                        account: account("alice.near"),
+                        by: account("bob.near"),
+                        reason: Some("rotation".to_string()),
+                    }
+                    .event(),
+                ),
+                (
+                    AclEvent::RoleGranted(RoleGranted {
+                        role: "LevelA".to_string(),
+                        to: account("alice.near"),
+                        by: account("bob.near"),
+                        correlation_id: None,
+                        expires_at_ns: None,
+                    }),
+                    RoleGranted {
+                        role: "LevelA".to_string(),
+                        to: account("alice.near"),
+                        by: account("bob.near"),
+                        correlation_id: None,
+                        expires_at_ns: None,
+                    }
+                    .event(),
+                ),
+                (
+                    AclEvent::RoleAssumed(RoleAssumed {
+                        role: "LevelA".to_string(),
+                        account: account("alice.near"),
+                    }),
+                    RoleAssumed {
+                        role: "LevelA".to_string(),
+                        account: account("alice.near"),
+                    }
+                    .event(),
+                ),
+            ];
+            for (acl_event, expected) in events {
+                assert_eq!(acl_event.event(), expected);
+            }
+        }
+
+        #[test]
+        fn emitted_version_matches_event_version() {
+            let events = vec![
+                AclEvent::SuperAdminAdded(SuperAdminAdded {
+                    account: account("alice.near"),
+                    by: account("bob.near"),
+                }),
+                AclEvent::RoleGranted(RoleGranted {
+                    role: "LevelA".to_string(),
+                    to: account("alice.near"),
+                    by: account("bob.near"),
+                    correlation_id: None,
+                    expires_at_ns: None,
+                }),
+                AclEvent::RoleAssumed(RoleAssumed {
+                    role: "LevelA".to_string(),
+                    account: account("alice.near"),
+                }),
+                AclEvent::RoleCleared(RoleCleared {
+                    role: "LevelA".to_string(),
+                    count: 2,
+                    by: account("bob.near"),
+                    correlation_id: None,
+                }),
+                AclEvent::SuperAdminTransferProposed(SuperAdminTransferProposed {
+                    from: account("alice.near"),
+                    to: account("bob.near"),
+                    by: account("alice.near"),
+                }),
+            ];
+            for event in events {
+                let log = event.event();
+                let json = log.strip_prefix("EVENT_JSON:").unwrap();
+                let value: near_sdk::serde_json::Value =
+                    near_sdk::serde_json::from_str(json).unwrap();
+                assert_eq!(value["version"], event.version());
+                assert_eq!(event.version(), "1.0.0");
+            }
+        }
+
+        #[test]
+        fn emit_logs_event() {
+            let event = AdminAdded {
+                role: "LevelA".to_string(),
+                account: account("alice.near"),
+                by: account("bob.near"),
+            };
+            emit(AclEvent::AdminAdded(event.clone()));
+            assert_eq!(get_logs(), vec![event.event()]);
+        }
+
+        #[test]
+        fn emit_at_level_filters_categories() {
+            let admin_added = AdminAdded {
+                role: "LevelA".to_string(),
+                account: account("alice.near"),
+                by: account("bob.near"),
+            };
+            let role_granted = RoleGranted {
+                role: "LevelA".to_string(),
+                to: account("alice.near"),
+                by: account("bob.near"),
+                correlation_id: None,
+                expires_at_ns: None,
+            };
+            let role_assumed = RoleAssumed {
+                role: "LevelA".to_string(),
+                account: account("alice.near"),
+            };
+            let emit_all = |level| {
+                emit_at_level(level, AclEvent::AdminAdded(admin_added.clone()));
+                emit_at_level(level, AclEvent::RoleGranted(role_granted.clone()));
+                emit_at_level(level, AclEvent::RoleAssumed(role_assumed.clone()));
+            };
+
+            emit_all(EventLevel::Minimal);
+            assert_eq!(get_logs(), vec![role_granted.event()]);
+
+            emit_all(EventLevel::Full);
+            assert_eq!(
+                get_logs(),
+                vec![
+                    role_granted.event(),
+                    admin_added.event(),
+                    role_granted.event(),
+                    role_assumed.event(),
+                ]
+            );
+        }
+    }
 }
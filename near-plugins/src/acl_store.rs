@@ -0,0 +1,143 @@
+//! # Storage backends of `AccessControllable`
+//!
+//! [`AclStore`] abstracts the storage of grants, which makes the permission
+//! logic testable and the storage swappable. Permissions are passed as the
+//! bitflags returned by [`AccessControlRole::acl_permission`] and
+//! [`AccessControlRole::acl_admin_permission`], each with exactly one active
+//! bit.
+//!
+//! The struct generated by `#[access_control]` implements [`AclStore`] on top
+//! of its own collections, keeping its storage layout. [`MockAclStore`] keeps
+//! grants in memory.
+//!
+//! [`AccessControlRole::acl_permission`]: crate::AccessControlRole::acl_permission
+//! [`AccessControlRole::acl_admin_permission`]: crate::AccessControlRole::acl_admin_permission
+use near_sdk::AccountId;
+use std::collections::BTreeMap;
+
+/// Storage of the accounts bearing permissions.
+pub trait AclStore {
+    /// Returns whether `account_id` bears `permission`.
+    fn has_role(&self, permission: u128, account_id: &AccountId) -> bool;
+
+    /// Adds `account_id` as bearer of `permission`. Returns whether
+    /// `account_id` is a new bearer.
+    fn add_grantee(&mut self, permission: u128, account_id: &AccountId) -> bool;
+
+    /// Removes `account_id` as bearer of `permission`. Returns whether
+    /// `account_id` was a bearer.
+    fn remove_grantee(&mut self, permission: u128, account_id: &AccountId) -> bool;
+
+    /// Iterates over the bearers of `permission`.
+    fn iter_grantees(&self, permission: u128) -> Box<dyn Iterator<Item = AccountId> + '_>;
+}
+
+/// Returns up to `limit` bearers of `permission`, skipping the first `skip`
+/// bearers.
+pub fn get_grantees<S: AclStore + ?Sized>(
+    store: &S,
+    permission: u128,
+    skip: usize,
+    limit: usize,
+) -> Vec<AccountId> {
+    store
+        .iter_grantees(permission)
+        .skip(skip)
+        .take(limit)
+        .collect()
+}
+
+/// Panics if `permission` does not have exactly one active bit.
+fn assert_single_permission(permission: u128) {
+    assert!(
+        permission.is_power_of_two(),
+        "Adding a bearer is allowed only for permissions with exactly one active bit"
+    );
+}
+
+/// In-memory backend, e.g. to test permission logic without storage.
+#[derive(Default, Debug, Clone)]
+pub struct MockAclStore {
+    /// The bearers of each permission, in order of insertion.
+    bearers: BTreeMap<u128, Vec<AccountId>>,
+}
+
+impl AclStore for MockAclStore {
+    fn has_role(&self, permission: u128, account_id: &AccountId) -> bool {
+        self.bearers
+            .get(&permission)
+            .map_or(false, |bearers| bearers.contains(account_id))
+    }
+
+    fn add_grantee(&mut self, permission: u128, account_id: &AccountId) -> bool {
+        assert_single_permission(permission);
+        let bearers = self.bearers.entry(permission).or_default();
+        if bearers.contains(account_id) {
+            return false;
+        }
+        bearers.push(account_id.clone());
+        true
+    }
+
+    fn remove_grantee(&mut self, permission: u128, account_id: &AccountId) -> bool {
+        let bearers = match self.bearers.get_mut(&permission) {
+            Some(bearers) => bearers,
+            None => return false,
+        };
+        let len = bearers.len();
+        bearers.retain(|bearer| bearer != account_id);
+        bearers.len() < len
+    }
+
+    fn iter_grantees(&self, permission: u128) -> Box<dyn Iterator<Item = AccountId> + '_> {
+        match self.bearers.get(&permission) {
+            Some(bearers) => Box::new(bearers.iter().cloned()),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(id: &str) -> AccountId {
+        id.parse().unwrap()
+    }
+
+    /// Exercises the permission logic on `store`, which must be empty.
+    fn check_store<S: AclStore>(store: &mut S) {
+        let (alice, bob) = (account("alice.near"), account("bob.near"));
+        let (role_a, role_b) = (1 << 1, 1 << 3);
+
+        assert!(!store.has_role(role_a, &alice));
+        assert!(store.add_grantee(role_a, &alice));
+        assert!(!store.add_grantee(role_a, &alice));
+        assert!(store.add_grantee(role_a, &bob));
+        assert!(store.has_role(role_a, &alice));
+        assert!(!store.has_role(role_b, &alice));
+
+        assert_eq!(
+            get_grantees(store, role_a, 0, 10),
+            vec![alice.clone(), bob.clone()]
+        );
+        assert_eq!(get_grantees(store, role_a, 1, 10), vec![bob.clone()]);
+        assert_eq!(get_grantees(store, role_b, 0, 10), vec![]);
+
+        assert!(store.remove_grantee(role_a, &alice));
+        assert!(!store.remove_grantee(role_a, &alice));
+        assert!(!store.has_role(role_a, &alice));
+        assert_eq!(get_grantees(store, role_a, 0, 10), vec![bob]);
+    }
+
+    #[test]
+    fn test_mock_store() {
+        check_store(&mut MockAclStore::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "exactly one active bit")]
+    fn test_add_grantee_multiple_bits() {
+        MockAclStore::default().add_grantee(0b11, &account("alice.near"));
+    }
+}
@@ -18,6 +18,22 @@ pub struct EventMetadata<T: Serialize = ()> {
     pub data: Option<T>,
 }
 
+/// Type of the data of events which carry no data, like signal-only events.
+pub type NoData = ();
+
+impl EventMetadata<NoData> {
+    /// Creates the metadata of an event which carries no data. The serialized
+    /// event omits the `data` field.
+    pub fn without_data(standard: &str, version: &str, event: &str) -> Self {
+        Self {
+            standard: standard.to_string(),
+            version: version.to_string(),
+            event: event.to_string(),
+            data: None,
+        }
+    }
+}
+
 pub trait AsEvent<T: Serialize> {
     fn metadata(&self) -> EventMetadata<T>;
 
@@ -35,7 +51,7 @@ pub trait AsEvent<T: Serialize> {
 
 #[cfg(test)]
 mod tests {
-    use crate::events::{AsEvent, EventMetadata};
+    use crate::events::{AsEvent, EventMetadata, NoData};
 
     struct CompileEvent {
         info: Option<String>,
@@ -52,6 +68,14 @@ mod tests {
         }
     }
 
+    struct ResetEvent;
+
+    impl AsEvent<NoData> for ResetEvent {
+        fn metadata(&self) -> EventMetadata<NoData> {
+            EventMetadata::without_data("Compile", "0.0.1", "reset_test")
+        }
+    }
+
     /// Helper function to check if an event is well formed and follows NEP-297
     /// i.e. tries to deserialize the json object.
     fn valid_event(event: String) -> bool {
@@ -95,4 +119,12 @@ mod tests {
         assert_eq!(event_log, expected);
         assert!(valid_event(event_log));
     }
+    #[test]
+    fn event_without_data() {
+        let event_log = ResetEvent.event();
+        let expected =
+            r#"EVENT_JSON:{"standard":"Compile","version":"0.0.1","event":"reset_test"}"#;
+        assert_eq!(event_log, expected);
+        assert!(valid_event(event_log));
+    }
 }
@@ -12,20 +12,43 @@
 //!
 //! Contract must be Ownable. Only the owner can attach a new FAK.
 //! The owner can be set to any arbitrary NEAR account id, for example a DAO.
+//!
+//! The keys attached via this mechanism are recorded in a storage slot, by default
+//! b"__FAK_KEYS__", and exposed via `fak_get_keys` to audit recovery keys. A key is recorded and
+//! the event `FullAccessKeyAdded` is emitted only once the key was added to the account, which
+//! fails e.g. if the key already exists.
+//!
+//! `attach_full_access_key` panics while the contract is in read-only mode, see
+//! [`crate::read_only`].
 use crate::events::{AsEvent, EventMetadata};
-use near_sdk::{AccountId, PublicKey};
+use near_sdk::{AccountId, Gas, PublicKey};
 use serde::Serialize;
 
 pub trait FullAccessKeyFallback {
-    /// Attach a new full access to the current contract.
+    /// Attach a new full access to the current contract. On success the callback
+    /// `fak_on_full_access_key_added` records the key and triggers an event of type
+    /// FullAccessKeyAdded.
     fn attach_full_access_key(&mut self, public_key: PublicKey) -> near_sdk::Promise;
     // fn attach_full_access_key(&mut self, public_key: PublicKey);
+
+    /// Callback of `attach_full_access_key`. Returns whether `public_key` was added to the
+    /// account. Only the contract itself may call this method.
+    fn fak_on_full_access_key_added(&mut self, by: AccountId, public_key: PublicKey) -> bool;
+
+    /// Returns the keys that were attached via `attach_full_access_key`, in order of their first
+    /// attachment. Keys removed from the account by other means are still listed.
+    fn fak_get_keys(&self) -> Vec<PublicKey>;
 }
 
+/// Gas attached to [`FullAccessKeyFallback::fak_on_full_access_key_added`].
+pub const FAK_ON_FULL_ACCESS_KEY_ADDED_GAS: Gas = Gas(5 * Gas::ONE_TERA.0);
+
 /// Event emitted every time a new FullAccessKey is added
 #[derive(Serialize, Clone)]
 pub struct FullAccessKeyAdded {
+    /// Account that attached the key, i.e. the owner.
     pub by: AccountId,
+    /// The attached key.
     pub public_key: PublicKey,
 }
 
@@ -47,7 +70,11 @@ mod tests {
     use crate as near_plugins;
     use crate::test_utils::get_context;
     use crate::{FullAccessKeyFallback, Ownable};
-    use near_sdk::{near_bindgen, testing_env, PublicKey};
+    use near_sdk::serde_json::Value;
+    use near_sdk::test_utils::get_logs;
+    use near_sdk::{
+        near_bindgen, testing_env, PromiseResult, PublicKey, RuntimeFeesConfig, VMConfig, VMContext,
+    };
     use std::convert::TryInto;
     use std::str::FromStr;
 
@@ -59,6 +86,17 @@ mod tests {
         PublicKey::from_str("ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp").unwrap()
     }
 
+    /// Sets up `ctx` with `result` as result of the key being added.
+    fn set_key_added_result(ctx: VMContext, result: PromiseResult) {
+        testing_env!(
+            ctx,
+            VMConfig::test(),
+            RuntimeFeesConfig::test(),
+            Default::default(),
+            vec![result],
+        );
+    }
+
     #[test]
     #[should_panic(expected = r#"Ownable: Method must be called from owner"#)]
     fn not_owner() {
@@ -82,4 +120,69 @@ mod tests {
 
         contract.attach_full_access_key(key());
     }
+
+    #[test]
+    fn emits_event() {
+        let mut ctx = get_context();
+        testing_env!(ctx.clone());
+
+        let mut contract = Contract;
+        contract.owner_set(Some("carol.test".to_string().try_into().unwrap()));
+
+        ctx.predecessor_account_id = "carol.test".to_string().try_into().unwrap();
+        testing_env!(ctx.clone());
+
+        // The event is emitted only once the key was added.
+        contract.attach_full_access_key(key());
+        assert!(get_logs().is_empty());
+
+        set_key_added_result(ctx, PromiseResult::Successful(vec![]));
+        assert!(contract.fak_on_full_access_key_added("carol.test".parse().unwrap(), key()));
+        let logs = get_logs();
+        let event: Value = near_sdk::serde_json::from_str(
+            logs.last().unwrap().strip_prefix("EVENT_JSON:").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(event["event"], "full_access_key_added");
+        assert_eq!(event["data"]["by"], "carol.test");
+        assert_eq!(event["data"]["public_key"], String::from(&key()));
+    }
+
+    #[test]
+    fn get_keys() {
+        let mut ctx = get_context();
+        testing_env!(ctx.clone());
+
+        let mut contract = Contract;
+        contract.owner_set(Some("carol.test".to_string().try_into().unwrap()));
+        assert_eq!(contract.fak_get_keys(), vec![]);
+
+        set_key_added_result(ctx, PromiseResult::Successful(vec![]));
+
+        let by: near_sdk::AccountId = "carol.test".parse().unwrap();
+        let other_key =
+            PublicKey::from_str("ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847").unwrap();
+        contract.fak_on_full_access_key_added(by.clone(), key());
+        contract.fak_on_full_access_key_added(by.clone(), other_key.clone());
+        // Attaching a key again does not list it twice.
+        contract.fak_on_full_access_key_added(by, key());
+        assert_eq!(contract.fak_get_keys(), vec![key(), other_key]);
+    }
+
+    #[test]
+    fn key_not_added() {
+        let mut ctx = get_context();
+        testing_env!(ctx.clone());
+
+        let mut contract = Contract;
+        contract.owner_set(Some("carol.test".to_string().try_into().unwrap()));
+
+        ctx.predecessor_account_id = "carol.test".to_string().try_into().unwrap();
+        set_key_added_result(ctx, PromiseResult::Failed);
+
+        // If adding the key failed, it is neither recorded nor reported.
+        assert!(!contract.fak_on_full_access_key_added("carol.test".parse().unwrap(), key()));
+        assert_eq!(contract.fak_get_keys(), vec![]);
+        assert!(get_logs().is_empty());
+    }
 }
@@ -0,0 +1,50 @@
+//! # Initialization:
+//!
+//! Some plugins require data to be set during initialization of the contract, e.g. an owner for
+//! `Ownable` or an admin for `AccessControllable`. Forgetting to initialize a plugin leaves the
+//! contract silently broken, since methods protected by the plugin can never be called.
+//!
+//! [`assert_all_plugins_initialized`] panics if any of the given plugins is not initialized. Only
+//! plugins that require initialization are accepted and using a plugin the contract doesn't
+//! implement is a compilation error.
+//!
+//! ```ignore
+//! pub fn guarded(&self) {
+//!     near_plugins::assert_all_plugins_initialized!(self, Ownable, AccessControllable);
+//!     // ..
+//! }
+//! ```
+//!
+//! A plugin is considered initialized if:
+//!
+//! - `Ownable`: an owner is set or ownership has been renounced.
+//! - `AccessControllable`: there is at least one super-admin or admin.
+
+/// Panics if any of the given plugins is not initialized, see the [module level
+/// documentation](crate::initialization).
+#[macro_export]
+macro_rules! assert_all_plugins_initialized {
+    ($contract:expr $(, $plugin:ident)+ $(,)?) => {
+        $(
+            $crate::__assert_plugin_initialized!($contract, $plugin);
+        )+
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_plugin_initialized {
+    ($contract:expr, Ownable) => {
+        assert!(
+            $crate::Ownable::owner_get($contract).is_some()
+                || $crate::Ownable::owner_is_renounced($contract),
+            "Plugin Ownable is not initialized: owner is not set",
+        );
+    };
+    ($contract:expr, AccessControllable) => {
+        assert!(
+            !$crate::AccessControllable::acl_get_all_admins($contract, 0, 1).is_empty(),
+            "Plugin AccessControllable is not initialized: there is no admin",
+        );
+    };
+}
@@ -1,9 +1,14 @@
+pub mod abi;
 pub mod access_control_role;
 pub mod access_controllable;
+pub mod acl_store;
 pub mod events;
 pub mod full_access_key_fallback;
+pub mod initialization;
 pub mod ownable;
+pub mod pagination;
 pub mod pausable;
+pub mod read_only;
 #[cfg(not(target_arch = "wasm32"))]
 mod test_utils;
 pub mod upgradable;
@@ -12,8 +17,9 @@ pub use access_control_role::AccessControlRole;
 pub use access_controllable::AccessControllable;
 pub use full_access_key_fallback::FullAccessKeyFallback;
 pub use near_plugins_derive::{
-    access_control, access_control_any, if_paused, only, pause, AccessControlRole,
-    FullAccessKeyFallback, Ownable, Pausable, Upgradable,
+    access_control, access_control_all, access_control_any, grants_role_on_success, if_paused,
+    only, pause, AccessControlRole, FullAccessKeyFallback, Ownable, Pausable, PluginsAbi,
+    Upgradable,
 };
 pub use ownable::Ownable;
 pub use pausable::Pausable;
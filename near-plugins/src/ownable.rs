@@ -7,6 +7,18 @@
 //! During creation of the contract set the owner using `owner_set`. Protect functions that should
 //! only be called by the owner using #[only(owner)].
 //!
+//! Ownership can be transferred in two steps as well: the owner proposes a new owner with
+//! `owner_propose`, who then calls `owner_accept`. This protects against transferring ownership to
+//! a mistyped account. The proposed owner is stored in its own slot, by default
+//! b"__PROPOSED_OWNER__". Like all other methods of this plugin these are prefixed with `owner_`
+//! instead of being named `propose_owner` and `accept_owner`.
+//!
+//! With `owner_renounce` the owner makes the contract permanently ownerless. Afterwards methods
+//! protected by #[only(owner)] fail and the owner can't be set anymore, not even by self. This is
+//! recorded in another slot, by default b"__OWNER_RENOUNCED__".
+//!
+//! Mutating methods panic while the contract is in read-only mode, see [`crate::read_only`].
+//!
 //! ## Credits:
 //!
 //! Inspired by Open Zeppelin Ownable module:
@@ -31,10 +43,35 @@ pub trait Ownable {
     ///
     /// Only the current owner can call this method. If no owner is set, only self can call this
     /// method. Notice that if the owner is set, self will not be able to call `owner_set` by default.
+    /// After ownership has been renounced via `owner_renounce`, nobody can call this method.
     fn owner_set(&mut self, owner: Option<AccountId>);
 
+    /// Remove the owner permanently, which freezes the contract with respect to ownership changes.
+    /// Triggers an event of type OwnershipRenounced. Only the current owner can call this method.
+    fn owner_renounce(&mut self);
+
+    /// Return true if ownership has been renounced via `owner_renounce`.
+    fn owner_is_renounced(&self) -> bool;
+
     /// Return true if the predecessor account id is the owner of the contract.
     fn owner_is(&self) -> bool;
+
+    /// Return the account proposed as new owner via `owner_propose`, if any.
+    fn owner_get_proposed(&self) -> Option<AccountId>;
+
+    /// Propose `account_id` as new owner, which becomes the owner once it calls `owner_accept`.
+    /// Until then the current owner stays in control. A new proposal replaces the previous one and
+    /// `None` clears it. Triggers an event of type OwnershipTransferProposed.
+    ///
+    /// # Default Implementation:
+    ///
+    /// Only the current owner can call this method and it can't propose itself. A proposal is
+    /// cleared when the owner changes via `owner_set`.
+    fn owner_propose(&mut self, account_id: Option<AccountId>);
+
+    /// Accept ownership of the contract. It must be called by the proposed owner. Triggers events
+    /// of type OwnershipTransferAccepted and OwnershipTransferred.
+    fn owner_accept(&mut self);
 }
 
 /// Event emitted when ownership is changed.
@@ -55,12 +92,67 @@ impl AsEvent<OwnershipTransferred> for OwnershipTransferred {
     }
 }
 
+/// Event emitted when ownership is renounced.
+#[derive(Serialize, Clone)]
+pub struct OwnershipRenounced {
+    pub previous_owner: AccountId,
+}
+
+impl AsEvent<OwnershipRenounced> for OwnershipRenounced {
+    fn metadata(&self) -> EventMetadata<OwnershipRenounced> {
+        EventMetadata {
+            standard: "Ownable".to_string(),
+            version: "1.0.0".to_string(),
+            event: "ownership_renounced".to_string(),
+            data: Some(self.clone()),
+        }
+    }
+}
+
+/// Event emitted when a new owner is proposed.
+#[derive(Serialize, Clone)]
+pub struct OwnershipTransferProposed {
+    pub owner: AccountId,
+    /// `None` if the proposal was cleared.
+    pub proposed_owner: Option<AccountId>,
+}
+
+impl AsEvent<OwnershipTransferProposed> for OwnershipTransferProposed {
+    fn metadata(&self) -> EventMetadata<OwnershipTransferProposed> {
+        EventMetadata {
+            standard: "Ownable".to_string(),
+            version: "1.0.0".to_string(),
+            event: "ownership_transfer_proposed".to_string(),
+            data: Some(self.clone()),
+        }
+    }
+}
+
+/// Event emitted when the proposed owner accepts ownership.
+#[derive(Serialize, Clone)]
+pub struct OwnershipTransferAccepted {
+    pub previous_owner: Option<AccountId>,
+    pub new_owner: AccountId,
+}
+
+impl AsEvent<OwnershipTransferAccepted> for OwnershipTransferAccepted {
+    fn metadata(&self) -> EventMetadata<OwnershipTransferAccepted> {
+        EventMetadata {
+            standard: "Ownable".to_string(),
+            version: "1.0.0".to_string(),
+            event: "ownership_transfer_accepted".to_string(),
+            data: Some(self.clone()),
+        }
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 #[cfg(test)]
 mod tests {
     use crate as near_plugins;
     use crate::test_utils::get_context;
     use crate::{only, Ownable};
+    use near_sdk::test_utils::get_logs;
     use near_sdk::{near_bindgen, testing_env, VMContext};
     use std::convert::TryInto;
 
@@ -151,6 +243,128 @@ mod tests {
         assert_eq!(counter.owner_get(), None);
     }
 
+    #[test]
+    fn test_propose_and_accept_owner() {
+        let (mut counter, mut ctx) = setup_basic();
+        ctx.predecessor_account_id = "carol.test".to_string().try_into().unwrap();
+        testing_env!(ctx.clone());
+        counter.owner_propose(Some("eve.test".to_string().try_into().unwrap()));
+        assert!(get_logs()
+            .last()
+            .unwrap()
+            .contains("ownership_transfer_proposed"));
+
+        // The current owner stays in control until the proposal is accepted.
+        assert_eq!(
+            counter.owner_get(),
+            Some("carol.test".to_string().try_into().unwrap())
+        );
+        assert_eq!(
+            counter.owner_get_proposed(),
+            Some("eve.test".to_string().try_into().unwrap())
+        );
+
+        ctx.predecessor_account_id = "eve.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+        counter.owner_accept();
+        assert!(counter.owner_is());
+        assert_eq!(counter.owner_get_proposed(), None);
+        let logs = get_logs();
+        assert!(logs[logs.len() - 2].contains("ownership_transfer_accepted"));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"Ownable: Only the proposed owner can accept ownership"#)]
+    fn test_accept_owner_not_proposed() {
+        let (mut counter, mut ctx) = setup_basic();
+        ctx.predecessor_account_id = "carol.test".to_string().try_into().unwrap();
+        testing_env!(ctx.clone());
+        counter.owner_propose(Some("eve.test".to_string().try_into().unwrap()));
+
+        ctx.predecessor_account_id = "mallory.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+        counter.owner_accept();
+    }
+
+    #[test]
+    #[should_panic(expected = r#"Ownable: Only the proposed owner can accept ownership"#)]
+    fn test_accept_owner_cleared_proposal() {
+        let (mut counter, mut ctx) = setup_basic();
+        ctx.predecessor_account_id = "carol.test".to_string().try_into().unwrap();
+        testing_env!(ctx.clone());
+        counter.owner_propose(Some("eve.test".to_string().try_into().unwrap()));
+        counter.owner_propose(None);
+        assert_eq!(counter.owner_get_proposed(), None);
+
+        ctx.predecessor_account_id = "eve.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+        counter.owner_accept();
+    }
+
+    #[test]
+    #[should_panic(expected = r#"Ownable: Only owner can propose a new owner"#)]
+    fn test_propose_owner_fail() {
+        let (mut counter, mut ctx) = setup_basic();
+        ctx.predecessor_account_id = "mallory.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+        counter.owner_propose(Some("mallory.test".to_string().try_into().unwrap()));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"Ownable: The current owner can't be proposed as new owner"#)]
+    fn test_propose_current_owner() {
+        let (mut counter, mut ctx) = setup_basic();
+        ctx.predecessor_account_id = "carol.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+        counter.owner_propose(Some("carol.test".to_string().try_into().unwrap()));
+    }
+
+    #[test]
+    fn test_renounce_owner() {
+        let (mut counter, mut ctx) = setup_basic();
+        ctx.predecessor_account_id = "carol.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+        assert!(!counter.owner_is_renounced());
+        counter.owner_renounce();
+        assert!(counter.owner_is_renounced());
+        assert_eq!(counter.owner_get(), None);
+        assert!(!counter.owner_is());
+        assert!(get_logs().last().unwrap().contains("ownership_renounced"));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"Ownable: Only owner can renounce ownership"#)]
+    fn test_renounce_owner_fail() {
+        let (mut counter, mut ctx) = setup_basic();
+        ctx.predecessor_account_id = "mallory.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+        counter.owner_renounce();
+    }
+
+    #[test]
+    #[should_panic(expected = r#"Ownable: Ownership has been renounced"#)]
+    fn test_set_owner_after_renounce() {
+        let (mut counter, mut ctx) = setup_basic();
+        ctx.predecessor_account_id = "carol.test".to_string().try_into().unwrap();
+        testing_env!(ctx.clone());
+        counter.owner_renounce();
+
+        // Not even self can set an owner.
+        ctx.predecessor_account_id = ctx.current_account_id.clone();
+        testing_env!(ctx);
+        counter.owner_set(Some("carol.test".to_string().try_into().unwrap()));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"Ownable: Method must be called from owner"#)]
+    fn protected_owner_after_renounce() {
+        let (mut counter, mut ctx) = setup_basic();
+        ctx.predecessor_account_id = "carol.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+        counter.owner_renounce();
+        counter.protected_owner();
+    }
+
     #[test]
     fn counter_unprotected() {
         let (mut counter, _) = setup_basic();
@@ -0,0 +1,88 @@
+//! # Pagination
+//!
+//! Cursor based pagination shared by views of plugins. A view returns a
+//! [`Page`] and clients pass its `next` cursor to the subsequent call, until
+//! `next` is `None`. Each page carries the `total` number of items as well,
+//! which saves clients a separate call to count them.
+use near_sdk::serde::{Deserialize, Serialize};
+
+/// A bounded page of items.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Page<T> {
+    /// The items of this page.
+    pub items: Vec<T>,
+    /// Opaque cursor to retrieve the next page. It is `None` if this is the
+    /// last page.
+    pub next: Option<String>,
+    /// Number of items in the collection at the time this page was read.
+    pub total: u64,
+}
+
+impl<T> Page<T> {
+    /// Creates a page of `items` which were read starting at `position` from
+    /// a collection of `len` elements.
+    pub fn new(items: Vec<T>, position: u64, len: u64) -> Self {
+        let end = position.saturating_add(items.len() as u64);
+        let next = if end < len {
+            Some(encode_cursor(end))
+        } else {
+            None
+        };
+        Self {
+            items,
+            next,
+            total: len,
+        }
+    }
+}
+
+/// Encodes a position in the backing collection as cursor.
+pub fn encode_cursor(position: u64) -> String {
+    position.to_string()
+}
+
+/// Decodes a cursor into a position in the backing collection. If `cursor` is
+/// `None`, the position of the first element is returned.
+///
+/// # Panics
+///
+/// Panics if `cursor` was not returned by [`encode_cursor`].
+pub fn decode_cursor(cursor: Option<&str>) -> u64 {
+    match cursor {
+        Some(cursor) => cursor.parse().expect("invalid pagination cursor"),
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        assert_eq!(decode_cursor(None), 0);
+        for position in [0, 1, 42, u64::MAX] {
+            assert_eq!(decode_cursor(Some(&encode_cursor(position))), position);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid pagination cursor")]
+    fn test_decode_invalid_cursor() {
+        decode_cursor(Some("foo"));
+    }
+
+    #[test]
+    fn test_page_next() {
+        let page = Page::new(vec![1, 2], 0, 3);
+        assert_eq!(page.next, Some(encode_cursor(2)));
+        assert_eq!(page.total, 3);
+        let page = Page::new(vec![3], 2, 3);
+        assert_eq!(page.next, None);
+        assert_eq!(page.total, 3);
+        let page: Page<u8> = Page::new(vec![], 0, 0);
+        assert_eq!(page.next, None);
+        assert_eq!(page.total, 0);
+    }
+}
@@ -18,6 +18,29 @@
 //! Only owner and self can call `pa_pause_feature` / `pa_unpause_feature`. Requires the contract to
 //! be Ownable.
 //!
+//! The maintenance message is stored in its own slot, by default b"__PAUSE_MESSAGE__". Only owner can
+//! call `pa_set_maintenance_message`.
+//!
+//! The block timestamp at which each key was paused is stored in a separate slot, by default
+//! b"__PAUSE_SINCE__", and exposed via `pa_paused_for`.
+//!
+//! Reasons given via `pa_pause_feature_with_reason` are stored per key in another slot, by default
+//! b"__PAUSE_REASONS__", and exposed via `pa_pause_reason`.
+//!
+//! Keys paused via `pa_pause_feature_until` are unpaused automatically once the block timestamp
+//! exceeds the scheduled time. These times are stored in another slot, by default
+//! b"__PAUSE_UNTIL__". Expired pauses are removed from storage by the next pause or unpause.
+//!
+//! With `#[pause(except_roles(Role::A, Role::B))]` grantees of any of the listed roles may call a
+//! method while it is paused, as checked by `acl_has_any_role`. This requires the contract to be
+//! `AccessControllable` as well.
+//!
+//! Besides `pa_is_paused`, the derive generates the inherent method `pa_check_not_paused`, which
+//! returns a [`PausableError`] instead of panicking like `#[pause]` does. Contract code may use it
+//! to degrade gracefully, e.g. by returning cached data while a feature is paused.
+//!
+//! Mutating methods panic while the contract is in read-only mode, see [`crate::read_only`].
+//!
 //! ## Credits:
 //!
 //! Inspired by Open Zeppelin Pausable module:
@@ -38,11 +61,53 @@ pub trait Pausable {
     /// List of all current paused features
     fn pa_all_paused(&self) -> Option<HashSet<String>>;
 
+    /// Duration in nanoseconds for which the feature has been paused, considering "ALL" as well.
+    /// If both are paused, the longer duration is returned. Returns `None` if the feature is not
+    /// paused.
+    ///
+    /// It can be used in method bodies to gate recovery logic, e.g. to allow force-cancelling only
+    /// if withdrawals have been paused for more than 24 hours.
+    fn pa_paused_for(&self, key: String) -> Option<u64>;
+
     /// Pause specified feature.
     fn pa_pause_feature(&mut self, key: String);
 
+    /// Pause specified feature with a human-readable `reason`, e.g. "oracle outage". The reason is
+    /// included in the pause event. Pausing a feature again replaces its reason, and
+    /// `pa_pause_feature` clears it.
+    fn pa_pause_feature_with_reason(&mut self, key: String, reason: String);
+
+    /// Reason for which the feature was paused. Returns `None` if the feature is not paused or was
+    /// paused without a reason. Only the reason of `key` itself is returned, not the one of "ALL".
+    fn pa_pause_reason(&self, key: String) -> Option<String>;
+
+    /// Pause specified feature until `unpause_at_ns`, a block timestamp in nanoseconds. Once the
+    /// block timestamp exceeds it, the feature is considered unpaused. It can still be unpaused
+    /// early via `pa_unpause_feature`. Pausing the feature again replaces the scheduled time, and
+    /// `pa_pause_feature` pauses it indefinitely.
+    fn pa_pause_feature_until(&mut self, key: String, unpause_at_ns: u64);
+
+    /// Time at which the pause of the feature expires. Returns `None` if the feature is not paused
+    /// or paused indefinitely.
+    fn pa_unpause_at(&self, key: String) -> Option<u64>;
+
     /// Unpause specified feature
     fn pa_unpause_feature(&mut self, key: String);
+
+    /// Pause all features by pausing "ALL", which covers features added to the contract later on
+    /// as well. Emits a single pause event with key "ALL".
+    fn pa_pause_all(&mut self);
+
+    /// Unpause all features, i.e. every currently paused key including "ALL". Emits one unpause
+    /// event per key.
+    fn pa_unpause_all(&mut self);
+
+    /// Set a user-facing maintenance message, independent of specific features. Frontends may
+    /// display it while features are paused. Use `None` to clear the message.
+    fn pa_set_maintenance_message(&mut self, message: Option<String>);
+
+    /// Current maintenance message, if any.
+    fn pa_maintenance_message(&self) -> Option<String>;
 }
 
 /// Event emitted when a feature is paused.
@@ -52,6 +117,13 @@ pub struct Pause {
     pub by: AccountId,
     /// Key identifying the feature that was paused.
     pub key: String,
+    /// Reason for the pause, if one was given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Block timestamp in nanoseconds at which the feature will be unpaused automatically, if the
+    /// pause is scheduled to expire.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unpause_at_ns: Option<u64>,
 }
 
 impl AsEvent<Pause> for Pause {
@@ -85,10 +157,28 @@ impl AsEvent<Unpause> for Unpause {
     }
 }
 
+/// Error returned by `pa_check_not_paused`, which is generated by `#[derive(Pausable)]` as
+/// inherent method taking a feature key. It enables contract code to degrade gracefully instead of
+/// panicking the way `#[pause]` does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PausableError {
+    /// The feature with the given key is paused, either by itself or via "ALL".
+    Paused { key: String },
+}
+
+impl std::fmt::Display for PausableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Paused { key } => write!(f, "Pausable: Feature {} is paused", key),
+        }
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 #[cfg(test)]
 mod tests {
     use crate as near_plugins;
+    use crate::pausable::PausableError;
     use crate::test_utils::get_context;
     use crate::{if_paused, pause, Ownable, Pausable};
     use std::collections::HashSet;
@@ -96,6 +186,7 @@ mod tests {
 
     use near_sdk::borsh::BorshDeserialize;
     use near_sdk::borsh::BorshSerialize;
+    use near_sdk::test_utils::get_logs;
     use near_sdk::{near_bindgen, testing_env, VMContext};
 
     #[near_bindgen]
@@ -156,6 +247,16 @@ mod tests {
 
             self.counter += 1;
         }
+
+        /// Like `increase_1`, but instead of failing while "increase_1" is paused it leaves the
+        /// counter unchanged. Returns whether the counter was increased.
+        fn try_increase_1(&mut self) -> bool {
+            if self.pa_check_not_paused("increase_1").is_err() {
+                return false;
+            }
+            self.counter += 1;
+            true
+        }
     }
 
     /// Setup basic account. Owner of the account is `dave.test`
@@ -322,6 +423,227 @@ mod tests {
         assert_eq!(counter.pa_is_paused("feature_a".to_string()), false);
     }
 
+    #[test]
+    fn test_check_not_paused() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "dave.test".to_string().try_into().unwrap();
+        testing_env!(ctx.clone());
+
+        assert_eq!(counter.pa_check_not_paused("increase_1"), Ok(()));
+        assert!(counter.try_increase_1());
+        assert_eq!(counter.counter, 1);
+
+        counter.pa_pause_feature("ALL".to_string());
+        let err = counter.pa_check_not_paused("increase_1").unwrap_err();
+        assert_eq!(
+            err,
+            PausableError::Paused {
+                key: "increase_1".to_string()
+            }
+        );
+        assert_eq!(err.to_string(), "Pausable: Feature increase_1 is paused");
+        assert!(!counter.try_increase_1());
+        assert_eq!(counter.counter, 1);
+    }
+
+    #[test]
+    fn test_paused_for() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "dave.test".to_string().try_into().unwrap();
+        ctx.block_timestamp = 100;
+        testing_env!(ctx.clone());
+
+        assert_eq!(counter.pa_paused_for("feature_a".to_string()), None);
+        counter.pa_pause_feature("feature_a".to_string());
+        assert_eq!(counter.pa_paused_for("feature_a".to_string()), Some(0));
+
+        ctx.block_timestamp = 150;
+        testing_env!(ctx.clone());
+        assert_eq!(counter.pa_paused_for("feature_a".to_string()), Some(50));
+
+        // Pausing again doesn't reset the duration.
+        counter.pa_pause_feature("feature_a".to_string());
+        assert_eq!(counter.pa_paused_for("feature_a".to_string()), Some(50));
+
+        // Pausing "ALL" applies to every feature.
+        counter.pa_pause_feature("ALL".to_string());
+        ctx.block_timestamp = 170;
+        testing_env!(ctx.clone());
+        assert_eq!(counter.pa_paused_for("feature_a".to_string()), Some(70));
+        assert_eq!(counter.pa_paused_for("feature_b".to_string()), Some(20));
+
+        counter.pa_unpause_feature("ALL".to_string());
+        counter.pa_unpause_feature("feature_a".to_string());
+        assert_eq!(counter.pa_paused_for("feature_a".to_string()), None);
+    }
+
+    #[test]
+    fn test_pause_reason() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "dave.test".to_string().try_into().unwrap();
+        testing_env!(ctx.clone());
+
+        assert_eq!(counter.pa_pause_reason("feature_a".to_string()), None);
+        counter.pa_pause_feature_with_reason("feature_a".to_string(), "Oracle outage".to_string());
+        assert!(counter.pa_is_paused("feature_a".to_string()));
+        assert_eq!(
+            counter.pa_pause_reason("feature_a".to_string()),
+            Some("Oracle outage".to_string())
+        );
+        assert!(get_logs()
+            .last()
+            .unwrap()
+            .contains(r#""reason":"Oracle outage""#));
+
+        // The reason of "ALL" is not returned for other keys.
+        counter.pa_pause_feature_with_reason("ALL".to_string(), "Upgrade".to_string());
+        assert_eq!(counter.pa_pause_reason("feature_b".to_string()), None);
+
+        // Pausing without a reason clears the reason.
+        counter.pa_pause_feature("feature_a".to_string());
+        assert_eq!(counter.pa_pause_reason("feature_a".to_string()), None);
+        assert!(!get_logs().last().unwrap().contains("reason"));
+
+        counter.pa_pause_feature_with_reason("feature_a".to_string(), "Exploit".to_string());
+        counter.pa_unpause_feature("feature_a".to_string());
+        assert_eq!(counter.pa_pause_reason("feature_a".to_string()), None);
+        assert_eq!(
+            counter.pa_pause_reason("ALL".to_string()),
+            Some("Upgrade".to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"Ownable: Method must be called from owner"#)]
+    fn test_pause_with_reason_only_owner() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "mallory.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+
+        counter.pa_pause_feature_with_reason("increase_1".to_string(), "Oracle outage".to_string());
+    }
+
+    #[test]
+    fn test_pause_feature_until() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "dave.test".to_string().try_into().unwrap();
+        ctx.block_timestamp = 100;
+        testing_env!(ctx.clone());
+
+        counter.pa_pause_feature_until("feature_a".to_string(), 150);
+        assert!(counter.pa_is_paused("feature_a".to_string()));
+        assert_eq!(counter.pa_unpause_at("feature_a".to_string()), Some(150));
+        assert!(get_logs()
+            .last()
+            .unwrap()
+            .contains(r#""unpause_at_ns":150"#));
+
+        ctx.block_timestamp = 150;
+        testing_env!(ctx.clone());
+        assert!(counter.pa_is_paused("feature_a".to_string()));
+
+        ctx.block_timestamp = 151;
+        testing_env!(ctx.clone());
+        assert!(!counter.pa_is_paused("feature_a".to_string()));
+        assert_eq!(counter.pa_all_paused(), None);
+        assert_eq!(counter.pa_paused_for("feature_a".to_string()), None);
+        assert_eq!(counter.pa_unpause_at("feature_a".to_string()), None);
+
+        // Pausing again starts a new pause.
+        counter.pa_pause_feature("feature_a".to_string());
+        assert_eq!(counter.pa_paused_for("feature_a".to_string()), Some(0));
+        assert_eq!(counter.pa_unpause_at("feature_a".to_string()), None);
+    }
+
+    #[test]
+    fn test_pause_feature_until_past() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "dave.test".to_string().try_into().unwrap();
+        ctx.block_timestamp = 100;
+        testing_env!(ctx.clone());
+
+        counter.pa_pause_feature_until("increase_1".to_string(), 50);
+        assert!(!counter.pa_is_paused("increase_1".to_string()));
+
+        ctx.predecessor_account_id = "rick.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+
+        counter.increase_1();
+        assert_eq!(counter.counter, 1);
+    }
+
+    #[test]
+    fn test_unpause_before_scheduled_time() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "dave.test".to_string().try_into().unwrap();
+        ctx.block_timestamp = 100;
+        testing_env!(ctx.clone());
+
+        counter.pa_pause_feature_until("feature_a".to_string(), 200);
+        counter.pa_unpause_feature("feature_a".to_string());
+        assert!(!counter.pa_is_paused("feature_a".to_string()));
+        assert_eq!(counter.pa_unpause_at("feature_a".to_string()), None);
+    }
+
+    #[test]
+    fn test_pause_all() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "dave.test".to_string().try_into().unwrap();
+        testing_env!(ctx.clone());
+
+        counter.pa_pause_feature("feature_a".to_string());
+        counter.pa_pause_all();
+        assert_eq!(
+            counter.pa_all_paused(),
+            Some(HashSet::from(["feature_a".to_string(), "ALL".to_string()]))
+        );
+        // Features without a registered key are covered as well.
+        assert!(counter.pa_is_paused("feature_added_later".to_string()));
+
+        counter.pa_unpause_all();
+        assert_eq!(counter.pa_all_paused(), None);
+        assert!(!counter.pa_is_paused("feature_a".to_string()));
+        let logs = get_logs();
+        let unpause_logs = &logs[logs.len() - 2..];
+        assert!(unpause_logs[0].contains(r#""key":"ALL""#));
+        assert!(unpause_logs[1].contains(r#""key":"feature_a""#));
+    }
+
+    #[test]
+    #[should_panic(expected = r#"Pausable: Method is paused"#)]
+    fn test_pause_all_pauses_methods() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "dave.test".to_string().try_into().unwrap();
+        testing_env!(ctx.clone());
+
+        counter.pa_pause_all();
+
+        ctx.predecessor_account_id = "rick.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+
+        counter.increase_1();
+    }
+
+    #[test]
+    #[should_panic(expected = r#"Ownable: Method must be called from owner"#)]
+    fn test_unpause_all_only_owner() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "mallory.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+
+        counter.pa_unpause_all();
+    }
+
     #[test]
     fn test_pause_custom_name_ok() {
         let (mut counter, mut ctx) = setup_basic();
@@ -447,4 +769,32 @@ mod tests {
         counter.decrease_1();
         assert_eq!(counter.counter, 0);
     }
+
+    #[test]
+    fn test_maintenance_message() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "dave.test".to_string().try_into().unwrap();
+        testing_env!(ctx.clone());
+
+        assert_eq!(counter.pa_maintenance_message(), None);
+        counter.pa_set_maintenance_message(Some("Oracle outage".to_string()));
+        assert_eq!(
+            counter.pa_maintenance_message(),
+            Some("Oracle outage".to_string())
+        );
+        counter.pa_set_maintenance_message(None);
+        assert_eq!(counter.pa_maintenance_message(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"Ownable: Method must be called from owner"#)]
+    fn test_maintenance_message_only_owner() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "mallory.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+
+        counter.pa_set_maintenance_message(Some("Oracle outage".to_string()));
+    }
 }
@@ -0,0 +1,60 @@
+//! # Read-only mode
+//!
+//! A global flag which puts the whole contract in read-only mode, e.g. while
+//! taking a snapshot of its state. It is broader than pausing features:
+//! while it is set, mutating methods of `AccessControllable`, `Pausable`,
+//! `Upgradable`, `Ownable` and `FullAccessKeyFallback` panic, whereas views
+//! keep working.
+//!
+//! The flag is controlled by super-admins via
+//! [`AccessControllable::acl_set_read_only`], which remains callable in
+//! read-only mode to leave it again. The callback of
+//! `attach_full_access_key` is not blocked, since the key was added already.
+//!
+//! [`AccessControllable::acl_set_read_only`]: crate::AccessControllable::acl_set_read_only
+use near_sdk::env;
+
+/// Storage key of the flag. It is set if and only if the contract is in
+/// read-only mode.
+pub const READ_ONLY_STORAGE_KEY: &[u8] = b"__READ_ONLY__";
+
+/// Returns whether the contract is in read-only mode.
+pub fn is_read_only() -> bool {
+    env::storage_has_key(READ_ONLY_STORAGE_KEY)
+}
+
+/// Enters or leaves read-only mode. It does not check permissions.
+pub fn set_read_only(read_only: bool) {
+    if read_only {
+        env::storage_write(READ_ONLY_STORAGE_KEY, &[]);
+    } else {
+        env::storage_remove(READ_ONLY_STORAGE_KEY);
+    }
+}
+
+/// Panics if the contract is in read-only mode.
+pub fn assert_not_read_only() {
+    assert!(!is_read_only(), "Contract is in read-only mode");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_only() {
+        assert!(!is_read_only());
+        assert_not_read_only();
+        set_read_only(true);
+        assert!(is_read_only());
+        set_read_only(false);
+        assert!(!is_read_only());
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is in read-only mode")]
+    fn test_assert_not_read_only() {
+        set_read_only(true);
+        assert_not_read_only();
+    }
+}
@@ -7,7 +7,39 @@
 //! ## Default implementation:
 //!
 //! Only owner or self can call `stage_code` and `deploy_code`.
-//! There is no timer or staging duration implemented by default.
+//!
+//! Alternatively, staging and deploying can be restricted to grantees of roles managed by
+//! `AccessControllable`, e.g. to let a deployer role stage code which only a more privileged role
+//! may deploy:
+//!
+//! ```ignore
+//! #[derive(Upgradable)]
+//! #[upgradable(stage_roles(Role::Deployer), deploy_roles(Role::DAO))]
+//! struct Contract { /* ... */ }
+//! ```
+//!
+//! Each list replaces the owner check of the respective methods, which is kept if its list is
+//! omitted.
+//!
+//! Owner can set a staging duration via `up_set_staging_duration`, which is zero by default. Staged
+//! code can be deployed only once the staging duration has passed since it was staged. This
+//! timelock lets users exit the contract if they disagree with an announced upgrade. The duration
+//! is applied when staging code, hence changing it does not affect code that is already staged.
+//! Increasing the duration takes effect immediately, whereas a reduction takes effect only once
+//! the current duration has passed. Otherwise the owner could remove the duration, then stage and
+//! deploy code at once. Code staged without a [`DelayStatus`], e.g. by an earlier version of this
+//! plugin, is treated as if it was staged at the time of deployment, hence it must be staged again
+//! unless the duration is zero.
+//!
+//! The duration is stored in slot b"__STAGING_DURATION__", a pending reduction in slot
+//! b"__STAGING_DURATION_UPDATE__" and the [`DelayStatus`] of staged code in slot
+//! b"__DELAY_STATUS__" by default.
+//!
+//! Staging and deploying code panics while the contract is in read-only mode, see
+//! [`crate::read_only`].
+//!
+//! To run a migration atomically with the deployment, use `up_deploy_code_and_call`, which batches
+//! the deployment with a function call of the new code.
 //!
 //! ## Security concerns:
 //!
@@ -20,7 +52,9 @@
 //! After the code is deployed, it should be removed from staging. This will prevent an old code
 //! with a security vulnerability to be deployed, in case it was upgraded using other mechanism.
 use crate::events::{AsEvent, EventMetadata};
-use near_sdk::{AccountId, CryptoHash, Promise};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::Base58CryptoHash;
+use near_sdk::{AccountId, CryptoHash, Gas, Promise};
 use serde::Serialize;
 
 pub trait Upgradable {
@@ -35,11 +69,59 @@ pub trait Upgradable {
     /// Returns staged code.
     fn up_staged_code(&self) -> Option<Vec<u8>>;
 
-    /// Returns hash of the staged code
-    fn up_staged_code_hash(&self) -> Option<CryptoHash>;
+    /// Returns the SHA-256 hash of the staged code, e.g. to verify it matches a reviewed build
+    /// without downloading the code. Returns `None` if no code is staged.
+    fn up_staged_code_hash(&self) -> Option<Base58CryptoHash>;
+
+    /// Allows authorized account to set the staging duration in nanoseconds, which applies to code
+    /// staged afterwards.
+    ///
+    /// An increase takes effect immediately. A reduction takes effect only once the current
+    /// staging duration has passed, until then it is returned by
+    /// `up_get_pending_staging_duration`. Setting the duration again replaces a pending reduction.
+    fn up_set_staging_duration(&mut self, staging_duration: u64);
+
+    /// Returns the staging duration in effect, in nanoseconds.
+    fn up_get_staging_duration(&self) -> u64;
 
-    /// Allows authorized account to deploy staged code. If no code is staged the method fails.
+    /// Returns the reduction of the staging duration which does not yet take effect, if any.
+    fn up_get_pending_staging_duration(&self) -> Option<StagingDurationUpdate>;
+
+    /// Returns when the staged code was staged and when it may be deployed. Returns `None` if no
+    /// code is staged.
+    fn up_get_delay_status(&self) -> Option<DelayStatus>;
+
+    /// Allows authorized account to deploy staged code. If no code is staged or the staging
+    /// duration has not passed yet, the method fails.
     fn up_deploy_code(&mut self) -> Promise;
+
+    /// Like `up_deploy_code`, but additionally calls `function_name` of the deployed code with
+    /// `args`, attaching `gas` and no deposit, e.g. to migrate state. Both actions are executed in
+    /// a single receipt, hence the deployment is reverted if the call fails.
+    fn up_deploy_code_and_call(
+        &mut self,
+        function_name: String,
+        args: Vec<u8>,
+        gas: Gas,
+    ) -> Promise;
+}
+
+/// Timing of staged code, as block timestamps in nanoseconds.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct DelayStatus {
+    /// When the code was staged.
+    pub staged_at: u64,
+    /// From when on the code may be deployed.
+    pub deploy_allowed_at: u64,
+}
+
+/// Reduction of the staging duration set via [`Upgradable::up_set_staging_duration`].
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct StagingDurationUpdate {
+    /// The reduced staging duration in nanoseconds.
+    pub staging_duration: u64,
+    /// Block timestamp in nanoseconds from when on the reduced duration takes effect.
+    pub apply_at: u64,
 }
 
 /// Event emitted when the code is staged
@@ -84,8 +166,10 @@ mod tests {
     // TODO: Make simulation test that verifies code is deployed
     use crate as near_plugins;
     use crate::test_utils::get_context;
+    use crate::upgradable::{DelayStatus, StagingDurationUpdate};
     use crate::{Ownable, Upgradable};
     use near_sdk::env::sha256;
+    use near_sdk::CryptoHash;
     use near_sdk::{near_bindgen, testing_env, VMContext};
     use std::convert::TryInto;
 
@@ -132,11 +216,155 @@ mod tests {
 
         assert_eq!(counter.up_staged_code(), Some(vec![1]));
 
+        let hash: CryptoHash = sha256(vec![1].as_slice()).try_into().unwrap();
+        assert_eq!(counter.up_staged_code_hash(), Some(hash.into()));
+
+        counter.up_deploy_code();
+    }
+
+    #[test]
+    fn test_staged_code_hash() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "eli.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+
+        assert_eq!(counter.up_staged_code_hash(), None);
+        counter.up_stage_code(b"abc".to_vec());
+
+        // SHA-256 of "abc", encoded in base58.
+        let expected = "DYu3G8aGTMBW1WrTw76zxQJQU4DHLw9MLyy7peG4LKkY";
+        let hash = counter.up_staged_code_hash().unwrap();
+        assert_eq!(String::from(&hash), expected);
+
+        counter.up_stage_code(vec![]);
+        assert_eq!(counter.up_staged_code_hash(), None);
+    }
+
+    #[test]
+    fn test_deploy_code_after_staging_duration() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "eli.test".to_string().try_into().unwrap();
+        ctx.block_timestamp = 100;
+        testing_env!(ctx.clone());
+
+        counter.up_set_staging_duration(50);
+        assert_eq!(counter.up_get_staging_duration(), 50);
+        assert_eq!(counter.up_get_delay_status(), None);
+        counter.up_stage_code(vec![1]);
+        assert_eq!(
+            counter.up_get_delay_status(),
+            Some(DelayStatus {
+                staged_at: 100,
+                deploy_allowed_at: 150,
+            })
+        );
+
+        // Reducing the duration does not affect staged code.
+        counter.up_set_staging_duration(0);
+        assert_eq!(
+            counter.up_get_delay_status().unwrap().deploy_allowed_at,
+            150
+        );
+
+        ctx.block_timestamp = 150;
+        testing_env!(ctx);
+        counter.up_deploy_code();
+    }
+
+    #[test]
+    #[should_panic(expected = r#"Upgradable: Deploy code too early: staging duration not passed"#)]
+    fn test_deploy_code_before_staging_duration() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "eli.test".to_string().try_into().unwrap();
+        ctx.block_timestamp = 100;
+        testing_env!(ctx.clone());
+
+        counter.up_set_staging_duration(50);
+        counter.up_stage_code(vec![1]);
+
+        ctx.block_timestamp = 149;
+        testing_env!(ctx);
+        counter.up_deploy_code();
+    }
+
+    #[test]
+    fn test_reduce_staging_duration_after_current_duration() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "eli.test".to_string().try_into().unwrap();
+        ctx.block_timestamp = 100;
+        testing_env!(ctx.clone());
+
+        counter.up_set_staging_duration(50);
+        counter.up_set_staging_duration(10);
+        assert_eq!(counter.up_get_staging_duration(), 50);
         assert_eq!(
-            counter.up_staged_code_hash(),
-            Some(sha256(vec![1].as_slice()).try_into().unwrap())
+            counter.up_get_pending_staging_duration(),
+            Some(StagingDurationUpdate {
+                staging_duration: 10,
+                apply_at: 150,
+            })
         );
 
+        // Code staged meanwhile is subject to the current duration.
+        counter.up_stage_code(vec![1]);
+        assert_eq!(
+            counter.up_get_delay_status().unwrap().deploy_allowed_at,
+            150
+        );
+
+        ctx.block_timestamp = 150;
+        testing_env!(ctx.clone());
+        assert_eq!(counter.up_get_staging_duration(), 10);
+        assert_eq!(counter.up_get_pending_staging_duration(), None);
+
+        // A further reduction is subject to the reduced duration.
+        counter.up_set_staging_duration(0);
+        assert_eq!(counter.up_get_staging_duration(), 10);
+        ctx.block_timestamp = 160;
+        testing_env!(ctx);
+        assert_eq!(counter.up_get_staging_duration(), 0);
+    }
+
+    #[test]
+    fn test_increase_staging_duration_immediately() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "eli.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+
+        counter.up_set_staging_duration(50);
+        counter.up_set_staging_duration(0);
+        assert!(counter.up_get_pending_staging_duration().is_some());
+
+        // An increase takes effect immediately and discards the pending reduction.
+        counter.up_set_staging_duration(80);
+        assert_eq!(counter.up_get_staging_duration(), 80);
+        assert_eq!(counter.up_get_pending_staging_duration(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"Upgradable: Deploy code too early: staging duration not passed"#)]
+    fn test_deploy_code_without_delay_status() {
+        let (mut counter, mut ctx) = setup_basic();
+
+        ctx.predecessor_account_id = "eli.test".to_string().try_into().unwrap();
+        testing_env!(ctx);
+
+        // Code staged without a delay status is treated as staged right now.
+        counter.up_set_staging_duration(50);
+        near_sdk::env::storage_write(counter.up_storage_key().as_ref(), &[1]);
+        assert_eq!(counter.up_get_delay_status(), None);
         counter.up_deploy_code();
     }
+
+    #[test]
+    #[should_panic(expected = r#"Ownable: Method must be called from owner"#)]
+    fn test_set_staging_duration_not_owner() {
+        let (mut counter, _) = setup_basic();
+        counter.up_set_staging_duration(50);
+    }
 }
@@ -2,19 +2,35 @@ mod common;
 
 use common::access_controllable_contract::{AccessControllableContract, Caller};
 use common::utils::{
+    assert_acl_state_unchanged, assert_event_emitted, assert_failure_with,
     assert_insufficient_acl_permissions, assert_private_method_failure, assert_success_with,
+    fast_forward, get_block_timestamp,
+};
+use near_plugins::access_controllable::{
+    AclHealth, AclManifest, AclResult, HealthWarning, MembershipChangeResult, SuperAdminTransfer,
+    ACL_GRANT_ROLES_MAX_LEN, ACL_MAX_LIMIT, ACL_REVOKE_BATCH_MAX_LEN, ACL_STORAGE_VERSION,
+    ACL_SUPER_ADMIN_ROLE_MARKER,
 };
 use near_sdk::serde_json::json;
 use std::convert::TryFrom;
 use std::path::Path;
 use workspaces::network::Sandbox;
 use workspaces::result::ExecutionFinalResult;
-use workspaces::{Account, Contract, Worker};
+use workspaces::{Account, AccountId, Contract, Worker};
 
 const PROJECT_PATH: &str = "./tests/contracts/access_controllable";
 
+/// Mock registry used to resolve handles to account ids.
+const HANDLE_REGISTRY_PATH: &str = "./tests/contracts/handle_registry";
+
+/// Mock contract recording notifications about revoked roles.
+const NOTIFICATION_RECEIVER_PATH: &str = "./tests/contracts/notification_receiver";
+
+/// Contract setting optional arguments of `#[access_control]`.
+const OPTIONS_PROJECT_PATH: &str = "./tests/contracts/access_controllable_options";
+
 /// All roles which are defined in the contract in [`PROJECT_PATH`].
-const ALL_ROLES: [&str; 3] = ["LevelA", "LevelB", "LevelC"];
+const ALL_ROLES: [&str; 4] = ["LevelA", "LevelB", "LevelC", "LevelD"];
 
 /// Bundles resources required in tests.
 struct Setup {
@@ -75,11 +91,10 @@ impl Setup {
 }
 
 /// Represents the outcome of a transaction sent to the [`PROJECT_PATH`]
-/// contract.
-// TODO generic `T` instead of `String`
+/// contract, which returns a value of type `T` on success.
 #[derive(Debug)]
-enum TxOutcome {
-    Success(String),
+enum TxOutcome<T> {
+    Success(T),
     AclFailure(AclFailure),
 }
 
@@ -93,18 +108,20 @@ struct AclFailure {
     result: ExecutionFinalResult,
 }
 
-impl TxOutcome {
-    fn assert_success(&self, expected: String) {
+impl<T: PartialEq + Debug> TxOutcome<T> {
+    fn assert_success(&self, expected: T) {
         let got = match self {
-            TxOutcome::Success(got) => got.clone(),
+            TxOutcome::Success(got) => got,
             TxOutcome::AclFailure(failure) => panic!(
                 "Expected transaction success but it failed with: {:?}",
                 failure
             ),
         };
-        assert_eq!(got, expected);
+        assert_eq!(got, &expected);
     }
+}
 
+impl<T> TxOutcome<T> {
     fn assert_acl_failure(&self) {
         let failure = match self {
             TxOutcome::Success(_) => panic!("Expected transaction failure"),
@@ -118,27 +135,51 @@ impl TxOutcome {
     }
 }
 
-async fn call_restricted_greeting(
+/// Calls `method_name` without arguments, which is restricted to
+/// `allowed_roles`, and deserializes its return value in case of success.
+async fn call_restricted<T: DeserializeOwned>(
     contract: &Contract,
     caller: &Account,
-) -> anyhow::Result<TxOutcome> {
+    method_name: &str,
+    allowed_roles: &[&str],
+) -> anyhow::Result<TxOutcome<T>> {
     let res = caller
-        .call(contract.id(), "restricted_greeting")
+        .call(contract.id(), method_name)
         .args_json(())
         .max_gas()
         .transact()
         .await?;
     let tx_outcome = match res.is_success() {
-        true => TxOutcome::Success(res.into_result().unwrap().json::<String>().unwrap()),
+        true => TxOutcome::Success(res.into_result().unwrap().json::<T>()?),
         false => TxOutcome::AclFailure(AclFailure {
-            method_name: "restricted_greeting".to_string(),
-            allowed_roles: vec!["LevelA".to_string(), "LevelC".to_string()],
+            method_name: method_name.to_string(),
+            allowed_roles: allowed_roles.iter().map(|&role| role.to_string()).collect(),
             result: res,
         }),
     };
     Ok(tx_outcome)
 }
 
+async fn call_restricted_greeting(
+    contract: &Contract,
+    caller: &Account,
+) -> anyhow::Result<TxOutcome<String>> {
+    call_restricted(
+        contract,
+        caller,
+        "restricted_greeting",
+        &["LevelA", "LevelC"],
+    )
+    .await
+}
+
+async fn call_restricted_action(
+    contract: &Contract,
+    caller: &Account,
+) -> anyhow::Result<TxOutcome<String>> {
+    call_restricted(contract, caller, "restricted_action", &["LevelB"]).await
+}
+
 /// Smoke test of contract setup and basic functionality.
 #[tokio::test]
 async fn test_set_and_get_status() -> anyhow::Result<()> {
@@ -204,6 +245,13 @@ async fn test_acl_init_super_admin() -> anyhow::Result<()> {
         ..
     } = Setup::new().await?;
 
+    // Only the contract itself may call `acl_init_super_admin`, which is
+    // generated due to `bootstrap`.
+    let res = contract
+        .acl_init_super_admin(account.clone().into(), account.id())
+        .await?;
+    assert_private_method_failure(res, "acl_init_super_admin");
+
     // Calling `acl_init_super_admin` after initialization adds super-admin.
     contract
         .assert_acl_is_super_admin(false, account.id())
@@ -300,6 +348,210 @@ async fn test_acl_revoke_super_admin_unchecked() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_acl_revoke_super_admin() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let super_admin = setup.new_super_admin_account().await?;
+    let revoker = setup.new_super_admin_account().await?;
+
+    // Revoking is a no-op if the caller is not a super-admin.
+    let res = setup
+        .contract
+        .acl_revoke_super_admin(setup.account.clone().into(), super_admin.id(), None)
+        .await?;
+    assert_success_with(res, None::<bool>);
+    setup
+        .contract
+        .assert_acl_is_super_admin(true, super_admin.id())
+        .await;
+
+    // The reason is included in the event.
+    let reason = "compromised key";
+    let res = setup
+        .contract
+        .acl_revoke_super_admin(revoker.clone().into(), super_admin.id(), Some(reason))
+        .await?;
+    let event = res
+        .logs()
+        .into_iter()
+        .find_map(|log| log.strip_prefix("EVENT_JSON:"))
+        .map(|event| near_sdk::serde_json::from_str::<near_sdk::serde_json::Value>(event))
+        .expect("Event should have been emitted")?;
+    assert_eq!(event["event"], "super_admin_revoked");
+    assert_eq!(
+        event["data"],
+        json!({
+            "account": super_admin.id(),
+            "by": revoker.id(),
+            "reason": reason,
+        })
+    );
+    assert_success_with(res, Some(true));
+    setup
+        .contract
+        .assert_acl_is_super_admin(false, super_admin.id())
+        .await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_last_super_admin() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let super_admin = setup.new_super_admin_account().await?;
+    let other = setup.new_super_admin_account().await?;
+    assert_eq!(contract.acl_num_super_admins().await?, 2);
+
+    // Revoking one of two super-admins succeeds.
+    let res = contract
+        .acl_revoke_super_admin(super_admin.clone().into(), other.id(), None)
+        .await?;
+    assert_success_with(res, Some(true));
+    assert_eq!(contract.acl_num_super_admins().await?, 1);
+
+    // The sole super-admin can neither be revoked nor renounce.
+    let res = contract
+        .acl_revoke_super_admin(super_admin.clone().into(), super_admin.id(), None)
+        .await?;
+    assert_success_with(res, None::<bool>);
+    let res = contract
+        .acl_renounce_super_admin(super_admin.clone().into())
+        .await?;
+    assert_success_with(res, false);
+    contract
+        .assert_acl_is_super_admin(true, super_admin.id())
+        .await;
+    assert_eq!(contract.acl_num_super_admins().await?, 1);
+
+    // Renouncing succeeds while another super-admin remains.
+    let other = setup.new_super_admin_account().await?;
+    let res = contract
+        .acl_renounce_super_admin(super_admin.clone().into())
+        .await?;
+    assert_success_with(res, true);
+    contract
+        .assert_acl_is_super_admin(false, super_admin.id())
+        .await;
+    contract.assert_acl_is_super_admin(true, other.id()).await;
+
+    Ok(())
+}
+
+/// Returns the events emitted by `res`.
+fn events(res: &ExecutionFinalResult) -> Vec<near_sdk::serde_json::Value> {
+    res.logs()
+        .into_iter()
+        .filter_map(|log| log.strip_prefix("EVENT_JSON:"))
+        .filter_map(|event| {
+            near_sdk::serde_json::from_str::<near_sdk::serde_json::Value>(event).ok()
+        })
+        .collect()
+}
+
+/// Returns the names of the events emitted by `res`.
+fn event_names(res: &ExecutionFinalResult) -> Vec<String> {
+    events(res)
+        .into_iter()
+        .filter_map(|event| event["event"].as_str().map(String::from))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_acl_super_admin_transfer() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let super_admin = setup.new_super_admin_account().await?;
+    let target = setup.worker.dev_create_account().await?;
+    let other = setup.worker.dev_create_account().await?;
+
+    // Only super-admins may propose a transfer.
+    let res = contract
+        .acl_propose_super_admin_transfer(setup.account.clone().into(), target.id())
+        .await?;
+    assert_success_with(res, None::<bool>);
+    assert_eq!(contract.acl_get_pending_super_admin_transfer().await?, None);
+
+    // A pending proposal can be overwritten and cancelled.
+    let res = contract
+        .acl_propose_super_admin_transfer(super_admin.clone().into(), other.id())
+        .await?;
+    assert!(event_names(&res).contains(&"super_admin_transfer_proposed".to_string()));
+    assert_success_with(res, Some(true));
+    let res = contract
+        .acl_propose_super_admin_transfer(super_admin.clone().into(), target.id())
+        .await?;
+    // Overwriting a proposal cancels it, which lets indexers follow the chain
+    // of transfers.
+    let emitted = events(&res);
+    assert_eq!(
+        event_names(&res),
+        vec![
+            "super_admin_transfer_cancelled".to_string(),
+            "super_admin_transfer_proposed".to_string(),
+        ]
+    );
+    assert_eq!(emitted[0]["data"]["to"], other.id().as_str());
+    assert_eq!(emitted[0]["data"]["by"], super_admin.id().as_str());
+    assert_eq!(emitted[1]["data"]["from"], super_admin.id().as_str());
+    assert_eq!(emitted[1]["data"]["to"], target.id().as_str());
+    assert_eq!(emitted[1]["data"]["by"], super_admin.id().as_str());
+    assert_success_with(res, Some(true));
+    assert_eq!(
+        contract.acl_get_pending_super_admin_transfer().await?,
+        Some(SuperAdminTransfer {
+            from: super_admin.id().as_str().parse().unwrap(),
+            to: target.id().as_str().parse().unwrap(),
+        })
+    );
+    let res = contract
+        .acl_accept_super_admin_transfer(other.clone().into())
+        .await?;
+    assert_success_with(res, false);
+    let res = contract
+        .acl_cancel_super_admin_transfer(super_admin.clone().into())
+        .await?;
+    let emitted = events(&res);
+    assert_eq!(
+        event_names(&res),
+        vec!["super_admin_transfer_cancelled".to_string()]
+    );
+    assert_eq!(emitted[0]["data"]["to"], target.id().as_str());
+    assert_eq!(emitted[0]["data"]["by"], super_admin.id().as_str());
+    assert_success_with(res, Some(true));
+    let res = contract
+        .acl_cancel_super_admin_transfer(super_admin.clone().into())
+        .await?;
+    assert_success_with(res, Some(false));
+    let res = contract
+        .acl_accept_super_admin_transfer(target.clone().into())
+        .await?;
+    assert_success_with(res, false);
+
+    // The proposer retains its permissions until the target accepts.
+    contract
+        .acl_propose_super_admin_transfer(super_admin.clone().into(), target.id())
+        .await?
+        .into_result()?;
+    contract
+        .assert_acl_is_super_admin(true, super_admin.id())
+        .await;
+    contract.assert_acl_is_super_admin(false, target.id()).await;
+
+    let res = contract
+        .acl_accept_super_admin_transfer(target.clone().into())
+        .await?;
+    assert!(event_names(&res).contains(&"super_admin_transfer_accepted".to_string()));
+    assert_success_with(res, true);
+    contract
+        .assert_acl_is_super_admin(false, super_admin.id())
+        .await;
+    contract.assert_acl_is_super_admin(true, target.id()).await;
+    assert_eq!(contract.acl_get_pending_super_admin_transfer().await?, None);
+
+    Ok(())
+}
+
 /// Verify that a super-admin is admin for every role.
 #[tokio::test]
 async fn test_super_admin_is_any_admin() -> anyhow::Result<()> {
@@ -531,6 +783,69 @@ async fn test_acl_revoke_admin() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_role_bitflag() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let bitflag = |roles: Vec<&str>| {
+        let contract = setup.contract.contract();
+        async move {
+            let res = contract
+                .view("role_bitflag")
+                .args_json(json!({ "roles": roles }))
+                .await?;
+            Ok::<_, anyhow::Error>(res.json::<near_sdk::json_types::U128>()?.0)
+        }
+    };
+
+    assert_eq!(bitflag(vec![]).await?, 0);
+    assert_eq!(bitflag(vec!["LevelA"]).await?, 1 << 1);
+    assert_eq!(bitflag(vec!["LevelA", "LevelC"]).await?, 1 << 1 | 1 << 5);
+    assert_eq!(
+        bitflag(vec!["LevelC", "LevelA", "LevelC"]).await?,
+        1 << 1 | 1 << 5
+    );
+
+    let res = bitflag(vec!["LevelA", "Unknown"]).await;
+    assert!(format!("{:?}", res.unwrap_err()).contains("Value does not correspond to a variant"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_is_admin_of_any() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let admin = setup.new_account_as_admin(&["LevelB"]).await?;
+    let super_admin = setup.new_super_admin_account().await?;
+
+    assert!(
+        contract
+            .acl_is_admin_of_any(&["LevelA", "LevelB"], admin.id())
+            .await?
+    );
+    assert!(
+        !contract
+            .acl_is_admin_of_any(&["LevelA", "LevelC"], admin.id())
+            .await?
+    );
+    assert!(
+        !contract
+            .acl_is_admin_of_any(&["LevelB"], setup.account.id())
+            .await?
+    );
+    assert!(
+        contract
+            .acl_is_admin_of_any(&["LevelA"], super_admin.id())
+            .await?
+    );
+
+    // An empty list of roles yields `false`, even for super-admins.
+    assert!(!contract.acl_is_admin_of_any(&[], admin.id()).await?);
+    assert!(!contract.acl_is_admin_of_any(&[], super_admin.id()).await?);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_acl_renounce_admin() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
@@ -539,7 +854,7 @@ async fn test_acl_renounce_admin() -> anyhow::Result<()> {
     // An account which is isn't admin calls `acl_renounce_admin`.
     let res = setup
         .contract
-        .acl_renounce_admin(setup.account.clone().into(), role)
+        .acl_renounce_admin(setup.account.clone().into(), role, None)
         .await?;
     assert_eq!(res, false);
 
@@ -551,7 +866,7 @@ async fn test_acl_renounce_admin() -> anyhow::Result<()> {
         .await;
     let res = setup
         .contract
-        .acl_renounce_admin(admin.clone().into(), role)
+        .acl_renounce_admin(admin.clone().into(), role, None)
         .await?;
     assert_eq!(res, true);
     setup
@@ -562,6 +877,40 @@ async fn test_acl_renounce_admin() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_acl_renounce_admin_event() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "LevelC";
+    let renounce = |account: Account| {
+        let contract_id = setup.contract.contract().id().clone();
+        async move {
+            account
+                .call(&contract_id, "acl_renounce_admin")
+                .args_json(json!({ "role": role }))
+                .max_gas()
+                .transact()
+                .await
+        }
+    };
+
+    // No event is emitted if the predecessor isn't an admin.
+    let res = renounce(setup.account.clone()).await?;
+    assert!(events(&res).is_empty());
+    assert_success_with(res, false);
+
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let res = renounce(admin.clone()).await?;
+    let events = events(&res);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["event"], "admin_revoked");
+    assert_eq!(events[0]["data"]["role"], role);
+    assert_eq!(events[0]["data"]["account"], admin.id().as_str());
+    assert_eq!(events[0]["data"]["by"], admin.id().as_str());
+    assert_success_with(res, true);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_acl_revoke_admin_unchecked() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
@@ -795,6 +1144,40 @@ async fn test_acl_renounce_role() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_acl_renounce_role_event() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "LevelC";
+    let renounce = |account: Account| {
+        let contract_id = setup.contract.contract().id().clone();
+        async move {
+            account
+                .call(&contract_id, "acl_renounce_role")
+                .args_json(json!({ "role": role }))
+                .max_gas()
+                .transact()
+                .await
+        }
+    };
+
+    // No event is emitted if the predecessor isn't a grantee.
+    let res = renounce(setup.account.clone()).await?;
+    assert!(events(&res).is_empty());
+    assert_success_with(res, false);
+
+    let grantee = setup.new_account_with_roles(&[role]).await?;
+    let res = renounce(grantee.clone()).await?;
+    let events = events(&res);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["event"], "role_revoked");
+    assert_eq!(events[0]["data"]["role"], role);
+    assert_eq!(events[0]["data"]["from"], grantee.id().as_str());
+    assert_eq!(events[0]["data"]["by"], grantee.id().as_str());
+    assert_success_with(res, true);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_acl_revoke_role_unchecked() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
@@ -906,6 +1289,107 @@ async fn test_attribute_access_control_any() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_attribute_access_control_any_groups() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let raw_contract = setup.contract.contract();
+    let call = |account: Account| async move {
+        call_restricted::<String>(
+            raw_contract,
+            &account,
+            "restricted_greeting_group",
+            &["LevelB", "LevelC"],
+        )
+        .await
+    };
+
+    // Accounts which are not granted a role of the group are restricted.
+    for roles in [&[][..], &["LevelA"], &["LevelD"]] {
+        let account = setup.new_account_with_roles(roles).await?;
+        call(account).await?.assert_acl_failure();
+    }
+
+    // Grantees of any role of the group succeed.
+    for role in ["LevelB", "LevelC"] {
+        let account = setup.new_account_with_roles(&[role]).await?;
+        call(account)
+            .await?
+            .assert_success("hello managers".to_string());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_attribute_access_control_all() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let raw_contract = setup.contract.contract();
+    let expected_result = "hello all".to_string();
+    let call = |account: Account| async move {
+        call_restricted::<String>(
+            raw_contract,
+            &account,
+            "restricted_greeting_all",
+            &["LevelA", "LevelC"],
+        )
+        .await
+    };
+
+    // Accounts holding only some of the required roles are restricted.
+    let partial_roles: [&[&str]; 4] = [&[], &["LevelA"], &["LevelC"], &["LevelA", "LevelB"]];
+    for roles in partial_roles {
+        let account = setup.new_account_with_roles(roles).await?;
+        call(account).await?.assert_acl_failure();
+    }
+
+    // An admin for the required roles is restricted.
+    let admin = setup.new_account_as_admin(&["LevelA", "LevelC"]).await?;
+    call(admin).await?.assert_acl_failure();
+
+    // Accounts holding all required roles succeed.
+    let account = setup.new_account_with_roles(&["LevelA", "LevelC"]).await?;
+    call(account).await?.assert_success(expected_result.clone());
+    let account = setup
+        .new_account_with_roles(&["LevelA", "LevelB", "LevelC"])
+        .await?;
+    call(account).await?.assert_success(expected_result.clone());
+
+    // A super-admin bypasses the check.
+    let super_admin = setup.new_super_admin_account().await?;
+    call(super_admin).await?.assert_success(expected_result);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_attribute_access_control_any_message() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let raw_contract = setup.contract.contract();
+
+    // Without the role the custom message is used instead of the default one.
+    let account = setup.new_account_with_roles(&["LevelA"]).await?;
+    let res = account
+        .call(raw_contract.id(), "restricted_greeting_with_message")
+        .args_json(())
+        .max_gas()
+        .transact()
+        .await?;
+    let err = format!("{:?}", res.clone().into_result().unwrap_err());
+    assert!(!err.contains("Insufficient permissions for method"));
+    assert_failure_with(res, "only LevelB may call this");
+
+    let account = setup.new_account_with_roles(&["LevelB"]).await?;
+    let res = account
+        .call(raw_contract.id(), "restricted_greeting_with_message")
+        .args_json(())
+        .max_gas()
+        .transact()
+        .await?;
+    assert_success_with(res, "hello world".to_string());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_acl_init_super_admin_is_private() -> anyhow::Result<()> {
     let Setup {
@@ -918,6 +1402,40 @@ async fn test_acl_init_super_admin_is_private() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_acl_get_super_admins() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+
+    let super_admin_ids = vec![
+        setup.new_super_admin_account().await?,
+        setup.new_super_admin_account().await?,
+        setup.new_super_admin_account().await?,
+    ]
+    .iter()
+    .map(|account| account.id().clone())
+    .collect::<Vec<_>>();
+    // Admins of roles are not listed.
+    setup.new_account_as_admin(&["LevelA"]).await?;
+
+    let actual = contract
+        .acl_get_super_admins(setup.account.clone().into(), 0, 10)
+        .await?;
+    assert_eq!(actual, super_admin_ids);
+
+    let actual = contract
+        .acl_get_super_admins(setup.account.clone().into(), 1, 1)
+        .await?;
+    assert_eq!(actual, super_admin_ids[1..2].to_vec());
+
+    let actual = contract
+        .acl_get_super_admins(setup.account.clone().into(), 3, 1)
+        .await?;
+    assert_eq!(actual, vec![]);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_acl_get_admins() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
@@ -989,41 +1507,104 @@ async fn test_acl_get_admins() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Verify that a huge `limit` is clamped to [`ACL_MAX_LIMIT`] instead of
+/// failing the view call.
 #[tokio::test]
-async fn test_acl_get_grantees() -> anyhow::Result<()> {
+async fn test_acl_get_bearers_limit_clamped() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
     let role = "LevelA";
+    let mut grantee_ids = vec![];
+    for _ in 0..3 {
+        grantee_ids.push(setup.new_account_with_roles(&[role]).await?.id().clone());
+    }
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let max_len = usize::try_from(ACL_MAX_LIMIT).unwrap();
 
-    let grantee_ids = vec![
-        setup.new_account_with_roles(&[role]).await?,
-        setup.new_account_with_roles(&[role]).await?,
-        setup.new_account_with_roles(&[role]).await?,
-    ]
-    .iter()
-    .map(|account| account.id().clone())
-    .collect::<Vec<_>>();
+    let grantees = setup
+        .contract
+        .acl_get_grantees(setup.account.clone().into(), role, 0, u64::MAX)
+        .await?;
+    assert!(grantees.len() <= max_len);
+    assert_eq!(grantees, grantee_ids);
 
-    // Behaves as expected for limit = 0.
-    let actual = setup
+    let admins = setup
         .contract
-        .acl_get_grantees(setup.account.clone().into(), role, 0, 0)
+        .acl_get_admins(setup.account.clone().into(), role, 0, u64::MAX)
         .await?;
-    assert_eq!(actual, vec![],);
+    assert!(admins.len() <= max_len);
+    assert_eq!(admins, vec![admin.id().clone()]);
 
-    // Skip outside of the number of existing grantees.
-    let n = u64::try_from(grantee_ids.len()).unwrap();
-    let actual = setup
+    let page = setup
         .contract
-        .acl_get_grantees(setup.account.clone().into(), role, n, 1)
+        .acl_get_grantees_page(setup.account.clone().into(), role, None, u64::MAX)
         .await?;
-    assert_eq!(actual, vec![],);
+    assert!(page.items.len() <= max_len);
+    assert_eq!(page.items, grantee_ids);
+    assert_eq!(page.next, None);
 
-    // Retrieve grantees with step size 1.
-    for i in 0..3 {
-        let actual = setup
-            .contract
-            .acl_get_grantees(setup.account.clone().into(), role, i, 1)
-            .await?;
+    let all_admins = setup
+        .contract
+        .acl_get_all_admins(setup.account.clone().into(), 0, u64::MAX)
+        .await?;
+    assert!(all_admins.len() <= max_len);
+    assert!(all_admins.contains(&(role.to_string(), admin.id().clone())));
+
+    let res = setup
+        .contract
+        .acl_set_role_metadata(admin.into(), role, "charter", "link")
+        .await?;
+    assert!(res);
+    let keys = setup
+        .contract
+        .acl_get_role_metadata_keys(role, None, u64::MAX)
+        .await?;
+    assert_eq!(keys.items, vec!["charter".to_string()]);
+
+    let super_admin = setup.new_super_admin_account().await?;
+    let res = setup
+        .contract
+        .acl_emit_snapshot(super_admin.into(), 0, u64::MAX)
+        .await?;
+    assert_success_with(res, Some(false));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_get_grantees() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "LevelA";
+
+    let grantee_ids = vec![
+        setup.new_account_with_roles(&[role]).await?,
+        setup.new_account_with_roles(&[role]).await?,
+        setup.new_account_with_roles(&[role]).await?,
+    ]
+    .iter()
+    .map(|account| account.id().clone())
+    .collect::<Vec<_>>();
+
+    // Behaves as expected for limit = 0.
+    let actual = setup
+        .contract
+        .acl_get_grantees(setup.account.clone().into(), role, 0, 0)
+        .await?;
+    assert_eq!(actual, vec![],);
+
+    // Skip outside of the number of existing grantees.
+    let n = u64::try_from(grantee_ids.len()).unwrap();
+    let actual = setup
+        .contract
+        .acl_get_grantees(setup.account.clone().into(), role, n, 1)
+        .await?;
+    assert_eq!(actual, vec![],);
+
+    // Retrieve grantees with step size 1.
+    for i in 0..3 {
+        let actual = setup
+            .contract
+            .acl_get_grantees(setup.account.clone().into(), role, i, 1)
+            .await?;
         let i = usize::try_from(i).unwrap();
         let expected = grantee_ids[i..i + 1].to_vec();
         assert_eq!(actual, expected, "Mismatch at position {}", i,);
@@ -1131,3 +1712,2862 @@ async fn test_acl_revoke_role_unchecked_is_private() -> anyhow::Result<()> {
     assert_private_method_failure(res, "acl_revoke_role_unchecked");
     Ok(())
 }
+
+#[tokio::test]
+async fn test_acl_transfer_role_bulk() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "LevelA";
+    let old_a = setup.new_account_with_roles(&[role]).await?;
+    let old_b = setup.new_account_with_roles(&[role]).await?;
+    let old_c = setup.new_account_with_roles(&[role]).await?;
+    let grantee = setup.new_account_with_roles(&[role]).await?;
+    let not_grantee = setup.worker.dev_create_account().await?;
+    let new_a = setup.worker.dev_create_account().await?;
+    let new_b = setup.worker.dev_create_account().await?;
+    let new_c = setup.worker.dev_create_account().await?;
+    let mappings = [
+        (old_a.id(), new_a.id()),
+        (not_grantee.id(), new_c.id()),
+        (old_b.id(), new_b.id()),
+        (old_c.id(), grantee.id()),
+        (grantee.id(), grantee.id()),
+    ];
+
+    // Transfer is a no-op if the caller is not an admin for the role.
+    let caller = setup.new_account_as_admin(&["LevelB"]).await?;
+    let res = setup
+        .contract
+        .acl_transfer_role_bulk(caller.into(), role, &mappings)
+        .await?;
+    assert_eq!(res, None);
+    setup
+        .contract
+        .assert_acl_has_role(true, role, old_a.id())
+        .await;
+    setup
+        .contract
+        .assert_acl_has_role(false, role, new_a.id())
+        .await;
+
+    // Transfer succeeds for grantees and skips accounts without the role as
+    // well as new accounts which already hold the role.
+    let caller = setup.new_account_as_admin(&[role]).await?;
+    let res = setup
+        .contract
+        .acl_transfer_role_bulk(caller.into(), role, &mappings)
+        .await?;
+    assert_eq!(res, Some(vec![true, false, true, false, false]));
+    for account in [&old_a, &old_b, &not_grantee, &new_c] {
+        setup
+            .contract
+            .assert_acl_has_role(false, role, account.id())
+            .await;
+    }
+    for account in [&new_a, &new_b, &old_c, &grantee] {
+        setup
+            .contract
+            .assert_acl_has_role(true, role, account.id())
+            .await;
+    }
+
+    Ok(())
+}
+
+/// Verify grantees of roles listed in `#[pause(except_roles(...))]` may call a
+/// paused method.
+#[tokio::test]
+async fn test_pause_except_roles() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = setup.contract.contract();
+    let grantee = setup.new_account_with_roles(&["LevelA"]).await?;
+    let other = setup.new_account_with_roles(&["LevelB"]).await?;
+
+    // Make the contract its own owner to be able to pause it.
+    contract
+        .as_account()
+        .call(contract.id(), "owner_set")
+        .args_json(json!({ "owner": contract.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    contract
+        .as_account()
+        .call(contract.id(), "pa_pause_feature")
+        .args_json(json!({ "key": "pausable_greeting" }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    let res = other
+        .call(contract.id(), "pausable_greeting")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_failure_with(res, "Pausable: Method is paused");
+
+    let res = grantee
+        .call(contract.id(), "pausable_greeting")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_eq!(res.into_result()?.json::<String>()?, "hello pausable");
+
+    Ok(())
+}
+
+/// Verify staging and deploying code are restricted to the roles given in
+/// `#[upgradable(stage_roles(...), deploy_roles(...))]` of the contract.
+#[tokio::test]
+async fn test_upgradable_roles() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = setup.contract.contract();
+    let stager = setup.new_account_with_roles(&["LevelA"]).await?;
+    let deployer = setup.new_account_with_roles(&["LevelB"]).await?;
+    let wasm = common::repo::compile_project(&Path::new(PROJECT_PATH)).await?;
+
+    let res = deployer
+        .call(contract.id(), "up_stage_code")
+        .args_borsh(wasm.clone())
+        .max_gas()
+        .transact()
+        .await?;
+    assert_failure_with(res, "Upgradable: Staging requires one of these roles");
+    stager
+        .call(contract.id(), "up_stage_code")
+        .args_borsh(wasm)
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    let res = stager
+        .call(contract.id(), "up_deploy_code")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_failure_with(res, "Upgradable: Deploying requires one of these roles");
+    deployer
+        .call(contract.id(), "up_deploy_code")
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    Ok(())
+}
+
+/// Verify ACL mutations are blocked while the contract is paused globally,
+/// which is enabled by `block_when_paused` in the contract.
+#[tokio::test]
+async fn test_acl_blocked_when_paused() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = setup.contract.contract();
+    let role = "LevelA";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+
+    // Make the contract its own owner to be able to pause it.
+    contract
+        .as_account()
+        .call(contract.id(), "owner_set")
+        .args_json(json!({ "owner": contract.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    contract
+        .as_account()
+        .call(contract.id(), "pa_pause_feature")
+        .args_json(json!({ "key": "ALL" }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    let res = admin
+        .call(contract.id(), "acl_grant_role")
+        .args_json(json!({
+            "role": role,
+            "account_id": setup.account.id(),
+        }))
+        .max_gas()
+        .transact()
+        .await?;
+    let err = format!("{}", res.into_result().unwrap_err());
+    assert!(err.contains("AccessControllable: Method is blocked while the contract is paused"));
+    setup
+        .contract
+        .assert_acl_has_role(false, role, setup.account.id())
+        .await;
+
+    // Self-registration is blocked too.
+    let res = setup
+        .account
+        .call(contract.id(), "register")
+        .args_json(())
+        .max_gas()
+        .transact()
+        .await?;
+    let err = format!("{}", res.into_result().unwrap_err());
+    assert!(err.contains("AccessControllable: Method is blocked while the contract is paused"));
+    setup
+        .contract
+        .assert_acl_has_role(false, role, setup.account.id())
+        .await;
+
+    // After unpausing, the grant succeeds.
+    contract
+        .as_account()
+        .call(contract.id(), "pa_unpause_feature")
+        .args_json(json!({ "key": "ALL" }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let res = setup
+        .contract
+        .acl_grant_role(admin.into(), role, setup.account.id())
+        .await?;
+    assert_eq!(res, Some(true));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_get_all_admins() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+
+    let res = setup
+        .contract
+        .acl_get_all_admins(Caller::Contract, 0, 10)
+        .await?;
+    assert_eq!(res, vec![]);
+
+    let admin_c = setup.new_account_as_admin(&["LevelC"]).await?;
+    let admin_a = setup.new_account_as_admin(&["LevelA"]).await?;
+    let super_admin = setup.new_super_admin_account().await?;
+    let expected = vec![
+        ("__SUPER_ADMIN".to_string(), super_admin.id().clone()),
+        ("LevelA".to_string(), admin_a.id().clone()),
+        ("LevelC".to_string(), admin_c.id().clone()),
+    ];
+
+    let res = setup
+        .contract
+        .acl_get_all_admins(Caller::Contract, 0, 10)
+        .await?;
+    assert_eq!(res, expected);
+
+    // Pagination across roles.
+    let res = setup
+        .contract
+        .acl_get_all_admins(Caller::Contract, 1, 1)
+        .await?;
+    assert_eq!(res, expected[1..2].to_vec());
+    let res = setup
+        .contract
+        .acl_get_all_admins(Caller::Contract, 2, 10)
+        .await?;
+    assert_eq!(res, expected[2..].to_vec());
+
+    Ok(())
+}
+
+/// Verify the admin cache makes repeated admin checks within the same receipt
+/// cheaper.
+#[tokio::test]
+async fn test_admin_cache_reduces_gas() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "LevelB";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+
+    let call_is_admin_repeatedly = |cached: bool| {
+        setup
+            .account
+            .call(setup.contract.contract().id(), "is_admin_repeatedly")
+            .args_json(json!({
+                "role": role,
+                "account_id": admin.id(),
+                "repetitions": 20,
+                "cached": cached,
+            }))
+            .max_gas()
+            .transact()
+    };
+
+    let uncached = call_is_admin_repeatedly(false).await?;
+    let uncached_gas = uncached.total_gas_burnt;
+    assert_success_with(uncached, true);
+    let cached = call_is_admin_repeatedly(true).await?;
+    let cached_gas = cached.total_gas_burnt;
+    assert_success_with(cached, true);
+
+    assert!(
+        cached_gas < uncached_gas,
+        "cached: {}, uncached: {}",
+        cached_gas,
+        uncached_gas,
+    );
+
+    Ok(())
+}
+
+/// Verify failed mutations leave the ACL state unmodified.
+#[tokio::test]
+async fn test_unauthorized_grant_does_not_change_state() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    setup.new_super_admin_account().await?;
+    setup.new_account_with_roles(&["LevelA", "LevelC"]).await?;
+    let caller = setup.new_account_as_admin(&["LevelB"]).await?;
+
+    let before = setup.contract.acl_state_snapshot(&ALL_ROLES).await?;
+    let res = setup
+        .contract
+        .acl_grant_role(caller.into(), "LevelA", setup.account.id())
+        .await?;
+    assert_eq!(res, None);
+    let after = setup.contract.acl_state_snapshot(&ALL_ROLES).await?;
+    assert_acl_state_unchanged(before, after);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_grant_consumable_role() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let raw_contract = setup.contract.contract();
+    let role = "LevelB";
+    let grantee = setup.worker.dev_create_account().await?;
+
+    // Granting is a no-op if the caller is not an admin for the role.
+    let caller = setup.new_account_as_admin(&["LevelA"]).await?;
+    let res = setup
+        .contract
+        .acl_grant_consumable_role(caller.into(), role, grantee.id(), 2)
+        .await?;
+    assert_eq!(res, None);
+
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let res = setup
+        .contract
+        .acl_grant_consumable_role(admin.clone().into(), role, grantee.id(), 2)
+        .await?;
+    assert_eq!(res, Some(true));
+    let res = setup
+        .contract
+        .acl_get_remaining_uses(Caller::Contract, role, grantee.id())
+        .await?;
+    assert_eq!(res, Some(2));
+
+    // Each successful call consumes one use.
+    call_restricted_action(raw_contract, &grantee)
+        .await?
+        .assert_success("done".to_string());
+    let res = setup
+        .contract
+        .acl_get_remaining_uses(Caller::Contract, role, grantee.id())
+        .await?;
+    assert_eq!(res, Some(1));
+
+    // Consuming the last use revokes the role.
+    call_restricted_action(raw_contract, &grantee)
+        .await?
+        .assert_success("done".to_string());
+    setup
+        .contract
+        .assert_acl_has_role(false, role, grantee.id())
+        .await;
+    let res = setup
+        .contract
+        .acl_get_remaining_uses(Caller::Contract, role, grantee.id())
+        .await?;
+    assert_eq!(res, None);
+    call_restricted_action(raw_contract, &grantee)
+        .await?
+        .assert_acl_failure();
+
+    // A transferred grant keeps its remaining uses.
+    let old = setup.worker.dev_create_account().await?;
+    let new = setup.worker.dev_create_account().await?;
+    setup
+        .contract
+        .acl_grant_consumable_role(admin.clone().into(), role, old.id(), 2)
+        .await?;
+    call_restricted_action(raw_contract, &old)
+        .await?
+        .assert_success("done".to_string());
+    let res = setup
+        .contract
+        .acl_transfer_role_bulk(admin.clone().into(), role, &[(old.id(), new.id())])
+        .await?;
+    assert_eq!(res, Some(vec![true]));
+    let res = setup
+        .contract
+        .acl_get_remaining_uses(Caller::Contract, role, new.id())
+        .await?;
+    assert_eq!(res, Some(1));
+    call_restricted_action(raw_contract, &new)
+        .await?
+        .assert_success("done".to_string());
+    setup
+        .contract
+        .assert_acl_has_role(false, role, new.id())
+        .await;
+
+    // Uses of roles without a limit are not tracked.
+    let account = setup.new_account_with_roles(&[role]).await?;
+    call_restricted_action(raw_contract, &account)
+        .await?
+        .assert_success("done".to_string());
+    let res = setup
+        .contract
+        .acl_get_remaining_uses(Caller::Contract, role, account.id())
+        .await?;
+    assert_eq!(res, None);
+    setup
+        .contract
+        .assert_acl_has_role(true, role, account.id())
+        .await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_grants_role_on_success() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let raw_contract = setup.contract.contract();
+    let account = setup.worker.dev_create_account().await?;
+
+    let register = || async {
+        let res = account
+            .call(raw_contract.id(), "register")
+            .args_json(())
+            .max_gas()
+            .transact()
+            .await?;
+        let has_role_granted_event = res
+            .logs()
+            .into_iter()
+            .filter_map(|log| log.strip_prefix("EVENT_JSON:"))
+            .filter_map(|event| {
+                near_sdk::serde_json::from_str::<near_sdk::serde_json::Value>(event).ok()
+            })
+            .any(|event| event["event"] == "role_granted");
+        assert_eq!(res.into_result()?.json::<String>()?, "registered");
+        Ok::<_, anyhow::Error>(has_role_granted_event)
+    };
+
+    call_restricted_greeting(raw_contract, &account)
+        .await?
+        .assert_acl_failure();
+
+    // The first call grants the role.
+    assert!(register().await?);
+    setup
+        .contract
+        .assert_acl_has_role(true, "LevelA", account.id())
+        .await;
+    call_restricted_greeting(raw_contract, &account)
+        .await?
+        .assert_success("hello world".to_string());
+
+    // Subsequent calls succeed without granting the role again.
+    assert!(!register().await?);
+    setup
+        .contract
+        .assert_acl_has_role(true, "LevelA", account.id())
+        .await;
+
+    Ok(())
+}
+
+/// Verify that an early `return` in the body behaves as usual and the role
+/// is granted nonetheless.
+#[tokio::test]
+async fn test_grants_role_on_success_with_early_return() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let raw_contract = setup.contract.contract();
+    for (early, expected) in [(true, "returned early"), (false, "registered")] {
+        let account = setup.worker.dev_create_account().await?;
+        let res = account
+            .call(raw_contract.id(), "register_or_return_early")
+            .args_json(json!({ "early": early }))
+            .max_gas()
+            .transact()
+            .await?;
+        assert_success_with(res, expected.to_string());
+        setup
+            .contract
+            .assert_acl_has_role(true, "LevelB", account.id())
+            .await;
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_assume_role() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let raw_contract = setup.contract.contract();
+    let role = "LevelA";
+    let account = setup.worker.dev_create_account().await?;
+
+    let assume_role_and_check = || async {
+        let res = account
+            .call(raw_contract.id(), "assume_role_and_check")
+            .args_json(json!({ "role": role }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<bool>()?;
+        Ok::<_, anyhow::Error>(res)
+    };
+
+    // Without being allowed, the role cannot be assumed.
+    assert!(!assume_role_and_check().await?);
+
+    // Only admins may allow assuming a role.
+    let res = setup
+        .contract
+        .acl_allow_assume_role(account.clone().into(), role, account.id())
+        .await?;
+    assert_eq!(res, None);
+
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let res = setup
+        .contract
+        .acl_allow_assume_role(admin.clone().into(), role, account.id())
+        .await?;
+    assert_eq!(res, Some(true));
+
+    // The role is active during the call but not persisted.
+    assert!(assume_role_and_check().await?);
+    setup
+        .contract
+        .assert_acl_has_role(false, role, account.id())
+        .await;
+    call_restricted_greeting(raw_contract, &account)
+        .await?
+        .assert_acl_failure();
+
+    // Methods restricted to the role accept it within the same call.
+    let res = account
+        .call(raw_contract.id(), "assume_role_and_greet")
+        .args_json(())
+        .max_gas()
+        .transact()
+        .await?;
+    assert_success_with(res, "hello world".to_string());
+
+    let res = setup
+        .contract
+        .acl_disallow_assume_role(admin.into(), role, account.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    assert!(!assume_role_and_check().await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_revoke_role_from_all() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "LevelC";
+    let mut grantees = vec![];
+    for _ in 0..3 {
+        grantees.push(setup.new_account_with_roles(&[role]).await?);
+    }
+
+    // Revoking is a no-op if the caller is not an admin for the role.
+    let caller = setup.new_account_as_admin(&["LevelA"]).await?;
+    let res = setup
+        .contract
+        .acl_revoke_role_from_all(caller.into(), role, 2)
+        .await?;
+    assert_eq!(res, None);
+    for grantee in grantees.iter() {
+        setup
+            .contract
+            .assert_acl_has_role(true, role, grantee.id())
+            .await;
+    }
+
+    // Repeated calls revoke the role until no grantee remains.
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let mut progress = vec![];
+    for _ in 0..3 {
+        let res = setup
+            .contract
+            .acl_revoke_role_from_all(admin.clone().into(), role, 2)
+            .await?;
+        progress.push(res.map(|p| (p.removed, p.remaining)));
+    }
+    assert_eq!(progress, vec![Some((2, 1)), Some((1, 0)), Some((0, 0))]);
+    for grantee in grantees.iter() {
+        setup
+            .contract
+            .assert_acl_has_role(false, role, grantee.id())
+            .await;
+    }
+
+    // A single event reports the revocations of a call.
+    for _ in 0..2 {
+        setup.new_account_with_roles(&[role]).await?;
+    }
+    let res = admin
+        .call(setup.contract.contract().id(), "acl_revoke_role_from_all")
+        .args_json(json!({ "role": role, "limit": 10 }))
+        .max_gas()
+        .transact()
+        .await?;
+    let events: Vec<near_sdk::serde_json::Value> = res
+        .logs()
+        .into_iter()
+        .filter_map(|log| log.strip_prefix("EVENT_JSON:"))
+        .filter_map(|event| near_sdk::serde_json::from_str(event).ok())
+        .collect();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["event"], "role_cleared");
+    assert_eq!(
+        events[0]["data"],
+        json!({ "role": role, "count": 2, "by": admin.id() })
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_ensure_role_absent() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "LevelA";
+    let grantee = setup.new_account_with_roles(&[role]).await?;
+
+    // No-op if the caller is not an admin for the role.
+    let caller = setup.new_account_as_admin(&["LevelB"]).await?;
+    let res = setup
+        .contract
+        .acl_ensure_role_absent(caller.into(), role, grantee.id())
+        .await?;
+    assert_eq!(res, None);
+    setup
+        .contract
+        .assert_acl_has_role(true, role, grantee.id())
+        .await;
+
+    // The role is revoked if present.
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let res = setup
+        .contract
+        .acl_ensure_role_absent(admin.clone().into(), role, grantee.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    setup
+        .contract
+        .assert_acl_has_role(false, role, grantee.id())
+        .await;
+
+    // It is a no-op if the role is absent.
+    let res = setup
+        .contract
+        .acl_ensure_role_absent(admin.into(), role, grantee.id())
+        .await?;
+    assert_eq!(res, Some(false));
+    setup
+        .contract
+        .assert_acl_has_role(false, role, grantee.id())
+        .await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_emit_snapshot() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let super_admin = setup.new_super_admin_account().await?;
+    let admin = setup.new_account_as_admin(&["LevelA"]).await?;
+    let grantee_a = setup.new_account_with_roles(&["LevelA"]).await?;
+    let grantee_b = setup.new_account_with_roles(&["LevelB"]).await?;
+
+    let snapshot_event = |res: &ExecutionFinalResult| {
+        res.logs()
+            .into_iter()
+            .filter_map(|log| log.strip_prefix("EVENT_JSON:"))
+            .filter_map(|event| {
+                near_sdk::serde_json::from_str::<near_sdk::serde_json::Value>(event).ok()
+            })
+            .find(|event| event["event"] == "acl_snapshot")
+    };
+
+    // Only super-admins may emit a snapshot.
+    let res = setup
+        .contract
+        .acl_emit_snapshot(admin.clone().into(), 0, 10)
+        .await?;
+    assert!(snapshot_event(&res).is_none());
+    assert_success_with(res, None::<bool>);
+
+    // The snapshot contains the current state.
+    let res = setup
+        .contract
+        .acl_emit_snapshot(super_admin.clone().into(), 0, 10)
+        .await?;
+    let event = snapshot_event(&res).expect("Snapshot should have been emitted");
+    assert_eq!(
+        event["data"],
+        json!({
+            "skip": 0,
+            "super_admins": [super_admin.id()],
+            "admins": [["LevelA", admin.id()]],
+            "grantees": [["LevelA", grantee_a.id()], ["LevelB", grantee_b.id()]],
+            "has_more": false,
+        })
+    );
+    assert_success_with(res, Some(false));
+
+    // Pages span multiple kinds of entries.
+    let res = setup
+        .contract
+        .acl_emit_snapshot(super_admin.into(), 1, 2)
+        .await?;
+    let event = snapshot_event(&res).expect("Snapshot should have been emitted");
+    assert_eq!(
+        event["data"],
+        json!({
+            "skip": 1,
+            "super_admins": [],
+            "admins": [["LevelA", admin.id()]],
+            "grantees": [["LevelA", grantee_a.id()]],
+            "has_more": true,
+        })
+    );
+    assert_success_with(res, Some(true));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_state_checksum() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let admin = setup.new_account_as_admin(&["LevelA"]).await?;
+    setup.new_account_with_roles(&["LevelB"]).await?;
+    let initial = setup.contract.acl_state_checksum().await?;
+
+    let role = "LevelA";
+    let res = setup
+        .contract
+        .acl_grant_role(admin.clone().into(), role, setup.account.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    let granted = setup.contract.acl_state_checksum().await?;
+    assert_ne!(granted, initial);
+
+    // The checksum depends only on the state, not on its history.
+    let res = setup
+        .contract
+        .acl_revoke_role(admin.into(), role, setup.account.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    assert_eq!(setup.contract.acl_state_checksum().await?, initial);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_assert_all_plugins_initialized() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let raw_contract = setup.contract.contract();
+
+    let call_initialized_greeting = || async {
+        setup
+            .account
+            .call(raw_contract.id(), "initialized_greeting")
+            .args_json(())
+            .max_gas()
+            .transact()
+            .await
+    };
+
+    // Neither an owner nor an admin is set.
+    let res = call_initialized_greeting().await?;
+    let err = format!("{:?}", res.into_result().unwrap_err());
+    assert!(err.contains("Plugin Ownable is not initialized"), "{}", err);
+
+    raw_contract
+        .as_account()
+        .call(raw_contract.id(), "owner_set")
+        .args_json(json!({ "owner": setup.account.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let res = call_initialized_greeting().await?;
+    let err = format!("{:?}", res.into_result().unwrap_err());
+    assert!(
+        err.contains("Plugin AccessControllable is not initialized"),
+        "{}",
+        err
+    );
+
+    setup.new_super_admin_account().await?;
+    let res = call_initialized_greeting().await?;
+    assert_success_with(res, "initialized".to_string());
+
+    // Renouncing ownership is a deliberate choice, hence `Ownable` remains initialized.
+    setup
+        .account
+        .call(raw_contract.id(), "owner_renounce")
+        .args_json(())
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let res = call_initialized_greeting().await?;
+    assert_success_with(res, "initialized".to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_grant_role_by_handle() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "LevelA";
+    let wasm = common::repo::compile_project(&Path::new(HANDLE_REGISTRY_PATH)).await?;
+    let registry = setup.worker.dev_deploy(&wasm).await?;
+    let grantee = setup.worker.dev_create_account().await?;
+    registry
+        .call("register")
+        .args_json(json!({ "handle": "alice", "account_id": grantee.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Only super-admins may set the registry.
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let res = setup
+        .contract
+        .acl_set_handle_registry(admin.clone().into(), Some(registry.id()))
+        .await?;
+    assert!(!res);
+    let super_admin = setup.new_super_admin_account().await?;
+    let res = setup
+        .contract
+        .acl_set_handle_registry(super_admin.into(), Some(registry.id()))
+        .await?;
+    assert!(res);
+
+    // Granting is a no-op if the caller is not an admin for the role.
+    let res = setup
+        .contract
+        .acl_grant_role_by_handle(setup.account.clone().into(), role, "alice")
+        .await?;
+    assert_success_with(res, None::<bool>);
+    setup
+        .contract
+        .assert_acl_has_role(false, role, grantee.id())
+        .await;
+
+    // The role is granted to the account the handle resolves to.
+    let res = setup
+        .contract
+        .acl_grant_role_by_handle(admin.clone().into(), role, "alice")
+        .await?;
+    assert_success_with(res, Some(true));
+    setup
+        .contract
+        .assert_acl_has_role(true, role, grantee.id())
+        .await;
+
+    // Unknown handles fail to resolve.
+    let res = setup
+        .contract
+        .acl_grant_role_by_handle(admin.into(), role, "bob")
+        .await?;
+    let err = format!("{:?}", res.into_result().unwrap_err());
+    assert!(err.contains("Failed to resolve handle bob"), "{}", err);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_governance_is_super_admin() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let governance = setup
+        .worker
+        .root_account()?
+        .create_subaccount("dao")
+        .transact()
+        .await?
+        .into_result()?;
+    assert_eq!(governance.id().as_str(), "dao.test.near");
+
+    setup
+        .contract
+        .assert_acl_is_super_admin(true, governance.id())
+        .await;
+    setup
+        .contract
+        .assert_acl_is_super_admin(false, setup.account.id())
+        .await;
+
+    // Governance may act as super-admin without being stored as such.
+    let res = setup
+        .contract
+        .acl_grant_role(governance.clone().into(), "LevelA", setup.account.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    let res = setup
+        .contract
+        .acl_add_admin(governance.into(), "LevelB", setup.account.id())
+        .await?;
+    assert_eq!(res, Some(true));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pa_paused_for_advances_with_time() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = setup.contract.contract();
+    let paused_for = || async {
+        let res = contract
+            .as_account()
+            .call(contract.id(), "pa_paused_for")
+            .args_json(json!({ "key": "feature" }))
+            .view()
+            .await?;
+        Ok::<_, anyhow::Error>(res.json::<Option<u64>>()?)
+    };
+
+    contract
+        .as_account()
+        .call(contract.id(), "owner_set")
+        .args_json(json!({ "owner": contract.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    contract
+        .as_account()
+        .call(contract.id(), "pa_pause_feature")
+        .args_json(json!({ "key": "feature" }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    // The feature was paused at or before `paused_at`.
+    let paused_at = get_block_timestamp(contract).await?;
+    assert!(paused_for().await?.is_some());
+
+    let now = fast_forward(&setup.worker, contract, 100).await?;
+    assert!(now > paused_at);
+    let duration = paused_for().await?.expect("Feature should be paused");
+    assert!(duration >= now - paused_at);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_grant_role_with_expiry() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let role = "LevelA";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let grantee = setup.worker.dev_create_account().await?;
+    let permanent_grantee = setup.new_account_with_roles(&[role]).await?;
+    let checksum_without_grant = contract.acl_state_checksum().await?;
+
+    // The expiry must be in the future.
+    let now = get_block_timestamp(contract.contract()).await?;
+    let res = contract
+        .acl_grant_role_with_expiry(admin.clone().into(), role, grantee.id(), now)
+        .await?;
+    let err = format!("{:?}", res.into_result().unwrap_err());
+    assert!(err.contains("The expiry of a grant must be in the future"));
+
+    // Without permissions nothing is granted.
+    let expires_at_ns = now + 60 * 1_000_000_000;
+    let res = contract
+        .acl_grant_role_with_expiry(
+            setup.account.clone().into(),
+            role,
+            grantee.id(),
+            expires_at_ns,
+        )
+        .await?;
+    assert_success_with(res, None::<bool>);
+    contract
+        .assert_acl_has_role(false, role, grantee.id())
+        .await;
+
+    let res = contract
+        .acl_grant_role_with_expiry(admin.clone().into(), role, grantee.id(), expires_at_ns)
+        .await?;
+    assert_event_emitted(
+        &res,
+        "AccessControllable",
+        "role_granted",
+        json!({
+            "role": role,
+            "to": grantee.id(),
+            "by": admin.id(),
+            "expires_at_ns": expires_at_ns,
+        }),
+    );
+    assert_success_with(res, Some(true));
+    contract.assert_acl_has_role(true, role, grantee.id()).await;
+    assert_eq!(
+        contract.acl_get_role_expiry(role, grantee.id()).await?,
+        Some(expires_at_ns)
+    );
+    assert_eq!(
+        contract
+            .acl_get_role_expiry(role, permanent_grantee.id())
+            .await?,
+        None
+    );
+
+    // Advance beyond the expiry.
+    let mut now = get_block_timestamp(contract.contract()).await?;
+    while now <= expires_at_ns {
+        now = fast_forward(&setup.worker, contract.contract(), 100).await?;
+    }
+    contract
+        .assert_acl_has_role(false, role, grantee.id())
+        .await;
+    assert_eq!(
+        contract.acl_get_role_expiry(role, grantee.id()).await?,
+        None
+    );
+    let grantees = contract
+        .acl_get_grantees(setup.account.clone().into(), role, 0, 100)
+        .await?;
+    assert_eq!(grantees, vec![permanent_grantee.id().clone()]);
+    assert_eq!(
+        contract.acl_get_roles(grantee.id()).await?,
+        Vec::<String>::new()
+    );
+
+    // Pages skip the expired grant as well, whereas counts read in constant
+    // time include it until it is removed from storage.
+    let page = contract
+        .acl_get_grantees_page(setup.account.clone().into(), role, None, 100)
+        .await?;
+    assert_eq!(page.items, vec![permanent_grantee.id().clone()]);
+    assert_eq!(page.next, None);
+    assert_eq!(page.total, 2);
+    assert_eq!(contract.acl_num_grantees(role).await?, 2);
+    let counts = contract.acl_get_all_grantee_counts().await?;
+    assert!(counts.contains(&(role.to_string(), 2)));
+    assert_eq!(contract.acl_state_checksum().await?, checksum_without_grant);
+
+    // An expired grant can be renewed.
+    let res = contract
+        .acl_grant_role_with_expiry(admin.into(), role, grantee.id(), now + 60 * 1_000_000_000)
+        .await?;
+    assert_success_with(res, Some(true));
+    contract.assert_acl_has_role(true, role, grantee.id()).await;
+
+    Ok(())
+}
+
+/// Verify that a transferred grant keeps its expiry.
+#[tokio::test]
+async fn test_acl_transfer_role_bulk_keeps_expiry() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let role = "LevelA";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let old = setup.worker.dev_create_account().await?;
+    let new = setup.worker.dev_create_account().await?;
+
+    let now = get_block_timestamp(contract.contract()).await?;
+    let expires_at_ns = now + 60 * 1_000_000_000;
+    contract
+        .acl_grant_role_with_expiry(admin.clone().into(), role, old.id(), expires_at_ns)
+        .await?
+        .into_result()?;
+
+    let res = admin
+        .call(contract.contract().id(), "acl_transfer_role_bulk")
+        .args_json(json!({
+            "role": role,
+            "mappings": [(old.id(), new.id())],
+        }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert_event_emitted(
+        &res,
+        "AccessControllable",
+        "role_granted",
+        json!({
+            "role": role,
+            "to": new.id(),
+            "by": admin.id(),
+            "expires_at_ns": expires_at_ns,
+        }),
+    );
+    assert_success_with(res, Some(vec![true]));
+    contract.assert_acl_has_role(true, role, new.id()).await;
+    assert_eq!(
+        contract.acl_get_role_expiry(role, new.id()).await?,
+        Some(expires_at_ns)
+    );
+
+    // The new grantee loses the role at the original expiry.
+    let mut now = get_block_timestamp(contract.contract()).await?;
+    while now <= expires_at_ns {
+        now = fast_forward(&setup.worker, contract.contract(), 100).await?;
+    }
+    contract.assert_acl_has_role(false, role, new.id()).await;
+
+    Ok(())
+}
+
+/// Verify that setting an expiry on a permanent grant is reported via
+/// `RoleGranted`, though the account is not a new grantee.
+#[tokio::test]
+async fn test_acl_grant_role_with_expiry_on_permanent_grant() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let role = "LevelA";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let grantee = setup.new_account_with_roles(&[role]).await?;
+
+    let now = get_block_timestamp(contract.contract()).await?;
+    let expires_at_ns = now + 60 * 1_000_000_000;
+    let res = contract
+        .acl_grant_role_with_expiry(admin.clone().into(), role, grantee.id(), expires_at_ns)
+        .await?;
+    assert_event_emitted(
+        &res,
+        "AccessControllable",
+        "role_granted",
+        json!({
+            "role": role,
+            "to": grantee.id(),
+            "by": admin.id(),
+            "expires_at_ns": expires_at_ns,
+        }),
+    );
+    assert_success_with(res, Some(false));
+    contract.assert_acl_has_role(true, role, grantee.id()).await;
+    assert_eq!(
+        contract.acl_get_role_expiry(role, grantee.id()).await?,
+        Some(expires_at_ns)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_estimate_grant_storage() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "LevelA";
+    let account = setup.worker.dev_create_account().await?;
+
+    let estimate = setup
+        .contract
+        .acl_estimate_grant_storage(Caller::Contract, role, account.id())
+        .await?;
+    assert!(estimate > 0);
+
+    // Further grants of the role reuse its set of bearers.
+    setup
+        .contract
+        .acl_grant_role_unchecked(Caller::Contract, role, setup.account.id())
+        .await?
+        .into_result()?;
+    let estimate_existing_set = setup
+        .contract
+        .acl_estimate_grant_storage(Caller::Contract, role, account.id())
+        .await?;
+    assert!(estimate_existing_set > 0);
+    assert!(estimate_existing_set < estimate);
+
+    // Nothing is added if the role is granted already.
+    setup
+        .contract
+        .acl_grant_role_unchecked(Caller::Contract, role, account.id())
+        .await?
+        .into_result()?;
+    let estimate = setup
+        .contract
+        .acl_estimate_grant_storage(Caller::Contract, role, account.id())
+        .await?;
+    assert_eq!(estimate, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_scoped_roles() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = setup.contract.contract();
+    let role = "LevelA";
+    let grantee = setup.worker.dev_create_account().await?;
+    let call_tenant_greeting = |tenant: u64| {
+        let grantee = grantee.clone();
+        async move {
+            grantee
+                .call(contract.id(), "restricted_tenant_greeting")
+                .args_json(json!({ "tenant": tenant }))
+                .max_gas()
+                .transact()
+                .await
+        }
+    };
+
+    // Granting is a no-op if the caller is not an admin for the role.
+    let caller = setup.new_account_as_admin(&["LevelB"]).await?;
+    let res = setup
+        .contract
+        .acl_grant_role_scoped(caller.into(), role, "42", grantee.id())
+        .await?;
+    assert_eq!(res, None);
+
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let res = setup
+        .contract
+        .acl_grant_role_scoped(admin.clone().into(), role, "42", grantee.id())
+        .await?;
+    assert_eq!(res, Some(true));
+
+    // The grant doesn't leak to other scopes or the global scope.
+    let has_role = |scope: &'static str| {
+        let contract = &setup.contract;
+        let grantee = grantee.id().clone();
+        async move {
+            contract
+                .acl_has_role_scoped(Caller::Contract, role, scope, &grantee)
+                .await
+        }
+    };
+    assert!(has_role("42").await?);
+    assert!(!has_role("43").await?);
+    setup
+        .contract
+        .assert_acl_has_role(false, role, grantee.id())
+        .await;
+
+    // Methods may check roles within a scope derived from their arguments.
+    let res = call_tenant_greeting(42).await?;
+    assert_success_with(res, "hello tenant 42".to_string());
+    let res = call_tenant_greeting(43).await?;
+    assert_insufficient_acl_permissions(res, "restricted_tenant_greeting", vec![role.to_string()]);
+
+    let res = setup
+        .contract
+        .acl_revoke_role_scoped(admin.into(), role, "42", grantee.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    assert!(!has_role("42").await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_revoke_role_notify() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "LevelA";
+    let wasm = common::repo::compile_project(&Path::new(NOTIFICATION_RECEIVER_PATH)).await?;
+    let receiver = setup.worker.dev_deploy(&wasm).await?;
+    let get_notifications = || async {
+        let res = receiver
+            .call("get_notifications")
+            .args_json(())
+            .view()
+            .await?;
+        Ok::<_, anyhow::Error>(res.json::<Vec<(AccountId, String)>>()?)
+    };
+    setup
+        .contract
+        .acl_grant_role_unchecked(Caller::Contract, role, receiver.id())
+        .await?
+        .into_result()?;
+
+    // Revoking is a no-op if the caller is not an admin for the role.
+    let res = setup
+        .contract
+        .acl_revoke_role_notify(setup.account.clone().into(), role, receiver.id())
+        .await?;
+    assert_eq!(res, None);
+    assert_eq!(get_notifications().await?, vec![]);
+
+    // The revoked account is notified.
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let res = setup
+        .contract
+        .acl_revoke_role_notify(admin.clone().into(), role, receiver.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    setup
+        .contract
+        .assert_acl_has_role(false, role, receiver.id())
+        .await;
+    assert_eq!(
+        get_notifications().await?,
+        vec![(setup.contract.contract().id().clone(), role.to_string())]
+    );
+
+    // No notification is sent if the account wasn't a grantee.
+    let res = setup
+        .contract
+        .acl_revoke_role_notify(admin.clone().into(), role, receiver.id())
+        .await?;
+    assert_eq!(res, Some(false));
+    assert_eq!(get_notifications().await?.len(), 1);
+
+    // Failing notifications are ignored, e.g. if the account is no contract.
+    let grantee = setup.new_account_with_roles(&[role]).await?;
+    let res = setup
+        .contract
+        .acl_revoke_role_notify(admin.into(), role, grantee.id())
+        .await?;
+    assert_eq!(res, Some(true));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_which_role() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let account = setup.new_account_with_roles(&["LevelA", "LevelC"]).await?;
+
+    let which_role = |roles: Vec<&'static str>| {
+        let contract = &setup.contract;
+        let account_id = account.id().clone();
+        async move {
+            contract
+                .acl_which_role(Caller::Contract, roles, &account_id)
+                .await
+        }
+    };
+
+    // The first matching role in input order is returned.
+    assert_eq!(
+        which_role(vec!["LevelB", "LevelC", "LevelA"]).await?,
+        Some("LevelC".to_string())
+    );
+    assert_eq!(
+        which_role(vec!["LevelA", "LevelC"]).await?,
+        Some("LevelA".to_string())
+    );
+    assert_eq!(which_role(vec!["LevelB"]).await?, None);
+    assert_eq!(which_role(vec![]).await?, None);
+
+    // Super-admin permissions are not taken into account.
+    let super_admin = setup.new_super_admin_account().await?;
+    let res = setup
+        .contract
+        .acl_which_role(Caller::Contract, vec!["LevelA"], super_admin.id())
+        .await?;
+    assert_eq!(res, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_storage_version() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let super_admin = setup.new_super_admin_account().await?;
+    assert_eq!(
+        contract.acl_get_storage_version(Caller::Contract).await?,
+        None
+    );
+
+    // Only super-admins may set the version.
+    let res = contract
+        .acl_set_storage_version(setup.account.clone().into(), 0)
+        .await?;
+    assert!(!res);
+
+    // Mutating methods fail while the recorded version is stale, whereas
+    // views keep working.
+    let res = contract
+        .acl_set_storage_version(super_admin.clone().into(), 0)
+        .await?;
+    assert!(res);
+    let err = contract
+        .acl_grant_role(super_admin.clone().into(), "LevelA", setup.account.id())
+        .await
+        .unwrap_err();
+    assert!(
+        format!("{:?}", err).contains("AccessControllable: Migration required"),
+        "{:?}",
+        err
+    );
+    contract
+        .assert_acl_has_role(false, "LevelA", setup.account.id())
+        .await;
+
+    // After bumping the version, methods succeed.
+    let res = contract
+        .acl_set_storage_version(super_admin.into(), ACL_STORAGE_VERSION)
+        .await?;
+    assert!(res);
+    contract
+        .assert_acl_has_role(false, "LevelA", setup.account.id())
+        .await;
+    assert_eq!(
+        contract.acl_get_storage_version(Caller::Contract).await?,
+        Some(ACL_STORAGE_VERSION)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_init_super_admin_records_storage_version() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    setup
+        .contract
+        .acl_init_super_admin(Caller::Contract, setup.account.id())
+        .await?
+        .into_result()?;
+    assert_eq!(
+        setup
+            .contract
+            .acl_get_storage_version(Caller::Contract)
+            .await?,
+        Some(ACL_STORAGE_VERSION)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_grant_role_threshold() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let role = "LevelD";
+    let grantee = setup.worker.dev_create_account().await?;
+    let admin_a = setup.new_account_as_admin(&[role]).await?;
+    let admin_b = setup.new_account_as_admin(&[role]).await?;
+    let grant = |admin: &Account| {
+        let caller = admin.clone();
+        let grantee = grantee.id().clone();
+        async move {
+            caller
+                .call(contract.contract().id(), "acl_grant_role")
+                .args_json(json!({ "role": role, "account_id": grantee }))
+                .max_gas()
+                .transact()
+                .await
+        }
+    };
+    let has_role_granted_event = |res: &ExecutionFinalResult| {
+        res.logs()
+            .into_iter()
+            .filter_map(|log| log.strip_prefix("EVENT_JSON:"))
+            .filter_map(|event| {
+                near_sdk::serde_json::from_str::<near_sdk::serde_json::Value>(event).ok()
+            })
+            .any(|event| event["event"] == "role_granted")
+    };
+
+    // One admin doesn't suffice, even when approving repeatedly.
+    for _ in 0..2 {
+        let res = grant(&admin_a).await?;
+        assert!(!has_role_granted_event(&res));
+        assert_success_with(res, Some(false));
+    }
+    contract
+        .assert_acl_has_role(false, role, grantee.id())
+        .await;
+    let res = contract
+        .contract()
+        .view("acl_get_grant_approvals")
+        .args_json(json!({ "role": role, "account_id": grantee.id() }))
+        .await?;
+    assert_eq!(res.json::<Vec<AccountId>>()?, vec![admin_a.id().clone()]);
+
+    // A revoke resets pending approvals.
+    let res = contract
+        .acl_revoke_role(admin_b.clone().into(), role, grantee.id())
+        .await?;
+    assert_eq!(res, Some(false));
+    let res = grant(&admin_b).await?;
+    assert_success_with(res, Some(false));
+    contract
+        .assert_acl_has_role(false, role, grantee.id())
+        .await;
+
+    // The role is granted once the threshold is reached.
+    let res = grant(&admin_a).await?;
+    assert!(has_role_granted_event(&res));
+    assert_success_with(res, Some(true));
+    contract.assert_acl_has_role(true, role, grantee.id()).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_grant_role_threshold_ignores_revoked_approvers() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let role = "LevelD";
+    let grantee = setup.worker.dev_create_account().await?;
+    let admin_a = setup.new_account_as_admin(&[role]).await?;
+    let admin_b = setup.new_account_as_admin(&[role]).await?;
+    let super_admin = setup.new_super_admin_account().await?;
+
+    let res = contract
+        .acl_grant_role(admin_a.clone().into(), role, grantee.id())
+        .await?;
+    assert_eq!(res, Some(false));
+    let res = contract
+        .acl_revoke_admin(super_admin.into(), role, admin_a.id())
+        .await?;
+    assert_eq!(res, Some(true));
+
+    // The approval of the revoked admin no longer counts.
+    let res = contract
+        .acl_grant_role(admin_b.clone().into(), role, grantee.id())
+        .await?;
+    assert_eq!(res, Some(false));
+    contract
+        .assert_acl_has_role(false, role, grantee.id())
+        .await;
+    let res = contract
+        .contract()
+        .view("acl_get_grant_approvals")
+        .args_json(json!({ "role": role, "account_id": grantee.id() }))
+        .await?;
+    assert_eq!(res.json::<Vec<AccountId>>()?, vec![admin_b.id().clone()]);
+
+    Ok(())
+}
+
+/// Verify that methods granting roles without collecting approvals reject
+/// roles with a grant threshold.
+#[tokio::test]
+async fn test_acl_grant_threshold_cannot_be_bypassed() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let role = "LevelD";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let grantee = setup.worker.dev_create_account().await?;
+    let expected = "Role LevelD requires approvals via acl_grant_role";
+
+    let err = contract
+        .acl_grant_consumable_role(admin.clone().into(), role, grantee.id(), 1)
+        .await
+        .unwrap_err();
+    assert!(format!("{:?}", err).contains(expected), "{:?}", err);
+
+    let res = contract
+        .acl_grant_role_with_expiry(admin.clone().into(), role, grantee.id(), u64::MAX)
+        .await?;
+    let err = format!("{:?}", res.into_result().unwrap_err());
+    assert!(err.contains(expected), "{}", err);
+
+    // Approvals of two admins grant the role, so there's a grantee to
+    // transfer from.
+    let other_admin = setup.new_account_as_admin(&[role]).await?;
+    for caller in [&admin, &other_admin] {
+        contract
+            .acl_grant_role(caller.clone().into(), role, grantee.id())
+            .await?;
+    }
+    contract.assert_acl_has_role(true, role, grantee.id()).await;
+    let new = setup.worker.dev_create_account().await?;
+    let err = contract
+        .acl_transfer_role_bulk(admin.clone().into(), role, &[(grantee.id(), new.id())])
+        .await
+        .unwrap_err();
+    assert!(format!("{:?}", err).contains(expected), "{:?}", err);
+    contract.assert_acl_has_role(false, role, new.id()).await;
+
+    let wasm = common::repo::compile_project(&Path::new(HANDLE_REGISTRY_PATH)).await?;
+    let registry = setup.worker.dev_deploy(&wasm).await?;
+    registry
+        .call("register")
+        .args_json(json!({ "handle": "alice", "account_id": new.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let super_admin = setup.new_super_admin_account().await?;
+    contract
+        .acl_set_handle_registry(super_admin.into(), Some(registry.id()))
+        .await?;
+    let res = contract
+        .acl_grant_role_by_handle(admin.clone().into(), role, "alice")
+        .await?;
+    let err = format!("{:?}", res.into_result().unwrap_err());
+    assert!(err.contains(expected), "{}", err);
+    contract.assert_acl_has_role(false, role, new.id()).await;
+
+    let res = new
+        .call(contract.contract().id(), "register_level_d")
+        .args_json(())
+        .max_gas()
+        .transact()
+        .await?;
+    let err = format!("{:?}", res.into_result().unwrap_err());
+    assert!(err.contains(expected), "{}", err);
+    contract.assert_acl_has_role(false, role, new.id()).await;
+
+    Ok(())
+}
+
+/// Verify that following `next` cursors visits every admin and grantee
+/// exactly once, with every page reporting the total number of bearers.
+#[tokio::test]
+async fn test_acl_get_bearers_page() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "LevelA";
+    let mut admin_ids = vec![];
+    let mut grantee_ids = vec![];
+    for _ in 0..5 {
+        admin_ids.push(setup.new_account_as_admin(&[role]).await?.id().clone());
+        grantee_ids.push(setup.new_account_with_roles(&[role]).await?.id().clone());
+    }
+
+    for limit in [1, 2, 5, 6] {
+        let mut admins = vec![];
+        let mut grantees = vec![];
+        let mut admins_cursor = None;
+        let mut grantees_cursor = None;
+        loop {
+            let page = setup
+                .contract
+                .acl_get_admins_page(setup.account.clone().into(), role, admins_cursor, limit)
+                .await?;
+            assert!(page.items.len() <= usize::try_from(limit).unwrap());
+            assert_eq!(page.total, 5);
+            admins.extend(page.items);
+            admins_cursor = page.next;
+            if admins_cursor.is_none() {
+                break;
+            }
+        }
+        loop {
+            let page = setup
+                .contract
+                .acl_get_grantees_page(setup.account.clone().into(), role, grantees_cursor, limit)
+                .await?;
+            assert!(page.items.len() <= usize::try_from(limit).unwrap());
+            assert_eq!(page.total, 5);
+            grantees.extend(page.items);
+            grantees_cursor = page.next;
+            if grantees_cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(admins, admin_ids, "Mismatch for limit {}", limit);
+        assert_eq!(grantees, grantee_ids, "Mismatch for limit {}", limit);
+    }
+
+    // A role without bearers yields a single empty page.
+    let page = setup
+        .contract
+        .acl_get_grantees_page(setup.account.clone().into(), "LevelB", None, 2)
+        .await?;
+    assert_eq!(page.items, vec![]);
+    assert_eq!(page.next, None);
+    assert_eq!(page.total, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_role_metadata() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let role = "LevelA";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let charter = "https://example.com/charter";
+
+    // Non-admins may not set metadata.
+    let res = contract
+        .acl_set_role_metadata(setup.account.clone().into(), role, "charter", charter)
+        .await?;
+    assert!(!res);
+    assert_eq!(contract.acl_get_role_metadata(role, "charter").await?, None);
+
+    // An admin may set and update metadata.
+    let res = contract
+        .acl_set_role_metadata(admin.clone().into(), role, "charter", "outdated")
+        .await?;
+    assert!(res);
+    let res = contract
+        .acl_set_role_metadata(admin.clone().into(), role, "charter", charter)
+        .await?;
+    assert!(res);
+    assert_eq!(
+        contract.acl_get_role_metadata(role, "charter").await?,
+        Some(charter.to_string())
+    );
+    let res = contract
+        .acl_set_role_metadata(admin.clone().into(), role, "contact", "ops@example.com")
+        .await?;
+    assert!(res);
+
+    // Metadata is attached to a single role.
+    assert_eq!(
+        contract.acl_get_role_metadata("LevelB", "charter").await?,
+        None
+    );
+
+    // Keys are retrieved page by page.
+    let page = contract.acl_get_role_metadata_keys(role, None, 1).await?;
+    assert_eq!(page.items, vec!["charter".to_string()]);
+    let page = contract
+        .acl_get_role_metadata_keys(role, page.next, 1)
+        .await?;
+    assert_eq!(page.items, vec!["contact".to_string()]);
+    assert_eq!(page.next, None);
+    let page = contract
+        .acl_get_role_metadata_keys("LevelB", None, 1)
+        .await?;
+    assert_eq!(page.items, Vec::<String>::new());
+    assert_eq!(page.next, None);
+
+    Ok(())
+}
+
+/// Verify that mutating methods of all plugins are blocked in read-only mode,
+/// while views keep working.
+#[tokio::test]
+async fn test_acl_read_only() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = setup.contract.contract();
+    let role = "LevelA";
+    let super_admin = setup.new_super_admin_account().await?;
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let consumable_admin = setup.new_account_as_admin(&["LevelB"]).await?;
+    let consumer = setup.worker.dev_create_account().await?;
+    let res = setup
+        .contract
+        .acl_grant_consumable_role(consumable_admin.into(), "LevelB", consumer.id(), 2)
+        .await?;
+    assert_eq!(res, Some(true));
+    let set_read_only = |caller: Account, read_only: bool| async move {
+        caller
+            .call(contract.id(), "acl_set_read_only")
+            .args_json(json!({ "read_only": read_only }))
+            .max_gas()
+            .transact()
+            .await?
+            .json::<bool>()
+    };
+
+    // Make the contract its own owner to call `Pausable` and `Upgradable`
+    // methods.
+    contract
+        .as_account()
+        .call(contract.id(), "owner_set")
+        .args_json(json!({ "owner": contract.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Only super-admins may enter read-only mode.
+    assert!(!set_read_only(admin.clone(), true).await?);
+    assert!(set_read_only(super_admin.clone(), true).await?);
+    let res = contract.view("acl_is_read_only").await?;
+    assert!(res.json::<bool>()?);
+
+    let calls = vec![
+        (
+            admin.clone(),
+            "acl_grant_role",
+            json!({ "role": role, "account_id": setup.account.id() }),
+        ),
+        (
+            super_admin.clone(),
+            "acl_add_admin",
+            json!({ "role": role, "account_id": setup.account.id() }),
+        ),
+        (
+            admin.clone(),
+            "acl_set_role_metadata",
+            json!({ "role": role, "key": "charter", "value": "link" }),
+        ),
+        (
+            admin.clone(),
+            "acl_grant_consumable_role",
+            json!({ "role": role, "account_id": setup.account.id(), "uses": 1 }),
+        ),
+        (
+            admin.clone(),
+            "acl_grant_role_with_expiry",
+            json!({ "role": role, "account_id": setup.account.id(), "expires_at_ns": u64::MAX }),
+        ),
+        (
+            admin.clone(),
+            "acl_grant_role_by_handle",
+            json!({ "role": role, "handle": "alice" }),
+        ),
+        (
+            admin.clone(),
+            "acl_transfer_role_bulk",
+            json!({ "role": role, "mappings": [[admin.id(), setup.account.id()]] }),
+        ),
+        (
+            admin.clone(),
+            "acl_revoke_role_notify",
+            json!({ "role": role, "account_id": setup.account.id() }),
+        ),
+        // Self-registration and consuming uses of a consumable role modify
+        // the ACL as well.
+        (setup.account.clone(), "register", json!({})),
+        (consumer.clone(), "restricted_action", json!({})),
+        (
+            contract.as_account().clone(),
+            "pa_pause_feature",
+            json!({ "key": "ALL" }),
+        ),
+        (
+            contract.as_account().clone(),
+            "pa_unpause_feature",
+            json!({ "key": "ALL" }),
+        ),
+        (
+            contract.as_account().clone(),
+            "pa_set_maintenance_message",
+            json!({ "message": "snapshot" }),
+        ),
+        (
+            contract.as_account().clone(),
+            "up_set_staging_duration",
+            json!({ "staging_duration": 1 }),
+        ),
+        (contract.as_account().clone(), "up_deploy_code", json!({})),
+        (
+            contract.as_account().clone(),
+            "up_deploy_code_and_call",
+            json!({
+                "function_name": "migrate",
+                "args": Vec::<u8>::new(),
+                "gas": near_sdk::Gas(20_000_000_000_000),
+            }),
+        ),
+        (
+            contract.as_account().clone(),
+            "owner_set",
+            json!({ "owner": admin.id() }),
+        ),
+        (
+            contract.as_account().clone(),
+            "owner_propose",
+            json!({ "account_id": admin.id() }),
+        ),
+        (admin.clone(), "owner_accept", json!({})),
+        (contract.as_account().clone(), "owner_renounce", json!({})),
+        (
+            contract.as_account().clone(),
+            "attach_full_access_key",
+            json!({ "public_key": "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp" }),
+        ),
+    ];
+    for (caller, method, args) in calls {
+        let res = caller
+            .call(contract.id(), method)
+            .args_json(args)
+            .max_gas()
+            .transact()
+            .await?;
+        let err = format!("{}", res.into_result().unwrap_err());
+        assert!(
+            err.contains("Contract is in read-only mode"),
+            "{} is not blocked: {}",
+            method,
+            err
+        );
+    }
+    let res = contract
+        .as_account()
+        .call(contract.id(), "up_stage_code")
+        .args_borsh(vec![1u8, 2, 3])
+        .max_gas()
+        .transact()
+        .await?;
+    let err = format!("{}", res.into_result().unwrap_err());
+    assert!(err.contains("Contract is in read-only mode"));
+
+    // Views keep working.
+    setup
+        .contract
+        .assert_acl_has_role(false, role, setup.account.id())
+        .await;
+    assert_eq!(
+        setup
+            .contract
+            .acl_get_remaining_uses(setup.account.clone().into(), "LevelB", consumer.id())
+            .await?,
+        Some(2)
+    );
+    setup
+        .contract
+        .assert_acl_is_admin(true, role, admin.id())
+        .await;
+
+    // Leaving read-only mode unblocks mutations.
+    assert!(set_read_only(super_admin, false).await?);
+    let res = setup
+        .contract
+        .acl_grant_role(admin.into(), role, setup.account.id())
+        .await?;
+    assert_eq!(res, Some(true));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_get_all_roles() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let roles = setup.contract.acl_get_all_roles().await?;
+    assert_eq!(roles, ALL_ROLES.to_vec());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_grant_role_and_list() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "LevelA";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let grantee = setup.new_account_with_roles(&[role]).await?;
+    let account = setup.account.id();
+
+    let res = setup
+        .contract
+        .acl_grant_role_and_list(setup.account.clone().into(), role, account, 0, 10)
+        .await?;
+    assert_eq!(res, None);
+    setup
+        .contract
+        .assert_acl_has_role(false, role, account)
+        .await;
+
+    let res = setup
+        .contract
+        .acl_grant_role_and_list(admin.into(), role, account, 0, 10)
+        .await?;
+    assert_eq!(res, Some(vec![grantee.id().clone(), account.clone()]));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_grant_and_revoke_role_checked() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "LevelA";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let account = setup.account.id();
+
+    let res = setup
+        .contract
+        .acl_grant_role_checked(setup.account.clone().into(), role, account)
+        .await?;
+    assert_eq!(res, AclResult::NoPermission);
+    let res = setup
+        .contract
+        .acl_grant_role_checked(admin.clone().into(), "LevelZ", account)
+        .await?;
+    assert_eq!(res, AclResult::UnknownRole);
+    let res = setup
+        .contract
+        .acl_grant_role_checked(admin.clone().into(), role, account)
+        .await?;
+    assert_eq!(res, AclResult::Ok(true));
+    let res = setup
+        .contract
+        .acl_grant_role_checked(admin.clone().into(), role, account)
+        .await?;
+    assert_eq!(res, AclResult::Ok(false));
+
+    let res = setup
+        .contract
+        .acl_revoke_role_checked(setup.account.clone().into(), role, account)
+        .await?;
+    assert_eq!(res, AclResult::NoPermission);
+    let res = setup
+        .contract
+        .acl_revoke_role_checked(admin.clone().into(), "LevelZ", account)
+        .await?;
+    assert_eq!(res, AclResult::UnknownRole);
+    let res = setup
+        .contract
+        .acl_revoke_role_checked(admin.into(), role, account)
+        .await?;
+    assert_eq!(res, AclResult::Ok(true));
+    setup
+        .contract
+        .assert_acl_has_role(false, role, account)
+        .await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_role_exists() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    for role in ALL_ROLES {
+        assert!(setup.contract.acl_role_exists(role).await?);
+    }
+    assert!(!setup.contract.acl_role_exists("LevelZ").await?);
+    // Role names are case sensitive, unless `case_insensitive_roles` is set.
+    assert!(!setup.contract.acl_role_exists("levela").await?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_get_all_grantee_counts() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    setup.new_account_with_roles(&["LevelA", "LevelC"]).await?;
+    setup.new_account_with_roles(&["LevelA"]).await?;
+    setup.new_account_with_roles(&["LevelA", "LevelB"]).await?;
+
+    let counts = setup.contract.acl_get_all_grantee_counts().await?;
+    let expected = vec![
+        ("LevelA".to_string(), 3),
+        ("LevelB".to_string(), 1),
+        ("LevelC".to_string(), 1),
+        ("LevelD".to_string(), 0),
+    ];
+    assert_eq!(counts, expected);
+
+    // Counts match per-role reads.
+    for (role, count) in counts {
+        let grantees = setup
+            .contract
+            .acl_get_grantees(setup.account.clone().into(), &role, 0, 100)
+            .await?;
+        assert_eq!(u64::try_from(grantees.len()).unwrap(), count);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_grant_roles() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let role = "LevelA";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let grantee = setup.new_account_with_roles(&[role]).await?;
+    let alice = setup.worker.dev_create_account().await?;
+    let bob = setup.worker.dev_create_account().await?;
+    let accounts = [alice.id(), grantee.id(), bob.id(), alice.id()];
+
+    // Without permissions nothing is granted.
+    let snapshot = contract.acl_state_snapshot(&ALL_ROLES).await?;
+    let res = contract
+        .acl_grant_roles(setup.account.clone().into(), role, &accounts)
+        .await?;
+    assert_eq!(res.json::<Vec<Option<bool>>>()?, vec![None; 4]);
+    assert_acl_state_unchanged(snapshot, contract.acl_state_snapshot(&ALL_ROLES).await?);
+
+    let res = contract
+        .acl_grant_roles(admin.clone().into(), role, &accounts)
+        .await?;
+    let granted_events = res
+        .logs()
+        .into_iter()
+        .filter(|log| log.contains(r#""event":"role_granted""#))
+        .count();
+    assert_eq!(
+        res.json::<Vec<Option<bool>>>()?,
+        vec![Some(true), Some(false), Some(true), Some(false)]
+    );
+    assert_eq!(granted_events, 2);
+    for account in accounts {
+        contract.assert_acl_has_role(true, role, account).await;
+    }
+
+    // The number of accounts is capped.
+    let too_many = vec![alice.id(); ACL_GRANT_ROLES_MAX_LEN + 1];
+    let res = contract
+        .acl_grant_roles(admin.into(), role, &too_many)
+        .await?
+        .into_result();
+    let err = format!("{:?}", res.unwrap_err());
+    assert!(err.contains("Granting roles accepts at most"));
+
+    // Roles with a grant threshold are not supported.
+    let res = contract
+        .acl_grant_roles(setup.account.clone().into(), "LevelD", &[alice.id()])
+        .await?
+        .into_result();
+    let err = format!("{:?}", res.unwrap_err());
+    assert!(err.contains("Role LevelD requires approvals via acl_grant_role"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_apply_membership_change() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let role = "LevelA";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let member_a = setup.new_account_with_roles(&[role]).await?;
+    let member_b = setup.new_account_with_roles(&[role]).await?;
+    let candidate = setup.worker.dev_create_account().await?;
+    let non_member = setup.worker.dev_create_account().await?;
+
+    // Without permissions nothing changes.
+    let snapshot = contract.acl_state_snapshot(&ALL_ROLES).await?;
+    let res = contract
+        .acl_apply_membership_change(
+            setup.account.clone().into(),
+            role,
+            &[candidate.id()],
+            &[member_a.id()],
+        )
+        .await?;
+    assert_success_with(res, None::<MembershipChangeResult>);
+    assert_acl_state_unchanged(snapshot, contract.acl_state_snapshot(&ALL_ROLES).await?);
+
+    // An account must not be both added and removed.
+    let res = contract
+        .acl_apply_membership_change(
+            admin.clone().into(),
+            role,
+            &[candidate.id()],
+            &[candidate.id()],
+        )
+        .await?
+        .into_result();
+    let err = format!("{:?}", res.unwrap_err());
+    assert!(err.contains("is both added and removed"));
+
+    // Roles with a grant threshold are not supported.
+    let res = contract
+        .acl_apply_membership_change(admin.clone().into(), "LevelD", &[candidate.id()], &[])
+        .await?
+        .into_result();
+    let err = format!("{:?}", res.unwrap_err());
+    assert!(err.contains("Role LevelD requires approvals via acl_grant_role"));
+
+    let res = contract
+        .acl_apply_membership_change(
+            admin.into(),
+            role,
+            &[candidate.id(), member_b.id()],
+            &[member_a.id(), non_member.id()],
+        )
+        .await?;
+    assert_success_with(
+        res,
+        Some(MembershipChangeResult {
+            added: vec![candidate.id().as_str().parse().unwrap()],
+            removed: vec![member_a.id().as_str().parse().unwrap()],
+        }),
+    );
+    let grantees = contract
+        .acl_get_grantees(setup.account.clone().into(), role, 0, 100)
+        .await?;
+    assert_eq!(
+        grantees,
+        vec![member_b.id().clone(), candidate.id().clone()]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_revoke_roles() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let role = "LevelA";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let grantee_a = setup.new_account_with_roles(&[role]).await?;
+    let grantee_b = setup.new_account_with_roles(&[role]).await?;
+    let non_grantee = setup.worker.dev_create_account().await?;
+    let accounts = [
+        grantee_a.id(),
+        non_grantee.id(),
+        grantee_b.id(),
+        grantee_a.id(),
+    ];
+
+    // Without permissions nothing is revoked.
+    let snapshot = contract.acl_state_snapshot(&ALL_ROLES).await?;
+    let res = contract
+        .acl_revoke_roles(setup.account.clone().into(), role, &accounts)
+        .await?;
+    assert_eq!(res.json::<Vec<Option<bool>>>()?, vec![None; 4]);
+    assert_acl_state_unchanged(snapshot, contract.acl_state_snapshot(&ALL_ROLES).await?);
+
+    let res = contract
+        .acl_revoke_roles(admin.into(), role, &accounts)
+        .await?;
+    let revoked_events = res
+        .logs()
+        .into_iter()
+        .filter(|log| log.contains(r#""event":"role_revoked""#))
+        .count();
+    assert_eq!(
+        res.json::<Vec<Option<bool>>>()?,
+        vec![Some(true), Some(false), Some(true), Some(false)]
+    );
+    assert_eq!(revoked_events, 2);
+    for account in accounts {
+        contract.assert_acl_has_role(false, role, account).await;
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_has_all_roles() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let account = setup.new_account_with_roles(&["LevelA", "LevelC"]).await?;
+
+    assert!(
+        contract
+            .acl_has_all_roles(&["LevelA"], account.id())
+            .await?
+    );
+    assert!(
+        contract
+            .acl_has_all_roles(&["LevelC", "LevelA"], account.id())
+            .await?
+    );
+    assert!(
+        !contract
+            .acl_has_all_roles(&["LevelA", "LevelB"], account.id())
+            .await?
+    );
+    assert!(
+        !contract
+            .acl_has_all_roles(&["LevelB"], account.id())
+            .await?
+    );
+
+    // Vacuously true for an empty list of roles, even without any grants.
+    assert!(contract.acl_has_all_roles(&[], account.id()).await?);
+    assert!(contract.acl_has_all_roles(&[], setup.account.id()).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_get_roles() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    assert_eq!(
+        contract.acl_get_roles(setup.account.id()).await?,
+        Vec::<String>::new()
+    );
+
+    // Roles are ordered independently of the order of grants and admin
+    // permissions are not included.
+    let account = setup.new_account_with_roles(&["LevelC", "LevelA"]).await?;
+    contract
+        .acl_add_admin_unchecked(Caller::Contract, "LevelB", account.id())
+        .await?
+        .into_result()?;
+    assert_eq!(
+        contract.acl_get_roles(account.id()).await?,
+        vec!["LevelA".to_string(), "LevelC".to_string()]
+    );
+
+    contract
+        .acl_revoke_role_unchecked(Caller::Contract, "LevelA", account.id())
+        .await?
+        .into_result()?;
+    assert_eq!(
+        contract.acl_get_roles(account.id()).await?,
+        vec!["LevelC".to_string()]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_my_roles() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let account_a = setup.new_account_with_roles(&["LevelA"]).await?;
+    let account_bc = setup.new_account_with_roles(&["LevelC", "LevelB"]).await?;
+    let super_admin = setup.new_super_admin_account().await?;
+
+    assert_eq!(
+        contract.acl_my_roles(account_a.clone().into()).await?,
+        vec!["LevelA".to_string()]
+    );
+    assert_eq!(
+        contract.acl_my_roles(account_bc.clone().into()).await?,
+        vec!["LevelB".to_string(), "LevelC".to_string()]
+    );
+    assert_eq!(
+        contract.acl_my_roles(super_admin.clone().into()).await?,
+        Vec::<String>::new()
+    );
+
+    assert!(!contract.acl_am_i_super_admin(account_a.into()).await?);
+    assert!(contract.acl_am_i_super_admin(super_admin.into()).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_health() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let health = contract.acl_health().await?;
+    assert_eq!(health.warnings, vec![HealthWarning::NoSuperAdmin]);
+    assert!(health.is_critical());
+
+    setup.new_super_admin_account().await?;
+    setup.new_account_with_roles(&["LevelA"]).await?;
+    setup.new_account_with_roles(&["LevelA", "LevelB"]).await?;
+    setup.new_account_as_admin(&["LevelB"]).await?;
+    let health = contract.acl_health().await?;
+    assert_eq!(
+        health.warnings,
+        vec![
+            HealthWarning::SingleSuperAdmin,
+            HealthWarning::RoleWithoutAdmins {
+                role: "LevelA".to_string(),
+                grantees: 2,
+            },
+        ]
+    );
+    assert!(!health.is_critical());
+
+    // Resolving the risks clears the warnings.
+    setup.new_super_admin_account().await?;
+    setup.new_account_as_admin(&["LevelA"]).await?;
+    let health = contract.acl_health().await?;
+    assert_eq!(health, AclHealth::default());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_num_admins_and_grantees() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    assert_eq!(contract.acl_num_admins("LevelA").await?, 0);
+    assert_eq!(contract.acl_num_grantees("LevelA").await?, 0);
+
+    setup.new_super_admin_account().await?;
+    setup.new_account_as_admin(&["LevelA"]).await?;
+    setup.new_account_as_admin(&["LevelA", "LevelB"]).await?;
+    let grantee = setup.new_account_with_roles(&["LevelA"]).await?;
+    setup.new_account_with_roles(&["LevelA", "LevelB"]).await?;
+
+    // Super-admins are not counted as admins.
+    assert_eq!(contract.acl_num_admins("LevelA").await?, 2);
+    assert_eq!(contract.acl_num_admins("LevelB").await?, 1);
+    assert_eq!(contract.acl_num_admins("LevelC").await?, 0);
+    assert_eq!(contract.acl_num_grantees("LevelA").await?, 2);
+    assert_eq!(contract.acl_num_grantees("LevelB").await?, 1);
+
+    contract
+        .acl_revoke_role_unchecked(Caller::Contract, "LevelA", grantee.id())
+        .await?
+        .into_result()?;
+    assert_eq!(contract.acl_num_grantees("LevelA").await?, 1);
+
+    Ok(())
+}
+
+/// Returns the `correlation_id` of every `role_granted`, `role_revoked`,
+/// `role_cleared` and `admin_revoked` event emitted by `res`.
+fn role_event_correlation_ids(res: &ExecutionFinalResult) -> Vec<Option<String>> {
+    res.logs()
+        .into_iter()
+        .filter_map(|log| log.strip_prefix("EVENT_JSON:"))
+        .filter_map(|event| {
+            near_sdk::serde_json::from_str::<near_sdk::serde_json::Value>(event).ok()
+        })
+        .filter(|event| {
+            [
+                "role_granted",
+                "role_revoked",
+                "role_cleared",
+                "admin_revoked",
+            ]
+            .contains(&event["event"].as_str().unwrap_or_default())
+        })
+        .map(|event| event["data"]["correlation_id"].as_str().map(String::from))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_acl_batch_correlation_id() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = setup.contract.contract();
+    let role = "LevelA";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let old = setup.new_account_with_roles(&[role]).await?;
+    let new = setup.worker.dev_create_account().await?;
+
+    // All events of a bulk transfer share the correlation id.
+    let res = admin
+        .call(contract.id(), "acl_transfer_role_bulk")
+        .args_json(json!({
+            "role": role,
+            "mappings": [(old.id(), new.id())],
+            "correlation_id": "proposal-1",
+        }))
+        .max_gas()
+        .transact()
+        .await?;
+    let ids = role_event_correlation_ids(&res);
+    assert_eq!(ids, vec![Some("proposal-1".to_string()); 2]);
+    assert_success_with(res, Some(vec![true]));
+
+    // Likewise for revoking from all grantees.
+    setup.new_account_with_roles(&[role]).await?;
+    let res = admin
+        .call(contract.id(), "acl_revoke_role_from_all")
+        .args_json(json!({
+            "role": role,
+            "limit": 10,
+            "correlation_id": "proposal-2",
+        }))
+        .max_gas()
+        .transact()
+        .await?;
+    let ids = role_event_correlation_ids(&res);
+    assert_eq!(ids, vec![Some("proposal-2".to_string())]);
+
+    // Likewise for granting to several accounts.
+    let res = admin
+        .call(contract.id(), "acl_grant_roles")
+        .args_json(json!({
+            "role": role,
+            "account_ids": [old.id(), new.id()],
+            "correlation_id": "proposal-3",
+        }))
+        .max_gas()
+        .transact()
+        .await?;
+    let ids = role_event_correlation_ids(&res);
+    assert_eq!(ids, vec![Some("proposal-3".to_string()); 2]);
+
+    // Likewise for revoking roles and admin permissions from several
+    // accounts.
+    let res = admin
+        .call(contract.id(), "acl_revoke_role_batch")
+        .args_json(json!({
+            "role": role,
+            "account_ids": [old.id(), new.id()],
+            "correlation_id": "proposal-4",
+        }))
+        .max_gas()
+        .transact()
+        .await?;
+    let ids = role_event_correlation_ids(&res);
+    assert_eq!(ids, vec![Some("proposal-4".to_string()); 2]);
+    let other_admin = setup.new_account_as_admin(&[role]).await?;
+    let res = admin
+        .call(contract.id(), "acl_revoke_admin_batch")
+        .args_json(json!({
+            "role": role,
+            "account_ids": [other_admin.id()],
+            "correlation_id": "proposal-5",
+        }))
+        .max_gas()
+        .transact()
+        .await?;
+    let ids = role_event_correlation_ids(&res);
+    assert_eq!(ids, vec![Some("proposal-5".to_string())]);
+
+    // Likewise for membership changes.
+    let res = admin
+        .call(contract.id(), "acl_apply_membership_change")
+        .args_json(json!({
+            "role": role,
+            "add": [old.id()],
+            "remove": [],
+            "correlation_id": "proposal-6",
+        }))
+        .max_gas()
+        .transact()
+        .await?;
+    let ids = role_event_correlation_ids(&res);
+    assert_eq!(ids, vec![Some("proposal-6".to_string())]);
+
+    // Single operations don't carry a correlation id.
+    let res = admin
+        .call(contract.id(), "acl_grant_role")
+        .args_json(json!({ "role": role, "account_id": new.id() }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert_eq!(role_event_correlation_ids(&res), vec![None]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_renounce_admin_last_admin() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let role = "LevelB";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    setup.new_account_with_roles(&[role]).await?;
+
+    // The last admin may not renounce while grantees remain.
+    for force in [None, Some(false)] {
+        let res = contract
+            .acl_renounce_admin(admin.clone().into(), role, force)
+            .await?;
+        assert!(!res);
+        contract.assert_acl_is_admin(true, role, admin.id()).await;
+    }
+
+    // With another admin, renouncing is allowed.
+    let other_admin = setup.new_account_as_admin(&[role]).await?;
+    let res = contract
+        .acl_renounce_admin(other_admin.clone().into(), role, None)
+        .await?;
+    assert!(res);
+    contract
+        .assert_acl_is_admin(false, role, other_admin.id())
+        .await;
+
+    // Forcing allows the last admin to renounce.
+    let res = contract
+        .acl_renounce_admin(admin.clone().into(), role, Some(true))
+        .await?;
+    assert!(res);
+    contract.assert_acl_is_admin(false, role, admin.id()).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_bootstrap_role() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let role = "LevelB";
+    let super_admin = setup.new_super_admin_account().await?;
+    let first_admin = setup.worker.dev_create_account().await?;
+    let admin_a = setup.new_account_as_admin(&["LevelA"]).await?;
+
+    // Only super-admins may bootstrap a role.
+    let res = contract
+        .acl_bootstrap_role(admin_a.into(), role, first_admin.id())
+        .await?;
+    assert_eq!(res, None);
+    contract
+        .assert_acl_is_admin(false, role, first_admin.id())
+        .await;
+
+    let res = super_admin
+        .call(contract.contract().id(), "acl_bootstrap_role")
+        .args_json(json!({ "role": role, "first_admin": first_admin.id() }))
+        .max_gas()
+        .transact()
+        .await?;
+    let has_admin_added_event = res
+        .logs()
+        .into_iter()
+        .filter_map(|log| log.strip_prefix("EVENT_JSON:"))
+        .filter_map(|event| {
+            near_sdk::serde_json::from_str::<near_sdk::serde_json::Value>(event).ok()
+        })
+        .any(|event| event["event"] == "admin_added");
+    assert!(has_admin_added_event);
+    assert_success_with(res, Some(true));
+    contract
+        .assert_acl_is_admin(true, role, first_admin.id())
+        .await;
+
+    // Bootstrapping a role which has admins is rejected.
+    let other = setup.worker.dev_create_account().await?;
+    let res = contract
+        .acl_bootstrap_role(super_admin.into(), role, other.id())
+        .await?;
+    assert_eq!(res, Some(false));
+    contract.assert_acl_is_admin(false, role, other.id()).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_case_insensitive_roles() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "LevelA";
+    setup
+        .contract
+        .acl_grant_role_unchecked(Caller::Contract, role, setup.account.id())
+        .await?
+        .into_result()?;
+
+    // Role names are case-sensitive by default.
+    let res = setup
+        .contract
+        .contract()
+        .view("acl_has_role")
+        .args_json(json!({ "role": "levela", "account_id": setup.account.id() }))
+        .await;
+    let err = format!("{}", res.unwrap_err());
+    assert!(err.contains("Value does not correspond to a role"));
+
+    // With `case_insensitive_roles`, "levela" matches "LevelA".
+    let wasm = common::repo::compile_project(&Path::new(OPTIONS_PROJECT_PATH)).await?;
+    let contract = AccessControllableContract::new(setup.worker.dev_deploy(&wasm).await?);
+    contract
+        .acl_grant_role_unchecked(Caller::Contract, role, setup.account.id())
+        .await?
+        .into_result()?;
+    for name in ["LevelA", "levela", "LEVELA"] {
+        contract
+            .assert_acl_has_role(true, name, setup.account.id())
+            .await;
+    }
+    contract
+        .assert_acl_has_role(false, "levelb", setup.account.id())
+        .await;
+    assert!(contract.acl_role_exists("levela").await?);
+    assert!(!contract.acl_role_exists("levelz").await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_super_admin_only_roles() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let wasm = common::repo::compile_project(&Path::new(OPTIONS_PROJECT_PATH)).await?;
+    let contract = AccessControllableContract::new(setup.worker.dev_deploy(&wasm).await?);
+    let admin = setup.worker.dev_create_account().await?;
+    let super_admin = setup.worker.dev_create_account().await?;
+    for role in ["LevelA", "LevelB"] {
+        contract
+            .acl_add_admin_unchecked(Caller::Contract, role, admin.id())
+            .await?
+            .into_result()?;
+    }
+    contract
+        .acl_add_super_admin_unchecked(Caller::Contract, super_admin.id())
+        .await?
+        .into_result()?;
+
+    // Admins manage roles which are not listed in `super_admin_only_roles`.
+    let res = contract
+        .acl_grant_role(admin.clone().into(), "LevelA", setup.account.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    let res = contract
+        .acl_revoke_role(admin.clone().into(), "LevelA", setup.account.id())
+        .await?;
+    assert_eq!(res, Some(true));
+
+    // `LevelB` may be managed only by super-admins.
+    contract
+        .assert_acl_is_admin(false, "LevelB", admin.id())
+        .await;
+    let res = contract
+        .acl_grant_role(admin.clone().into(), "LevelB", setup.account.id())
+        .await?;
+    assert_eq!(res, None);
+    let res = contract
+        .acl_grant_role(super_admin.clone().into(), "LevelB", setup.account.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    let res = contract
+        .acl_revoke_role(admin.into(), "LevelB", setup.account.id())
+        .await?;
+    assert_eq!(res, None);
+    let res = contract
+        .acl_revoke_role(super_admin.into(), "LevelB", setup.account.id())
+        .await?;
+    assert_eq!(res, Some(true));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_storage_prefix() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let wasm = common::repo::compile_project(&Path::new(OPTIONS_PROJECT_PATH)).await?;
+    let options_contract = AccessControllableContract::new(setup.worker.dev_deploy(&wasm).await?);
+    for contract in [&setup.contract, &options_contract] {
+        contract
+            .acl_grant_role_unchecked(Caller::Contract, "LevelA", setup.account.id())
+            .await?
+            .into_result()?;
+    }
+
+    // Collections of the default-prefixed contract don't use the custom prefix.
+    let state = setup.contract.contract().view_state(None).await?;
+    let acl_keys = state
+        .keys()
+        .filter(|key| key.starts_with(b"__acl"))
+        .collect::<Vec<_>>();
+    assert!(!acl_keys.is_empty());
+    assert!(acl_keys
+        .iter()
+        .all(|key| !key.starts_with(b"__acl_options")));
+
+    // All collections of the contract with a custom prefix use it.
+    let state = options_contract.contract().view_state(None).await?;
+    let keys = state
+        .keys()
+        .filter(|key| key.as_slice() != b"STATE")
+        .collect::<Vec<_>>();
+    assert!(!keys.is_empty());
+    assert!(keys.iter().all(|key| key.starts_with(b"__acl_options")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_init_from_manifest() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = setup.contract.contract();
+    let super_admin = setup.worker.dev_create_account().await?;
+    let admin = setup.worker.dev_create_account().await?;
+    let grantee = setup.worker.dev_create_account().await?;
+    let init = |caller: Account, manifest: AclManifest| async move {
+        caller
+            .call(contract.id(), "acl_init_from_manifest")
+            .args_json(json!({ "manifest": manifest }))
+            .max_gas()
+            .transact()
+            .await
+    };
+    // `AclManifest` holds account ids of `near_sdk`.
+    let sdk_id =
+        |account: &Account| -> near_sdk::AccountId { account.id().as_str().parse().unwrap() };
+    let manifest = AclManifest {
+        super_admins: vec![sdk_id(&super_admin)],
+        admins: vec![("LevelA".to_string(), sdk_id(&admin))],
+        grantees: vec![
+            ("LevelA".to_string(), sdk_id(&grantee)),
+            ("LevelB".to_string(), sdk_id(&grantee)),
+        ],
+    };
+
+    // Only the contract itself may apply a manifest.
+    let res = init(setup.account.clone(), manifest.clone()).await?;
+    assert_private_method_failure(res, "acl_init_from_manifest");
+
+    // Invalid manifests are rejected without modifying state.
+    let before = setup.contract.acl_state_snapshot(&ALL_ROLES).await?;
+    let mut unknown_role = manifest.clone();
+    unknown_role
+        .grantees
+        .push(("LevelZ".to_string(), sdk_id(&grantee)));
+    let mut duplicate = manifest.clone();
+    duplicate
+        .admins
+        .push(("LevelA".to_string(), sdk_id(&admin)));
+    let no_super_admin = AclManifest {
+        super_admins: vec![],
+        ..manifest.clone()
+    };
+    for (invalid, expected_err) in [
+        (unknown_role, "Value does not correspond to a role"),
+        (duplicate, "AclManifest: duplicate admin"),
+        (
+            no_super_admin,
+            "AclManifest: at least one super-admin is required",
+        ),
+    ] {
+        let res = init(contract.as_account().clone(), invalid).await?;
+        let err = format!("{}", res.into_result().unwrap_err());
+        assert!(err.contains(expected_err), "unexpected error: {}", err);
+    }
+    let after = setup.contract.acl_state_snapshot(&ALL_ROLES).await?;
+    assert_acl_state_unchanged(before, after);
+
+    // A valid manifest produces the expected state.
+    let res = init(contract.as_account().clone(), manifest.clone()).await?;
+    assert_success_with(res, true);
+    let snapshot = setup.contract.acl_state_snapshot(&ALL_ROLES).await?;
+    let mut admins = snapshot.admins;
+    admins.sort();
+    assert_eq!(
+        admins,
+        vec![
+            ("LevelA".to_string(), admin.id().clone()),
+            (
+                ACL_SUPER_ADMIN_ROLE_MARKER.to_string(),
+                super_admin.id().clone()
+            ),
+        ]
+    );
+    let grantees: Vec<_> = snapshot
+        .grantees
+        .into_iter()
+        .filter(|(_, grantees)| !grantees.is_empty())
+        .collect();
+    assert_eq!(
+        grantees,
+        vec![
+            ("LevelA".to_string(), vec![grantee.id().clone()]),
+            ("LevelB".to_string(), vec![grantee.id().clone()]),
+        ]
+    );
+
+    // A manifest can be applied only once.
+    let res = init(contract.as_account().clone(), manifest).await?;
+    assert_success_with(res, false);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_revoke_batch() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let role = "LevelA";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let grantee_a = setup.new_account_with_roles(&[role]).await?;
+    let grantee_b = setup.new_account_with_roles(&[role]).await?;
+    let other_admin = setup.new_account_as_admin(&[role]).await?;
+    let non_grantee = setup.worker.dev_create_account().await?;
+    let accounts = [grantee_a.id(), non_grantee.id(), grantee_b.id()];
+
+    // Without permissions nothing is revoked.
+    let res = contract
+        .acl_revoke_role_batch(setup.account.clone().into(), role, &accounts)
+        .await?;
+    assert_eq!(res, None);
+    let res = contract
+        .acl_revoke_admin_batch(setup.account.clone().into(), role, &[other_admin.id()])
+        .await?;
+    assert_eq!(res, None);
+    contract
+        .assert_acl_has_role(true, role, grantee_a.id())
+        .await;
+
+    // Revoke from a mix of grantees and non-grantees.
+    let res = contract
+        .acl_revoke_role_batch(admin.clone().into(), role, &accounts)
+        .await?;
+    assert_eq!(res, Some(vec![true, false, true]));
+    for account in accounts {
+        contract.assert_acl_has_role(false, role, account).await;
+    }
+
+    // Revoke admin permissions from a mix of admins and non-admins.
+    let res = contract
+        .acl_revoke_admin_batch(
+            admin.clone().into(),
+            role,
+            &[other_admin.id(), non_grantee.id()],
+        )
+        .await?;
+    assert_eq!(res, Some(vec![true, false]));
+    contract
+        .assert_acl_is_admin(false, role, other_admin.id())
+        .await;
+
+    // The number of accounts is capped.
+    let too_many = vec![non_grantee.id(); ACL_REVOKE_BATCH_MAX_LEN + 1];
+    let res = contract
+        .acl_revoke_role_batch(admin.into(), role, &too_many)
+        .await;
+    let err = format!("{}", res.unwrap_err());
+    assert!(err.contains("Batch revoke accepts at most"));
+
+    Ok(())
+}
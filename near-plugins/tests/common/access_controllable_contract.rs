@@ -1,3 +1,7 @@
+use near_plugins::access_controllable::{
+    AclHealth, AclResult, RevokeRoleFromAllProgress, SuperAdminTransfer,
+};
+use near_plugins::pagination::Page;
 use near_sdk::serde_json::json;
 use workspaces::result::ExecutionFinalResult;
 use workspaces::{Account, AccountId, Contract};
@@ -17,6 +21,16 @@ impl From<Account> for Caller {
     }
 }
 
+/// Snapshot of a contract's ACL state, assembled from view methods.
+#[derive(Debug, PartialEq)]
+pub struct AclStateSnapshot {
+    /// All `(role, admin)` pairs including super-admins, as returned by
+    /// `acl_get_all_admins`.
+    pub admins: Vec<(String, AccountId)>,
+    /// The grantees of each role.
+    pub grantees: Vec<(String, Vec<AccountId>)>,
+}
+
 /// Wrapper for a contract that is `#[access_controllable]`. It allows
 /// implementing helpers for calling contract methods.
 pub struct AccessControllableContract {
@@ -63,6 +77,35 @@ impl AccessControllableContract {
         assert_eq!(is_super_admin, expected);
     }
 
+    pub async fn acl_get_storage_version(&self, caller: Caller) -> anyhow::Result<Option<u32>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_get_storage_version")
+            .args_json(())
+            .view()
+            .await?;
+        Ok(res.json::<Option<u32>>()?)
+    }
+
+    pub async fn acl_set_storage_version(
+        &self,
+        caller: Caller,
+        version: u32,
+    ) -> anyhow::Result<bool> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_set_storage_version")
+            .args_json(json!({
+                "version": version,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<bool>()?;
+        Ok(res)
+    }
+
     pub async fn acl_init_super_admin(
         &self,
         caller: Caller,
@@ -93,6 +136,86 @@ impl AccessControllableContract {
             .await
     }
 
+    pub async fn acl_revoke_super_admin(
+        &self,
+        caller: Caller,
+        account_id: &AccountId,
+        reason: Option<&str>,
+    ) -> workspaces::Result<ExecutionFinalResult> {
+        self.account(caller)
+            .call(self.contract.id(), "acl_revoke_super_admin")
+            .args_json(json!({
+                "account_id": account_id,
+                "reason": reason,
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn acl_renounce_super_admin(
+        &self,
+        caller: Caller,
+    ) -> workspaces::Result<ExecutionFinalResult> {
+        self.account(caller)
+            .call(self.contract.id(), "acl_renounce_super_admin")
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn acl_num_super_admins(&self) -> anyhow::Result<u64> {
+        let res = self.contract.view("acl_num_super_admins").await?;
+        Ok(res.json::<u64>()?)
+    }
+
+    pub async fn acl_propose_super_admin_transfer(
+        &self,
+        caller: Caller,
+        account_id: &AccountId,
+    ) -> workspaces::Result<ExecutionFinalResult> {
+        self.account(caller)
+            .call(self.contract.id(), "acl_propose_super_admin_transfer")
+            .args_json(json!({
+                "account_id": account_id,
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn acl_accept_super_admin_transfer(
+        &self,
+        caller: Caller,
+    ) -> workspaces::Result<ExecutionFinalResult> {
+        self.account(caller)
+            .call(self.contract.id(), "acl_accept_super_admin_transfer")
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn acl_cancel_super_admin_transfer(
+        &self,
+        caller: Caller,
+    ) -> workspaces::Result<ExecutionFinalResult> {
+        self.account(caller)
+            .call(self.contract.id(), "acl_cancel_super_admin_transfer")
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn acl_get_pending_super_admin_transfer(
+        &self,
+    ) -> anyhow::Result<Option<SuperAdminTransfer>> {
+        let res = self
+            .contract
+            .view("acl_get_pending_super_admin_transfer")
+            .await?;
+        Ok(res.json::<Option<SuperAdminTransfer>>()?)
+    }
+
     pub async fn acl_revoke_super_admin_unchecked(
         &self,
         caller: Caller,
@@ -126,6 +249,22 @@ impl AccessControllableContract {
         Ok(res.json::<bool>()?)
     }
 
+    pub async fn acl_is_admin_of_any(
+        &self,
+        roles: &[&str],
+        account_id: &AccountId,
+    ) -> anyhow::Result<bool> {
+        let res = self
+            .contract
+            .view("acl_is_admin_of_any")
+            .args_json(json!({
+                "roles": roles,
+                "account_id": account_id,
+            }))
+            .await?;
+        Ok(res.json::<bool>()?)
+    }
+
     pub async fn assert_acl_is_admin(&self, expected: bool, role: &str, account_id: &AccountId) {
         let is_admin = self
             .acl_is_admin(Caller::Contract, role, account_id)
@@ -193,12 +332,39 @@ impl AccessControllableContract {
         Ok(res)
     }
 
-    pub async fn acl_renounce_admin(&self, caller: Caller, role: &str) -> anyhow::Result<bool> {
+    pub async fn acl_bootstrap_role(
+        &self,
+        caller: Caller,
+        role: &str,
+        first_admin: &AccountId,
+    ) -> anyhow::Result<Option<bool>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_bootstrap_role")
+            .args_json(json!({
+                "role": role,
+                "first_admin": first_admin,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Option<bool>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_renounce_admin(
+        &self,
+        caller: Caller,
+        role: &str,
+        force: Option<bool>,
+    ) -> anyhow::Result<bool> {
         let res = self
             .account(caller)
             .call(self.contract.id(), "acl_renounce_admin")
             .args_json(json!({
                 "role": role,
+                "force": force,
             }))
             .max_gas()
             .transact()
@@ -243,6 +409,22 @@ impl AccessControllableContract {
         Ok(res.json::<bool>()?)
     }
 
+    pub async fn acl_has_all_roles(
+        &self,
+        roles: &[&str],
+        account_id: &AccountId,
+    ) -> anyhow::Result<bool> {
+        let res = self
+            .contract
+            .view("acl_has_all_roles")
+            .args_json(json!({
+                "roles": roles,
+                "account_id": account_id,
+            }))
+            .await?;
+        Ok(res.json::<bool>()?)
+    }
+
     pub async fn assert_acl_has_role(&self, expected: bool, role: &str, account_id: &AccountId) {
         let has_role = self
             .acl_has_role(Caller::Contract, role, account_id)
@@ -272,32 +454,40 @@ impl AccessControllableContract {
         Ok(res)
     }
 
-    pub async fn acl_grant_role_unchecked(
+    pub async fn acl_grant_role_and_list(
         &self,
         caller: Caller,
         role: &str,
         account_id: &AccountId,
-    ) -> workspaces::Result<ExecutionFinalResult> {
-        self.account(caller)
-            .call(self.contract.id(), "acl_grant_role_unchecked")
+        skip: u64,
+        limit: u64,
+    ) -> anyhow::Result<Option<Vec<AccountId>>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_grant_role_and_list")
             .args_json(json!({
                 "role": role,
                 "account_id": account_id,
+                "skip": skip,
+                "limit": limit,
             }))
             .max_gas()
             .transact()
-            .await
+            .await?
+            .into_result()?
+            .json::<Option<Vec<AccountId>>>()?;
+        Ok(res)
     }
 
-    pub async fn acl_revoke_role(
+    pub async fn acl_grant_role_checked(
         &self,
         caller: Caller,
         role: &str,
         account_id: &AccountId,
-    ) -> anyhow::Result<Option<bool>> {
+    ) -> anyhow::Result<AclResult> {
         let res = self
             .account(caller)
-            .call(self.contract.id(), "acl_revoke_role")
+            .call(self.contract.id(), "acl_grant_role_checked")
             .args_json(json!({
                 "role": role,
                 "account_id": account_id,
@@ -306,85 +496,845 @@ impl AccessControllableContract {
             .transact()
             .await?
             .into_result()?
-            .json::<Option<bool>>()?;
+            .json::<AclResult>()?;
         Ok(res)
     }
 
-    pub async fn acl_renounce_role(&self, caller: Caller, role: &str) -> anyhow::Result<bool> {
+    pub async fn acl_revoke_role_checked(
+        &self,
+        caller: Caller,
+        role: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<AclResult> {
         let res = self
             .account(caller)
-            .call(self.contract.id(), "acl_renounce_role")
+            .call(self.contract.id(), "acl_revoke_role_checked")
             .args_json(json!({
                 "role": role,
+                "account_id": account_id,
             }))
             .max_gas()
             .transact()
             .await?
             .into_result()?
-            .json::<bool>()?;
+            .json::<AclResult>()?;
         Ok(res)
     }
 
-    pub async fn acl_revoke_role_unchecked(
+    pub async fn acl_grant_consumable_role(
+        &self,
+        caller: Caller,
+        role: &str,
+        account_id: &AccountId,
+        uses: u32,
+    ) -> anyhow::Result<Option<bool>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_grant_consumable_role")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+                "uses": uses,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Option<bool>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_grant_role_with_expiry(
         &self,
         caller: Caller,
         role: &str,
         account_id: &AccountId,
+        expires_at_ns: u64,
     ) -> workspaces::Result<ExecutionFinalResult> {
         self.account(caller)
-            .call(self.contract.id(), "acl_revoke_role_unchecked")
+            .call(self.contract.id(), "acl_grant_role_with_expiry")
             .args_json(json!({
                 "role": role,
                 "account_id": account_id,
+                "expires_at_ns": expires_at_ns,
             }))
             .max_gas()
             .transact()
             .await
     }
 
-    pub async fn acl_get_admins(
+    pub async fn acl_get_role_expiry(
+        &self,
+        role: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<Option<u64>> {
+        let res = self
+            .contract
+            .view("acl_get_role_expiry")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+            }))
+            .await?;
+        Ok(res.json::<Option<u64>>()?)
+    }
+
+    pub async fn acl_allow_assume_role(
         &self,
         caller: Caller,
         role: &str,
-        skip: u64,
-        limit: u64,
-    ) -> anyhow::Result<Vec<AccountId>> {
+        account_id: &AccountId,
+    ) -> anyhow::Result<Option<bool>> {
         let res = self
             .account(caller)
-            .call(self.contract.id(), "acl_get_admins")
+            .call(self.contract.id(), "acl_allow_assume_role")
             .args_json(json!({
                 "role": role,
-                "skip": skip,
-                "limit": limit,
+                "account_id": account_id,
             }))
             .max_gas()
             .transact()
             .await?
             .into_result()?
-            .json::<Vec<AccountId>>()?;
+            .json::<Option<bool>>()?;
         Ok(res)
     }
 
-    pub async fn acl_get_grantees(
+    pub async fn acl_disallow_assume_role(
         &self,
         caller: Caller,
         role: &str,
-        skip: u64,
-        limit: u64,
-    ) -> anyhow::Result<Vec<AccountId>> {
+        account_id: &AccountId,
+    ) -> anyhow::Result<Option<bool>> {
         let res = self
             .account(caller)
-            .call(self.contract.id(), "acl_get_grantees")
+            .call(self.contract.id(), "acl_disallow_assume_role")
             .args_json(json!({
                 "role": role,
-                "skip": skip,
-                "limit": limit,
+                "account_id": account_id,
             }))
             .max_gas()
             .transact()
             .await?
             .into_result()?
-            .json::<Vec<AccountId>>()?;
+            .json::<Option<bool>>()?;
         Ok(res)
     }
+
+    pub async fn acl_estimate_grant_storage(
+        &self,
+        caller: Caller,
+        role: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<u64> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_estimate_grant_storage")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<u64>()?)
+    }
+
+    pub async fn acl_get_remaining_uses(
+        &self,
+        caller: Caller,
+        role: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<Option<u32>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_get_remaining_uses")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<Option<u32>>()?)
+    }
+
+    pub async fn acl_grant_role_unchecked(
+        &self,
+        caller: Caller,
+        role: &str,
+        account_id: &AccountId,
+    ) -> workspaces::Result<ExecutionFinalResult> {
+        self.account(caller)
+            .call(self.contract.id(), "acl_grant_role_unchecked")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn acl_set_handle_registry(
+        &self,
+        caller: Caller,
+        registry: Option<&AccountId>,
+    ) -> anyhow::Result<bool> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_set_handle_registry")
+            .args_json(json!({
+                "registry": registry,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<bool>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_grant_role_by_handle(
+        &self,
+        caller: Caller,
+        role: &str,
+        handle: &str,
+    ) -> workspaces::Result<ExecutionFinalResult> {
+        self.account(caller)
+            .call(self.contract.id(), "acl_grant_role_by_handle")
+            .args_json(json!({
+                "role": role,
+                "handle": handle,
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn acl_grant_role_scoped(
+        &self,
+        caller: Caller,
+        role: &str,
+        scope: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<Option<bool>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_grant_role_scoped")
+            .args_json(json!({
+                "role": role,
+                "scope": scope,
+                "account_id": account_id,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Option<bool>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_revoke_role_scoped(
+        &self,
+        caller: Caller,
+        role: &str,
+        scope: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<Option<bool>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_revoke_role_scoped")
+            .args_json(json!({
+                "role": role,
+                "scope": scope,
+                "account_id": account_id,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Option<bool>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_has_role_scoped(
+        &self,
+        caller: Caller,
+        role: &str,
+        scope: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<bool> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_has_role_scoped")
+            .args_json(json!({
+                "role": role,
+                "scope": scope,
+                "account_id": account_id,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<bool>()?)
+    }
+
+    pub async fn acl_revoke_role(
+        &self,
+        caller: Caller,
+        role: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<Option<bool>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_revoke_role")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Option<bool>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_revoke_role_notify(
+        &self,
+        caller: Caller,
+        role: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<Option<bool>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_revoke_role_notify")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Option<bool>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_ensure_role_absent(
+        &self,
+        caller: Caller,
+        role: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<Option<bool>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_ensure_role_absent")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Option<bool>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_revoke_role_from_all(
+        &self,
+        caller: Caller,
+        role: &str,
+        limit: u64,
+    ) -> anyhow::Result<Option<RevokeRoleFromAllProgress>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_revoke_role_from_all")
+            .args_json(json!({
+                "role": role,
+                "limit": limit,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Option<RevokeRoleFromAllProgress>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_renounce_role(&self, caller: Caller, role: &str) -> anyhow::Result<bool> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_renounce_role")
+            .args_json(json!({
+                "role": role,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<bool>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_revoke_role_unchecked(
+        &self,
+        caller: Caller,
+        role: &str,
+        account_id: &AccountId,
+    ) -> workspaces::Result<ExecutionFinalResult> {
+        self.account(caller)
+            .call(self.contract.id(), "acl_revoke_role_unchecked")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn acl_which_role(
+        &self,
+        caller: Caller,
+        roles: Vec<&str>,
+        account_id: &AccountId,
+    ) -> anyhow::Result<Option<String>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_which_role")
+            .args_json(json!({
+                "roles": roles,
+                "account_id": account_id,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<Option<String>>()?)
+    }
+
+    pub async fn acl_get_super_admins(
+        &self,
+        caller: Caller,
+        skip: u64,
+        limit: u64,
+    ) -> anyhow::Result<Vec<AccountId>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_get_super_admins")
+            .args_json(json!({
+                "skip": skip,
+                "limit": limit,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Vec<AccountId>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_get_admins(
+        &self,
+        caller: Caller,
+        role: &str,
+        skip: u64,
+        limit: u64,
+    ) -> anyhow::Result<Vec<AccountId>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_get_admins")
+            .args_json(json!({
+                "role": role,
+                "skip": skip,
+                "limit": limit,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Vec<AccountId>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_get_grantees(
+        &self,
+        caller: Caller,
+        role: &str,
+        skip: u64,
+        limit: u64,
+    ) -> anyhow::Result<Vec<AccountId>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_get_grantees")
+            .args_json(json!({
+                "role": role,
+                "skip": skip,
+                "limit": limit,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Vec<AccountId>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_num_admins(&self, role: &str) -> anyhow::Result<u64> {
+        self.num_bearers("acl_num_admins", role).await
+    }
+
+    pub async fn acl_num_grantees(&self, role: &str) -> anyhow::Result<u64> {
+        self.num_bearers("acl_num_grantees", role).await
+    }
+
+    async fn num_bearers(&self, method_name: &str, role: &str) -> anyhow::Result<u64> {
+        let res = self
+            .contract
+            .view(method_name)
+            .args_json(json!({
+                "role": role,
+            }))
+            .await?;
+        Ok(res.json::<u64>()?)
+    }
+
+    pub async fn acl_get_all_roles(&self) -> anyhow::Result<Vec<String>> {
+        let res = self.contract.view("acl_get_all_roles").await?;
+        Ok(res.json::<Vec<String>>()?)
+    }
+
+    pub async fn acl_state_checksum(&self) -> anyhow::Result<String> {
+        let res = self.contract.view("acl_state_checksum").await?;
+        Ok(res.json::<String>()?)
+    }
+
+    pub async fn acl_role_exists(&self, role: &str) -> anyhow::Result<bool> {
+        let res = self
+            .contract
+            .view("acl_role_exists")
+            .args_json(json!({
+                "role": role,
+            }))
+            .await?;
+        Ok(res.json::<bool>()?)
+    }
+
+    pub async fn acl_get_all_grantee_counts(&self) -> anyhow::Result<Vec<(String, u64)>> {
+        let res = self.contract.view("acl_get_all_grantee_counts").await?;
+        Ok(res.json::<Vec<(String, u64)>>()?)
+    }
+
+    pub async fn acl_get_admins_page(
+        &self,
+        caller: Caller,
+        role: &str,
+        cursor: Option<String>,
+        limit: u64,
+    ) -> anyhow::Result<Page<AccountId>> {
+        self.get_bearers_page(caller, "acl_get_admins_page", role, cursor, limit)
+            .await
+    }
+
+    pub async fn acl_get_grantees_page(
+        &self,
+        caller: Caller,
+        role: &str,
+        cursor: Option<String>,
+        limit: u64,
+    ) -> anyhow::Result<Page<AccountId>> {
+        self.get_bearers_page(caller, "acl_get_grantees_page", role, cursor, limit)
+            .await
+    }
+
+    async fn get_bearers_page(
+        &self,
+        caller: Caller,
+        method_name: &str,
+        role: &str,
+        cursor: Option<String>,
+        limit: u64,
+    ) -> anyhow::Result<Page<AccountId>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), method_name)
+            .args_json(json!({
+                "role": role,
+                "cursor": cursor,
+                "limit": limit,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Page<AccountId>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_set_role_metadata(
+        &self,
+        caller: Caller,
+        role: &str,
+        key: &str,
+        value: &str,
+    ) -> anyhow::Result<bool> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_set_role_metadata")
+            .args_json(json!({
+                "role": role,
+                "key": key,
+                "value": value,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<bool>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_get_role_metadata(
+        &self,
+        role: &str,
+        key: &str,
+    ) -> anyhow::Result<Option<String>> {
+        let res = self
+            .contract
+            .view("acl_get_role_metadata")
+            .args_json(json!({
+                "role": role,
+                "key": key,
+            }))
+            .await?;
+        Ok(res.json::<Option<String>>()?)
+    }
+
+    pub async fn acl_get_role_metadata_keys(
+        &self,
+        role: &str,
+        cursor: Option<String>,
+        limit: u64,
+    ) -> anyhow::Result<Page<String>> {
+        let res = self
+            .contract
+            .view("acl_get_role_metadata_keys")
+            .args_json(json!({
+                "role": role,
+                "cursor": cursor,
+                "limit": limit,
+            }))
+            .await?;
+        Ok(res.json::<Page<String>>()?)
+    }
+
+    pub async fn acl_emit_snapshot(
+        &self,
+        caller: Caller,
+        skip: u64,
+        limit: u64,
+    ) -> workspaces::Result<ExecutionFinalResult> {
+        self.account(caller)
+            .call(self.contract.id(), "acl_emit_snapshot")
+            .args_json(json!({
+                "skip": skip,
+                "limit": limit,
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn acl_my_roles(&self, caller: Caller) -> anyhow::Result<Vec<String>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_my_roles")
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Vec<String>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_am_i_super_admin(&self, caller: Caller) -> anyhow::Result<bool> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_am_i_super_admin")
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<bool>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_get_roles(&self, account_id: &AccountId) -> anyhow::Result<Vec<String>> {
+        let res = self
+            .contract
+            .view("acl_get_roles")
+            .args_json(json!({
+                "account_id": account_id,
+            }))
+            .await?;
+        Ok(res.json::<Vec<String>>()?)
+    }
+
+    pub async fn acl_health(&self) -> anyhow::Result<AclHealth> {
+        let res = self.contract.view("acl_health").await?;
+        Ok(res.json::<AclHealth>()?)
+    }
+
+    pub async fn acl_grant_roles(
+        &self,
+        caller: Caller,
+        role: &str,
+        account_ids: &[&AccountId],
+    ) -> anyhow::Result<ExecutionFinalResult> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_grant_roles")
+            .args_json(json!({
+                "role": role,
+                "account_ids": account_ids,
+            }))
+            .max_gas()
+            .transact()
+            .await?;
+        Ok(res)
+    }
+
+    pub async fn acl_apply_membership_change(
+        &self,
+        caller: Caller,
+        role: &str,
+        add: &[&AccountId],
+        remove: &[&AccountId],
+    ) -> anyhow::Result<ExecutionFinalResult> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_apply_membership_change")
+            .args_json(json!({
+                "role": role,
+                "add": add,
+                "remove": remove,
+            }))
+            .max_gas()
+            .transact()
+            .await?;
+        Ok(res)
+    }
+
+    pub async fn acl_revoke_roles(
+        &self,
+        caller: Caller,
+        role: &str,
+        account_ids: &[&AccountId],
+    ) -> anyhow::Result<ExecutionFinalResult> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_revoke_roles")
+            .args_json(json!({
+                "role": role,
+                "account_ids": account_ids,
+            }))
+            .max_gas()
+            .transact()
+            .await?;
+        Ok(res)
+    }
+
+    pub async fn acl_revoke_role_batch(
+        &self,
+        caller: Caller,
+        role: &str,
+        account_ids: &[&AccountId],
+    ) -> anyhow::Result<Option<Vec<bool>>> {
+        self.revoke_batch(caller, "acl_revoke_role_batch", role, account_ids)
+            .await
+    }
+
+    pub async fn acl_revoke_admin_batch(
+        &self,
+        caller: Caller,
+        role: &str,
+        account_ids: &[&AccountId],
+    ) -> anyhow::Result<Option<Vec<bool>>> {
+        self.revoke_batch(caller, "acl_revoke_admin_batch", role, account_ids)
+            .await
+    }
+
+    async fn revoke_batch(
+        &self,
+        caller: Caller,
+        method_name: &str,
+        role: &str,
+        account_ids: &[&AccountId],
+    ) -> anyhow::Result<Option<Vec<bool>>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), method_name)
+            .args_json(json!({
+                "role": role,
+                "account_ids": account_ids,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Option<Vec<bool>>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_transfer_role_bulk(
+        &self,
+        caller: Caller,
+        role: &str,
+        mappings: &[(&AccountId, &AccountId)],
+    ) -> anyhow::Result<Option<Vec<bool>>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_transfer_role_bulk")
+            .args_json(json!({
+                "role": role,
+                "mappings": mappings,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Option<Vec<bool>>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_get_all_admins(
+        &self,
+        caller: Caller,
+        skip: u64,
+        limit: u64,
+    ) -> anyhow::Result<Vec<(String, AccountId)>> {
+        let res = self
+            .account(caller)
+            .call(self.contract.id(), "acl_get_all_admins")
+            .args_json(json!({
+                "skip": skip,
+                "limit": limit,
+            }))
+            .view()
+            .await?
+            .json::<Vec<(String, AccountId)>>()?;
+        Ok(res)
+    }
+
+    /// Returns a snapshot of the ACL state for `roles`.
+    pub async fn acl_state_snapshot(&self, roles: &[&str]) -> anyhow::Result<AclStateSnapshot> {
+        let admins = self
+            .acl_get_all_admins(Caller::Contract, 0, u64::MAX)
+            .await?;
+        let mut grantees = vec![];
+        for &role in roles {
+            let role_grantees = self
+                .acl_get_grantees(Caller::Contract, role, 0, u64::MAX)
+                .await?;
+            grantees.push((role.to_string(), role_grantees));
+        }
+        Ok(AclStateSnapshot { admins, grantees })
+    }
 }
@@ -34,7 +34,13 @@ async fn add_wasm_target(project_path: &Path, toolchain: &str) -> anyhow::Result
     Ok(())
 }
 
+/// Compiles the contract in `project_path` to wasm. The name of the package is
+/// expected to match the name of the directory.
 pub async fn compile_project(project_path: &Path) -> anyhow::Result<Vec<u8>> {
+    let package = project_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| anyhow::Error::msg("Failed to get package name from project path"))?;
     let toolchain = read_toolchain(project_path).await?;
     add_wasm_target(project_path, &toolchain).await?;
     let output = tokio::process::Command::new("cargo")
@@ -47,18 +53,19 @@ pub async fn compile_project(project_path: &Path) -> anyhow::Result<Vec<u8>> {
             "--release",
             "--no-default-features",
             "-p",
-            "access_controllable",
+            package,
         ])
         .output()
         .await?;
 
     require_success(output)?;
+    let binary_name = format!("{}.wasm", package);
     let binary_path = project_path.join(
         [
             "target",
             "wasm32-unknown-unknown",
             "release",
-            "access_controllable.wasm",
+            binary_name.as_str(),
         ]
         .iter()
         .collect::<PathBuf>(),
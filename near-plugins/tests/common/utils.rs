@@ -1,7 +1,11 @@
+use super::access_controllable_contract::AclStateSnapshot;
 use near_sdk::serde::de::DeserializeOwned;
+use near_sdk::serde_json::Value;
 use std::cmp::PartialEq;
 use std::fmt::Debug;
+use workspaces::network::Sandbox;
 use workspaces::result::ExecutionFinalResult;
+use workspaces::{Contract, Worker};
 
 /// Asserts execution was successful and returned the `expected` value.
 pub fn assert_success_with<T>(res: ExecutionFinalResult, expected: T)
@@ -60,3 +64,75 @@ pub fn assert_insufficient_acl_permissions(
         err,
     );
 }
+
+/// Asserts transaction failure with an error containing `must_contain`.
+pub fn assert_failure_with(res: ExecutionFinalResult, must_contain: &str) {
+    let err = res
+        .into_result()
+        .err()
+        .expect("Transaction should have failed");
+    let err = format!("{}", err);
+    assert!(
+        err.contains(must_contain),
+        "'{}' is not contained in '{}'",
+        must_contain,
+        err,
+    );
+}
+
+/// Asserts that `res` emitted an event of `standard` named `event`, whose data
+/// equals `expected`.
+pub fn assert_event_emitted<E>(res: &ExecutionFinalResult, standard: &str, event: &str, expected: E)
+where
+    E: DeserializeOwned + PartialEq + Debug,
+{
+    let events: Vec<Value> = res
+        .logs()
+        .into_iter()
+        .filter_map(|log| log.strip_prefix("EVENT_JSON:"))
+        .filter_map(|log| near_sdk::serde_json::from_str(log).ok())
+        .collect();
+    let found = events
+        .iter()
+        .filter(|value| value["standard"] == standard && value["event"] == event)
+        .filter_map(|value| near_sdk::serde_json::from_value::<E>(value["data"].clone()).ok())
+        .any(|data| data == expected);
+    assert!(
+        found,
+        "Event '{}' of '{}' with data {:?} not found in {:?}",
+        event, standard, expected, events,
+    );
+}
+
+/// Asserts that the ACL state did not change, e.g. when taking snapshots
+/// before and after a mutation that is expected to fail.
+pub fn assert_acl_state_unchanged(before: AclStateSnapshot, after: AclStateSnapshot) {
+    assert_eq!(before, after, "ACL state should not have changed");
+}
+
+/// Returns the block timestamp in nanoseconds as observed by `contract`. The
+/// contract is expected to expose it via a view method `block_timestamp`.
+pub async fn get_block_timestamp(contract: &Contract) -> anyhow::Result<u64> {
+    let res = contract
+        .as_account()
+        .call(contract.id(), "block_timestamp")
+        .args_json(())
+        .view()
+        .await?;
+    Ok(res.json::<u64>()?)
+}
+
+/// Advances the sandbox by `blocks` and returns the block timestamp observed by
+/// `contract` afterwards, see [`get_block_timestamp`].
+///
+/// Block times are not fixed, so tests of time based features should compute
+/// expected instants from the returned timestamp instead of assuming a
+/// duration per block.
+pub async fn fast_forward(
+    worker: &Worker<Sandbox>,
+    contract: &Contract,
+    blocks: u64,
+) -> anyhow::Result<u64> {
+    worker.fast_forward(blocks).await?;
+    get_block_timestamp(contract).await
+}
@@ -1,6 +1,10 @@
-use near_plugins::events::AsEvent;
-use near_plugins::{access_control, access_control_any, AccessControlRole, AccessControllable};
+use near_plugins::{
+    access_control, access_control_all, access_control_any, assert_all_plugins_initialized,
+    grants_role_on_success, pause, AccessControlRole, AccessControllable, FullAccessKeyFallback,
+    Ownable, Pausable, Upgradable,
+};
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
 use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::{env, log, near_bindgen, AccountId};
 use std::collections::HashMap;
@@ -12,11 +16,24 @@ pub enum Role {
     LevelA,
     LevelB,
     LevelC,
+    #[grant_threshold(2)]
+    LevelD,
 }
 
-#[access_control(role_type = "Role")]
+// The account used as governance is a sub-account of the sandbox's root
+// account, hence it is known before tests run.
+#[access_control(
+    role_type = "Role",
+    block_when_paused,
+    governance = "dao.test.near",
+    bootstrap,
+    group(name = "Managers", roles(Role::LevelB, Role::LevelC))
+)]
 #[near_bindgen]
-#[derive(Default, BorshDeserialize, BorshSerialize)]
+#[derive(
+    Default, BorshDeserialize, BorshSerialize, FullAccessKeyFallback, Ownable, Pausable, Upgradable,
+)]
+#[upgradable(stage_roles(Role::LevelA), deploy_roles(Role::LevelB))]
 pub struct StatusMessage {
     records: HashMap<AccountId, String>,
 }
@@ -55,11 +72,124 @@ impl StatusMessage {
         self.records.get(&account_id).cloned()
     }
 
+    /// Pausable via the key "pausable_greeting", while grantees of
+    /// `Role::LevelA` may still call it.
+    #[pause(except_roles(Role::LevelA))]
+    pub fn pausable_greeting(&self) -> String {
+        "hello pausable".to_string()
+    }
+
     #[access_control_any(roles(Role::LevelA, Role::LevelC))]
     pub fn restricted_greeting(&self) -> String {
         "hello world".to_string()
     }
 
+    /// Like `restricted_greeting` but roles are checked within the scope of
+    /// `tenant`.
+    #[access_control_any(roles(Role::LevelA), scope = "tenant")]
+    pub fn restricted_tenant_greeting(&self, tenant: u64) -> String {
+        format!("hello tenant {}", tenant)
+    }
+
+    /// Like `restricted_greeting` but may modify state, hence it consumes
+    /// uses of consumable roles.
+    #[access_control_any(roles(Role::LevelB))]
+    pub fn restricted_action(&mut self) -> String {
+        "done".to_string()
+    }
+
+    /// Like `restricted_greeting` but panics with a custom message if the
+    /// caller lacks the role.
+    #[access_control_any(roles(Role::LevelB), message = "only LevelB may call this")]
+    pub fn restricted_greeting_with_message(&self) -> String {
+        "hello world".to_string()
+    }
+
+    /// Like `restricted_greeting` but refers to the roles via the group
+    /// "Managers".
+    #[access_control_any(groups("Managers"))]
+    pub fn restricted_greeting_group(&self) -> String {
+        "hello managers".to_string()
+    }
+
+    /// Like `restricted_greeting` but requires both roles.
+    #[access_control_all(roles(Role::LevelA, Role::LevelC))]
+    pub fn restricted_greeting_all(&self) -> String {
+        "hello all".to_string()
+    }
+
+    /// Self-registration which grants `Role::LevelA` to the predecessor, thereby
+    /// unlocking `restricted_greeting`.
+    #[grants_role_on_success(Role::LevelA)]
+    pub fn register(&mut self) -> String {
+        "registered".to_string()
+    }
+
+    /// Grants `Role::LevelB` also when the body returns early.
+    #[grants_role_on_success(Role::LevelB)]
+    pub fn register_or_return_early(&mut self, early: bool) -> String {
+        if early {
+            return "returned early".to_string();
+        }
+        "registered".to_string()
+    }
+
+    /// Fails since `Role::LevelD` requires approvals of multiple admins.
+    #[grants_role_on_success(Role::LevelD)]
+    pub fn register_level_d(&mut self) -> String {
+        "registered".to_string()
+    }
+
+    /// Assumes `role` for the current receipt and returns whether the
+    /// predecessor has `role` afterwards.
+    pub fn assume_role_and_check(&mut self, role: Role) -> bool {
+        self.acl_assume_role(role);
+        self.acl_has_role(role.into(), env::predecessor_account_id())
+    }
+
+    /// Assumes `Role::LevelA` and calls `restricted_greeting` within the same
+    /// execution.
+    pub fn assume_role_and_greet(&mut self) -> String {
+        self.acl_assume_role(Role::LevelA);
+        self.restricted_greeting()
+    }
+
+    /// Panics unless all plugins which require initialization are initialized.
+    pub fn initialized_greeting(&self) -> String {
+        assert_all_plugins_initialized!(self, Ownable, AccessControllable);
+        "initialized".to_string()
+    }
+
+    /// Returns the current block timestamp to support tests of time based
+    /// features.
+    pub fn block_timestamp(&self) -> u64 {
+        env::block_timestamp()
+    }
+
+    /// Returns the bitmask of `roles`, see `Role::bitflag`.
+    pub fn role_bitflag(&self, roles: Vec<String>) -> U128 {
+        U128(Role::bitflag(&roles))
+    }
+
+    /// Checks `repetitions` times whether `account_id` is an admin for
+    /// `role`. If `cached` is true, the admin cache is enabled beforehand.
+    pub fn is_admin_repeatedly(
+        &self,
+        role: Role,
+        account_id: AccountId,
+        repetitions: u64,
+        cached: bool,
+    ) -> bool {
+        if cached {
+            self.__acl.enable_admin_cache();
+        }
+        let mut is_admin = false;
+        for _ in 0..repetitions {
+            is_admin = self.acl_is_admin(role.into(), account_id.clone());
+        }
+        is_admin
+    }
+
     // In addition, `AccessControllable` trait methods can be called directly:
     //
     // ```
@@ -75,11 +205,6 @@ impl StatusMessage {
 /// Exposing internal methods to facilitate integration testing.
 #[near_bindgen]
 impl StatusMessage {
-    #[private]
-    pub fn acl_init_super_admin(&mut self, account_id: ::near_sdk::AccountId) -> bool {
-        self.__acl.init_super_admin(&account_id)
-    }
-
     #[private]
     pub fn acl_add_super_admin_unchecked(&mut self, account_id: AccountId) -> bool {
         self.__acl.add_super_admin_unchecked(&account_id)
@@ -0,0 +1,45 @@
+use near_plugins::{access_control, AccessControlRole, AccessControllable};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{near_bindgen, AccountId};
+use std::convert::TryFrom;
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    LevelA,
+    LevelB,
+}
+
+// Sets optional arguments of `#[access_control]` which are not set by the
+// contract in `../access_controllable`.
+#[access_control(
+    role_type = "Role",
+    storage_prefix = "__acl_options",
+    case_insensitive_roles,
+    super_admin_only_roles(Role::LevelB)
+)]
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+pub struct Counter {
+    counter: u64,
+}
+
+/// Exposing internal methods to facilitate integration testing.
+#[near_bindgen]
+impl Counter {
+    #[private]
+    pub fn acl_add_super_admin_unchecked(&mut self, account_id: AccountId) -> bool {
+        self.__acl.add_super_admin_unchecked(&account_id)
+    }
+
+    #[private]
+    pub fn acl_add_admin_unchecked(&mut self, role: Role, account_id: AccountId) -> bool {
+        self.__acl.add_admin_unchecked(role, &account_id)
+    }
+
+    #[private]
+    pub fn acl_grant_role_unchecked(&mut self, role: Role, account_id: AccountId) -> bool {
+        self.__acl.grant_role_unchecked(role, &account_id)
+    }
+}
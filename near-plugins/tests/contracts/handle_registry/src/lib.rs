@@ -0,0 +1,22 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::{near_bindgen, AccountId};
+use std::collections::HashMap;
+
+/// Mock of a registry resolving handles to account ids, used to test
+/// `AccessControllable::acl_grant_role_by_handle`.
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+pub struct HandleRegistry {
+    handles: HashMap<String, AccountId>,
+}
+
+#[near_bindgen]
+impl HandleRegistry {
+    pub fn register(&mut self, handle: String, account_id: AccountId) {
+        self.handles.insert(handle, account_id);
+    }
+
+    pub fn resolve(&self, handle: String) -> Option<AccountId> {
+        self.handles.get(&handle).cloned()
+    }
+}
@@ -0,0 +1,23 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::{env, near_bindgen, AccountId};
+
+/// Mock of a contract receiving notifications about revoked roles, used to
+/// test `AccessControllable::acl_revoke_role_notify`.
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+pub struct NotificationReceiver {
+    /// Pairs of the notifying account and the revoked role.
+    notifications: Vec<(AccountId, String)>,
+}
+
+#[near_bindgen]
+impl NotificationReceiver {
+    pub fn on_role_revoked(&mut self, role: String) {
+        self.notifications
+            .push((env::predecessor_account_id(), role));
+    }
+
+    pub fn get_notifications(&self) -> Vec<(AccountId, String)> {
+        self.notifications.clone()
+    }
+}
@@ -0,0 +1,34 @@
+use near_plugins::{Ownable, Upgradable};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::{env, near_bindgen, PanicOnDefault};
+
+/// Contract used to test `Upgradable`. Tests may deploy its code once more,
+/// calling `migrate` to mimic a state migration of new code.
+#[near_bindgen]
+#[derive(PanicOnDefault, BorshDeserialize, BorshSerialize, Ownable, Upgradable)]
+pub struct Counter {
+    version: u32,
+}
+
+#[near_bindgen]
+impl Counter {
+    /// Makes the predecessor the owner. Since `Ownable` requires a contract
+    /// without owner to set the owner itself, it must be called by the
+    /// contract.
+    #[init]
+    pub fn new() -> Self {
+        let mut contract = Self { version: 0 };
+        contract.owner_set(Some(env::predecessor_account_id()));
+        contract
+    }
+
+    /// Bumps the version, to be called after deploying new code.
+    #[private]
+    pub fn migrate(&mut self) {
+        self.version += 1;
+    }
+
+    pub fn get_version(&self) -> u32 {
+        self.version
+    }
+}
@@ -0,0 +1,48 @@
+#[allow(dead_code)]
+mod common;
+
+use near_sdk::serde_json::json;
+use near_sdk::Gas;
+use std::path::Path;
+
+const PROJECT_PATH: &str = "./tests/contracts/upgradable";
+
+/// Verify the migration passed to `up_deploy_code_and_call` runs on the
+/// deployed code.
+#[tokio::test]
+async fn test_deploy_code_and_call() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let wasm = common::repo::compile_project(&Path::new(PROJECT_PATH)).await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    contract
+        .call("new")
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Stage the contract's own code again, which is then deployed.
+    contract
+        .call("up_stage_code")
+        .args_borsh(wasm)
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    contract
+        .call("up_deploy_code_and_call")
+        .args_json(json!({
+            "function_name": "migrate",
+            "args": Vec::<u8>::new(),
+            "gas": Gas(20_000_000_000_000),
+        }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    let version = contract.view("get_version").await?.json::<u32>()?;
+    assert_eq!(version, 1);
+
+    Ok(())
+}